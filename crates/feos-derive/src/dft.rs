@@ -83,6 +83,7 @@ fn impl_fluid_parameters(
 ) -> syn::Result<proc_macro2::TokenStream> {
     let mut epsilon_k_ff = Vec::new();
     let mut sigma_ff = Vec::new();
+    let mut sigma_ff_t = Vec::new();
 
     for v in variants.iter() {
         let name = &v.ident;
@@ -93,6 +94,9 @@ fn impl_fluid_parameters(
             sigma_ff.push(quote! {
                 Self::#name(functional) => functional.sigma_ff()
             });
+            sigma_ff_t.push(quote! {
+                Self::#name(functional) => functional.sigma_ff_t(temperature)
+            });
         } else {
             epsilon_k_ff.push(quote! {
                 Self::#name(functional) => panic!("{} does not support the automatic calculation of external potentials!", stringify!(#name))
@@ -100,6 +104,9 @@ fn impl_fluid_parameters(
             sigma_ff.push(quote! {
                 Self::#name(functional) => panic!("{} does not support the automatic calculation of external potentials!", stringify!(#name))
             });
+            sigma_ff_t.push(quote! {
+                Self::#name(functional) => panic!("{} does not support the automatic calculation of external potentials!", stringify!(#name))
+            });
         }
     }
     Ok(quote! {
@@ -115,6 +122,12 @@ fn impl_fluid_parameters(
                     #(#sigma_ff,)*
                 }
             }
+
+            fn sigma_ff_t(&self, temperature: f64) -> DVector<f64> {
+                match self {
+                    #(#sigma_ff_t,)*
+                }
+            }
         }
     })
 }
@@ -0,0 +1,139 @@
+use super::{FluidParameters, InitialGuess, Pore1D, PoreProfile1D, PoreSpecification};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::Geometry;
+use crate::profile::MAX_POTENTIAL;
+use crate::solver::DFTSolver;
+use feos_core::{FeosError, FeosResult, ReferenceSystem, State};
+use ndarray::{Array1, Zip};
+use quantity::{Density, Length, Pressure, SurfaceTension};
+
+/// Change of the film thickness (in Å) for the numerical derivative of the
+/// grand potential. The positions of hard walls are only resolved up to the
+/// grid spacing, so the step has to span several grid points.
+const DELTA_FILM_THICKNESS: f64 = 1e-1;
+
+/// Disjoining-pressure isotherm $\Pi(h)$ of a symmetric film confined between
+/// two identical walls in contact with a bulk reservoir.
+///
+/// Entries for which the calculation did not converge are NaN.
+pub struct DisjoiningPressureIsotherm {
+    /// Film thicknesses, i.e., the distances between the walls.
+    pub film_thickness: Length<Array1<f64>>,
+    /// Disjoining pressures $\Pi=-\frac{\mathrm{d}\omega}{\mathrm{d}h}$.
+    pub disjoining_pressure: Pressure<Array1<f64>>,
+    /// Excess grand potential per area $\omega=\frac{\Omega+pV}{A}$ of the
+    /// film including both walls.
+    pub excess_grand_potential: SurfaceTension<Array1<f64>>,
+}
+
+impl Pore1D {
+    /// Calculate the disjoining-pressure isotherm of a film confined between
+    /// the walls of a symmetric slit pore in contact with `bulk`.
+    ///
+    /// The film thicknesses are the pore widths and have to be sorted in
+    /// ascending order. Starting from the thickest film, every profile is
+    /// initialized with the solution of the next thicker film, shifted such
+    /// that the walls coincide, i.e., the isotherm follows the branch of a
+    /// thinning film. The disjoining pressure is the derivative of the excess
+    /// grand potential per area $\omega(h)$ with respect to the film
+    /// thickness and is evaluated with central differences. For walls whose
+    /// potentials do not reach across the pore, it equals the difference of
+    /// the normal pressure in the film and the bulk pressure, see [PoreProfile1D::stress_profile](super::PoreProfile1D::stress_profile).
+    /// All other properties of the pore are taken from `self`. Only available
+    /// for slit pores with identical walls.
+    pub fn disjoining_pressure_isotherm<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        film_thickness: &Length<Array1<f64>>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<DisjoiningPressureIsotherm> {
+        if self.geometry != Geometry::Cartesian || self.right_wall_potential.is_some() {
+            return Err(FeosError::Error(String::from(
+                "Disjoining pressures are only available for slit pores with identical walls.",
+            )));
+        }
+        let h = film_thickness.to_reduced();
+        if h.windows(2).into_iter().any(|h| h[1] <= h[0]) {
+            return Err(FeosError::Error(String::from(
+                "The film thicknesses have to be sorted in ascending order.",
+            )));
+        }
+
+        // excess grand potential of one half of the symmetric pore
+        let solve = |h: f64, previous: Option<&(f64, PoreProfile1D<F>)>| {
+            let pore = Pore1D {
+                pore_size: Length::from_reduced(h),
+                ..self.clone()
+            };
+            let mut profile = pore.initialize(bulk, InitialGuess::Bulk, None)?;
+            if let Some((h_prev, prev)) = previous {
+                // the walls are located at half the pore size from the center,
+                // inside the walls the densities of the bulk initialization are kept
+                let z = profile.profile.grid.grids()[0] + 0.5 * (h_prev - h);
+                let shifted = prev.profile.grid.axes()[0]
+                    .interpolate_density(&prev.profile.density, &z)
+                    .into_reduced();
+                let mut density = profile.profile.density.to_reduced();
+                Zip::from(&mut density)
+                    .and(&shifted)
+                    .and(&profile.profile.external_potential)
+                    .for_each(|rho, &rho_shifted, &v| {
+                        if v < MAX_POTENTIAL {
+                            *rho = rho_shifted
+                        }
+                    });
+                profile.profile.density = Density::from_reduced(density);
+            }
+            let profile = profile.solve(solver)?;
+            let omega = profile.interfacial_tension.unwrap().to_reduced();
+            Ok::<_, FeosError>((omega, (h, profile)))
+        };
+
+        let n = h.len();
+        let mut disjoining_pressure = Array1::from_elem(n, f64::NAN);
+        let mut excess_grand_potential = Array1::from_elem(n, f64::NAN);
+        let mut previous = None;
+        for k in (0..n).rev() {
+            let Ok((omega, profile)) = solve(h[k], previous.as_ref()) else {
+                continue;
+            };
+            let (Ok((omega_plus, _)), Ok((omega_minus, _))) = (
+                solve(h[k] + DELTA_FILM_THICKNESS, Some(&profile)),
+                solve(h[k] - DELTA_FILM_THICKNESS, Some(&profile)),
+            ) else {
+                continue;
+            };
+            // the domain contains one half of the pore
+            excess_grand_potential[k] = 2.0 * omega;
+            disjoining_pressure[k] = -(omega_plus - omega_minus) / DELTA_FILM_THICKNESS;
+            previous = Some(profile);
+        }
+        Ok(DisjoiningPressureIsotherm {
+            film_thickness: film_thickness.clone(),
+            disjoining_pressure: Pressure::from_reduced(disjoining_pressure),
+            excess_grand_potential: SurfaceTension::from_reduced(excess_grand_potential),
+        })
+    }
+}
+
+impl DisjoiningPressureIsotherm {
+    /// Stability of the film at every thickness.
+    ///
+    /// A film is (mechanically) stable if the disjoining pressure decreases
+    /// with increasing film thickness, i.e., $\frac{\mathrm{d}\Pi}{\mathrm{d}h}<0$.
+    /// The slope is calculated from the neighboring points of the isotherm
+    /// (one-sided at the thinnest and the thickest film), so the resolution
+    /// is determined by the spacing of the film thicknesses. Films for which
+    /// the slope is not available are classified as unstable.
+    pub fn is_stable(&self) -> Vec<bool> {
+        let h = self.film_thickness.to_reduced();
+        let pi = self.disjoining_pressure.to_reduced();
+        let n = h.len();
+        (0..n)
+            .map(|k| {
+                let (k0, k1) = (k.saturating_sub(1), (k + 1).min(n - 1));
+                k1 > k0 && (pi[k1] - pi[k0]) / (h[k1] - h[k0]) < 0.0
+            })
+            .collect()
+    }
+}
@@ -72,6 +72,14 @@ pub enum ExternalPotential {
 pub trait FluidParameters {
     fn epsilon_k_ff(&self) -> DVector<f64>;
     fn sigma_ff(&self) -> DVector<f64>;
+
+    /// Segment diameters at the given (reduced) temperature.
+    ///
+    /// Used to evaluate external potentials. Overwrite this method for fluids
+    /// with temperature-dependent diameters. Defaults to [FluidParameters::sigma_ff].
+    fn sigma_ff_t(&self, _temperature: f64) -> DVector<f64> {
+        self.sigma_ff()
+    }
 }
 
 impl<C: Deref<Target = T>, T: FluidParameters> FluidParameters for C {
@@ -81,6 +89,9 @@ impl<C: Deref<Target = T>, T: FluidParameters> FluidParameters for C {
     fn sigma_ff(&self) -> DVector<f64> {
         T::sigma_ff(self)
     }
+    fn sigma_ff_t(&self, temperature: f64) -> DVector<f64> {
+        T::sigma_ff_t(self, temperature)
+    }
 }
 
 impl ExternalPotential {
@@ -89,7 +100,7 @@ impl ExternalPotential {
         &self,
         z_grid: &Array1<f64>,
         fluid_parameters: &P,
-        #[cfg_attr(not(feature = "rayon"), expect(unused_variables))] temperature: f64,
+        temperature: f64,
     ) -> Array2<f64> {
         if let ExternalPotential::Custom(potential) = self {
             return potential.clone();
@@ -97,12 +108,13 @@ impl ExternalPotential {
 
         // Allocate external potential
         let m = fluid_parameters.m();
+        let sigma_ff = fluid_parameters.sigma_ff_t(temperature);
         let mut ext_pot = Array2::zeros((m.len(), z_grid.len()));
 
         for (i, &mi) in m.iter().enumerate() {
             ext_pot.index_axis_mut(Axis_nd(0), i).assign(&match self {
                 Self::HardWall { sigma_ss } => {
-                    let sigma_sf = (fluid_parameters.sigma_ff()[i] + *sigma_ss) * 0.5;
+                    let sigma_sf = (sigma_ff[i] + *sigma_ss) * 0.5;
                     z_grid.mapv(|z| if z < sigma_sf { f64::INFINITY } else { 0.0 })
                 }
                 Self::LJ93 {
@@ -113,7 +125,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     2.0 * PI * mi * epsilon_k_sf[i] * sigma_sf[i].powi(3) * rho_s / 45.0
                         * (2.0 * (sigma_sf[i] / z_grid).mapv(|x| x.powi(9))
@@ -126,7 +138,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     epsilon_k_sf[i]
                         * ((sigma_sf[i] / z_grid).mapv(|x| x.powi(9))
@@ -149,7 +161,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     (2.0 * PI * mi * xi.unwrap_or(1.0) * epsilon_k_sf[i])
                         * (sigma_sf[i].powi(2) * DELTA_STEELE * rho_s)
@@ -184,7 +196,7 @@ impl ExternalPotential {
                         (fluid_parameters.epsilon_k_ff() * *epsilon1_k_ss).map(|e| e.sqrt());
                     let epsilon2_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon2_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     let bh_tail = (2.0 * PI * mi * epsilon2_k_sf[i] * sigma_sf[i].powi(3) * rho_s
                         / 45.0
@@ -210,7 +222,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff()[i] * epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = (fluid_parameters.sigma_ff()[i] + sigma_ss) * 0.5;
+                    let sigma_sf = (sigma_ff[i] + sigma_ss) * 0.5;
 
                     calculate_fea_potential(
                         z_grid,
@@ -238,7 +250,7 @@ impl ExternalPotential {
         r_grid: &Array1<f64>,
         pore_size: f64,
         fluid_parameters: &P,
-        #[cfg_attr(not(feature = "rayon"), expect(unused_variables))] temperature: f64,
+        temperature: f64,
     ) -> Array2<f64> {
         if let ExternalPotential::Custom(potential) = self {
             return potential.clone();
@@ -246,12 +258,13 @@ impl ExternalPotential {
 
         // Allocate external potential
         let m = fluid_parameters.m();
+        let sigma_ff = fluid_parameters.sigma_ff_t(temperature);
         let mut ext_pot = Array2::zeros((m.len(), r_grid.len()));
 
         for (i, &mi) in m.iter().enumerate() {
             ext_pot.index_axis_mut(Axis_nd(0), i).assign(&match self {
                 Self::HardWall { sigma_ss } => {
-                    let sigma_sf = (fluid_parameters.sigma_ff()[i] + *sigma_ss) * 0.5;
+                    let sigma_sf = (sigma_ff[i] + *sigma_ss) * 0.5;
                     r_grid.mapv(|r| {
                         if r > pore_size - sigma_sf {
                             f64::INFINITY
@@ -268,7 +281,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     (phi(6, &(r_grid / pore_size), sigma_sf[i] / pore_size)
                         - phi(3, &(r_grid / pore_size), sigma_sf[i] / pore_size))
@@ -300,7 +313,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     (2.0 * PI * mi * xi.unwrap_or(1.0) * epsilon_k_sf[i])
                         * (sigma_sf[i].powi(2) * DELTA_STEELE * rho_s)
@@ -341,7 +354,7 @@ impl ExternalPotential {
                         (fluid_parameters.epsilon_k_ff() * *epsilon1_k_ss).map(|e| e.sqrt());
                     let epsilon2_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon2_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     let bh_tail = ((phi(6, &(r_grid / pore_size), 2.0 * sigma_sf[i] / pore_size)
                         - phi(3, &(r_grid / pore_size), 2.0 * sigma_sf[i] / pore_size))
@@ -376,7 +389,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff()[i] * epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = (fluid_parameters.sigma_ff()[i] + sigma_ss) * 0.5;
+                    let sigma_sf = (sigma_ff[i] + sigma_ss) * 0.5;
 
                     calculate_fea_potential(
                         r_grid,
@@ -404,7 +417,7 @@ impl ExternalPotential {
         r_grid: &Array1<f64>,
         pore_size: f64,
         fluid_parameters: &P,
-        #[cfg_attr(not(feature = "rayon"), expect(unused_variables))] temperature: f64,
+        temperature: f64,
     ) -> Array2<f64> {
         if let ExternalPotential::Custom(potential) = self {
             return potential.clone();
@@ -412,12 +425,13 @@ impl ExternalPotential {
 
         // Allocate external potential
         let m = fluid_parameters.m();
+        let sigma_ff = fluid_parameters.sigma_ff_t(temperature);
         let mut ext_pot = Array2::zeros((m.len(), r_grid.len()));
 
         for (i, &mi) in m.iter().enumerate() {
             ext_pot.index_axis_mut(Axis_nd(0), i).assign(&match self {
                 Self::HardWall { sigma_ss } => {
-                    let sigma_sf = (fluid_parameters.sigma_ff()[i] + *sigma_ss) * 0.5;
+                    let sigma_sf = (sigma_ff[i] + *sigma_ss) * 0.5;
                     r_grid.mapv(|r| {
                         if r > pore_size - sigma_sf {
                             f64::INFINITY
@@ -434,7 +448,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     PI * mi
                         * epsilon_k_sf[i]
@@ -472,7 +486,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     (2.0 * PI * mi * xi.unwrap_or(1.0) * epsilon_k_sf[i])
                         * (sigma_sf[i].powi(2) * DELTA_STEELE * rho_s)
@@ -531,7 +545,7 @@ impl ExternalPotential {
                         (fluid_parameters.epsilon_k_ff() * *epsilon1_k_ss).map(|e| e.sqrt());
                     let epsilon2_k_sf =
                         (fluid_parameters.epsilon_k_ff() * *epsilon2_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = fluid_parameters.sigma_ff().add_scalar(*sigma_ss) * 0.5;
+                    let sigma_sf = sigma_ff.add_scalar(*sigma_ss) * 0.5;
 
                     let bh_tail = (2.0
                         * PI
@@ -566,7 +580,7 @@ impl ExternalPotential {
                     // combining rules
                     let epsilon_k_sf =
                         (fluid_parameters.epsilon_k_ff()[i] * epsilon_k_ss).map(|e| e.sqrt());
-                    let sigma_sf = (fluid_parameters.sigma_ff()[i] + sigma_ss) * 0.5;
+                    let sigma_sf = (sigma_ff[i] + sigma_ss) * 0.5;
 
                     calculate_fea_potential(
                         r_grid,
@@ -0,0 +1,139 @@
+use super::{Adsorption, FluidParameters, InitialGuess, PoreProfile, PoreSpecification};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::solver::DFTSolver;
+use feos_core::{FeosResult, ReferenceSystem};
+use nalgebra::DVector;
+use ndarray::{Array, Array1, Dimension, RemoveAxis};
+use quantity::{Density, Energy, Moles, Pressure, Temperature};
+
+/// Maximum relative difference of the loadings and grand potentials of two
+/// profiles that are considered to be the same solution.
+const TOL_BRANCHES: f64 = 1e-5;
+
+/// All distinct solutions of the Euler-Lagrange equation found at the
+/// pressures of an isotherm.
+///
+/// Pores with complex topologies, e.g., ink-bottle pores, can show more than
+/// two (meta)stable states at the same pressure.
+pub struct IsothermBranches<D: Dimension, F> {
+    pub pressure: Pressure<Array1<f64>>,
+    /// Distinct converged profiles at every pressure, sorted by increasing
+    /// grand potential, i.e., the first profile is the stable one.
+    pub profiles: Vec<Vec<PoreProfile<D, F>>>,
+}
+
+impl<D: Dimension + RemoveAxis + 'static, F: HelmholtzEnergyFunctional + FluidParameters>
+    Adsorption<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// Calculate all distinct solutions at the given pressures using
+    /// multiple initial guesses per pressure.
+    ///
+    /// The initial guesses are the profiles of the adsorption and the
+    /// desorption isotherm, [InitialGuess::Bulk], [InitialGuess::Empty] and
+    /// all profiles in `initial_densities`, e.g., partially filled states of
+    /// an ink-bottle pore. Converged profiles are considered to be the same
+    /// solution if their loadings and grand potentials agree.
+    pub fn isotherm_branches<S: PoreSpecification<D>>(
+        functional: &F,
+        temperature: Temperature,
+        pressure: &Pressure<Array1<f64>>,
+        pore: &S,
+        molefracs: &Option<DVector<f64>>,
+        initial_densities: &[Density<Array<f64, D::Larger>>],
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<IsothermBranches<D, F>> {
+        let adsorption =
+            Self::adsorption_isotherm(functional, temperature, pressure, pore, molefracs, solver)?;
+        let desorption =
+            Self::desorption_isotherm(functional, temperature, pressure, pore, molefracs, solver)?;
+
+        let profiles = adsorption
+            .profiles
+            .into_iter()
+            .zip(desorption.profiles)
+            .map(|(a, d)| {
+                let mut candidates: Vec<_> = [a, d].into_iter().flatten().collect();
+                let Some(reference) = candidates.first() else {
+                    return Vec::new();
+                };
+                let bulk = reference.profile.bulk.clone();
+                let external_potential = reference.profile.external_potential.clone();
+                let guesses = [InitialGuess::Bulk, InitialGuess::Empty]
+                    .into_iter()
+                    .chain(initial_densities.iter().map(InitialGuess::Explicit));
+                for guess in guesses {
+                    if let Ok(p) = pore
+                        .initialize(&bulk, guess, Some(&external_potential))
+                        .and_then(|p| p.solve(solver))
+                    {
+                        candidates.push(p);
+                    }
+                }
+                Self::distinct_profiles(candidates)
+            })
+            .collect();
+        Ok(IsothermBranches {
+            pressure: pressure.clone(),
+            profiles,
+        })
+    }
+
+    fn distinct_profiles(mut candidates: Vec<PoreProfile<D, F>>) -> Vec<PoreProfile<D, F>> {
+        let properties = |p: &PoreProfile<D, F>| {
+            (
+                p.profile.total_moles().to_reduced(),
+                p.grand_potential.unwrap().to_reduced(),
+            )
+        };
+        candidates.sort_by(|p1, p2| properties(p1).1.total_cmp(&properties(p2).1));
+        let mut distinct: Vec<PoreProfile<D, F>> = Vec::with_capacity(candidates.len());
+        for p in candidates {
+            let (n, omega) = properties(&p);
+            let is_new = distinct.iter().all(|d| {
+                let (n_d, omega_d) = properties(d);
+                (n - n_d).abs() > TOL_BRANCHES * n.abs().max(n_d.abs())
+                    || (omega - omega_d).abs() > TOL_BRANCHES * omega.abs().max(omega_d.abs())
+            });
+            if is_new {
+                distinct.push(p);
+            }
+        }
+        distinct
+    }
+}
+
+impl<D: Dimension, F> IsothermBranches<D, F> {
+    /// Number of distinct solutions at every pressure.
+    pub fn branches(&self) -> Array1<usize> {
+        self.profiles.iter().map(Vec::len).collect()
+    }
+}
+
+impl<D: Dimension + RemoveAxis + 'static, F: HelmholtzEnergyFunctional> IsothermBranches<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// Total loadings of the distinct solutions at every pressure, sorted by
+    /// increasing grand potential.
+    pub fn total_adsorption(&self) -> Vec<Moles<Array1<f64>>> {
+        self.profiles
+            .iter()
+            .map(|p| Moles::from_shape_fn(p.len(), |i| p[i].profile.total_moles()))
+            .collect()
+    }
+
+    /// Grand potentials of the distinct solutions at every pressure in
+    /// increasing order.
+    pub fn grand_potential(&self) -> Vec<Energy<Array1<f64>>> {
+        self.profiles
+            .iter()
+            .map(|p| Energy::from_shape_fn(p.len(), |i| p[i].grand_potential.unwrap()))
+            .collect()
+    }
+}
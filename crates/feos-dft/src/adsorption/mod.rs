@@ -2,22 +2,35 @@
 use super::functional::HelmholtzEnergyFunctional;
 use super::solver::DFTSolver;
 use feos_core::{
-    Contributions, DensityInitialization, FeosError, FeosResult, ReferenceSystem, SolverOptions,
-    State, StateBuilder,
+    Contributions, DensityInitialization, FeosError, FeosResult, PhaseEquilibrium, ReferenceSystem,
+    SolverOptions, State, StateBuilder,
 };
 use nalgebra::{DMatrix, DVector};
 use ndarray::{Array1, Array2, Dimension, Ix1, Ix3, RemoveAxis};
-use quantity::{Energy, MolarEnergy, Moles, Pressure, Temperature};
+use quantity::{Energy, MolarEnergy, Moles, Pressure, RGAS, Temperature};
 use std::iter;
 
+mod disjoining_pressure;
 mod external_potential;
 #[cfg(feature = "rayon")]
 mod fea_potential;
+mod isotherm_branches;
 mod pore;
 mod pore2d;
+mod pore_filling_diagram;
+mod pore_size_distribution;
+mod wall;
+pub use disjoining_pressure::DisjoiningPressureIsotherm;
 pub use external_potential::{ExternalPotential, FluidParameters};
-pub use pore::{HenryCoefficient, Pore1D, PoreProfile, PoreProfile1D, PoreSpecification};
+pub use isotherm_branches::IsothermBranches;
+pub use pore::{
+    HenryCoefficient, InitialGuess, Pore1D, PoreProfile, PoreProfile1D, PoreSpecification,
+    bulk_from_fugacity,
+};
+pub use pore_filling_diagram::PoreFillingDiagram;
+pub use pore_size_distribution::pore_size_distribution;
 pub use pore2d::{Pore2D, PoreProfile2D};
+pub use wall::{CorrugatedWall2D, Wall1D, WettingIsotherm};
 
 #[cfg(feature = "rayon")]
 mod pore3d;
@@ -97,6 +110,55 @@ where
         ))
     }
 
+    /// Calculate an adsorption isotherm of a pure component for pressures
+    /// relative to the saturation pressure $p/p^\mathrm{sat}$, e.g.,
+    /// relative humidities for water.
+    pub fn adsorption_isotherm_relative<S: PoreSpecification<D>>(
+        functional: &F,
+        temperature: Temperature,
+        relative_pressure: &Array1<f64>,
+        pore: &S,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<Adsorption<D, F>> {
+        let pressure = Self::pressure_from_relative(functional, temperature, relative_pressure)?;
+        Self::adsorption_isotherm(functional, temperature, &pressure, pore, &None, solver)
+    }
+
+    /// Calculate a desorption isotherm of a pure component for pressures
+    /// relative to the saturation pressure $p/p^\mathrm{sat}$.
+    pub fn desorption_isotherm_relative<S: PoreSpecification<D>>(
+        functional: &F,
+        temperature: Temperature,
+        relative_pressure: &Array1<f64>,
+        pore: &S,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<Adsorption<D, F>> {
+        let pressure = Self::pressure_from_relative(functional, temperature, relative_pressure)?;
+        Self::desorption_isotherm(functional, temperature, &pressure, pore, &None, solver)
+    }
+
+    fn pressure_from_relative(
+        functional: &F,
+        temperature: Temperature,
+        relative_pressure: &Array1<f64>,
+    ) -> FeosResult<Pressure<Array1<f64>>> {
+        let p_sat = Self::saturation_pressure(functional, temperature)?;
+        Ok(Pressure::from_reduced(
+            relative_pressure * p_sat.to_reduced(),
+        ))
+    }
+
+    /// Saturation pressure of a pure component at the given temperature.
+    fn saturation_pressure(functional: &F, temperature: Temperature) -> FeosResult<Pressure> {
+        if functional.components() != 1 {
+            return Err(FeosError::IncompatibleComponents(
+                functional.components(),
+                1,
+            ));
+        }
+        Ok(PhaseEquilibrium::pure_t(functional, temperature, None, SolverOptions::default())?.0)
+    }
+
     /// Calculate an equilibrium isotherm
     pub fn equilibrium_isotherm<S: PoreSpecification<D>>(
         functional: &F,
@@ -210,7 +272,10 @@ where
                 _ => unreachable!(),
             };
         }
-        let profile = pore.initialize(&bulk, None, None)?.solve(solver)?.profile;
+        let profile = pore
+            .initialize(&bulk, InitialGuess::Bulk, None)?
+            .solve(solver)?
+            .profile;
         let external_potential = Some(&profile.external_potential);
         let mut old_density = Some(&profile.density);
 
@@ -227,8 +292,8 @@ where
                     .clone();
             }
 
-            let p = pore.initialize(&bulk, old_density, external_potential)?;
-            let p2 = pore.initialize(&bulk, None, external_potential)?;
+            let p = pore.initialize(&bulk, old_density.into(), external_potential)?;
+            let p2 = pore.initialize(&bulk, InitialGuess::Bulk, external_potential)?;
             profiles.push(p.solve(solver).or_else(|_| p2.solve(solver)));
 
             old_density = if let Some(Ok(l)) = profiles.last() {
@@ -275,8 +340,12 @@ where
             .vapor()
             .build()?;
 
-        let mut vapor = pore.initialize(&vapor_bulk, None, None)?.solve(solver)?;
-        let mut liquid = pore.initialize(&bulk_init, None, None)?.solve(solver)?;
+        let mut vapor = pore
+            .initialize(&vapor_bulk, InitialGuess::Bulk, None)?
+            .solve(solver)?;
+        let mut liquid = pore
+            .initialize(&bulk_init, InitialGuess::Bulk, None)?
+            .solve(solver)?;
 
         // calculate initial value for bulk density
         let n_dp_drho_v = (vapor.profile.moles() * vapor_bulk.dp_drho(Contributions::Total)).sum();
@@ -331,6 +400,116 @@ where
         ))
     }
 
+    /// Loading, temperature, chemical potential and enthalpy of the bulk phase
+    /// for every converged profile of a pure component in reduced units.
+    ///
+    /// Contributions that only depend on temperature are omitted from the
+    /// chemical potential and the enthalpy, because they cancel in the
+    /// isosteric heat.
+    fn isosteric_properties(&self) -> Vec<Option<[f64; 4]>> {
+        self.profiles
+            .iter()
+            .map(|p| {
+                p.as_ref().ok().map(|p| {
+                    let bulk = &p.profile.bulk;
+                    let t = bulk.temperature;
+                    let mu = bulk.residual_chemical_potential().get(0)
+                        + RGAS * t * bulk.density.to_reduced().ln();
+                    let h = bulk.residual_molar_enthalpy() + RGAS * t;
+                    [
+                        p.profile.total_moles().to_reduced(),
+                        t.to_reduced(),
+                        mu.to_reduced(),
+                        h.to_reduced(),
+                    ]
+                })
+            })
+            .collect()
+    }
+
+    /// Isosteric heat of adsorption of a pure component as a function of the
+    /// loading.
+    ///
+    /// The isosteric heat $q_\mathrm{st}$ is obtained from finite differences of
+    /// the bulk chemical potential at constant loading between this isotherm
+    /// and an isotherm `other` at a slightly different temperature:
+    /// $$q_\mathrm{st}=h^\mathrm{bulk}-\left.\frac{\Delta\left(\mu/T\right)}{\Delta\left(1/T\right)}\right|_N$$
+    /// Because the pressures of the two isotherms generally correspond to
+    /// different loadings, the properties of `other` are interpolated linearly
+    /// in the logarithm of the loading (which is exact for the chemical
+    /// potential in the Henry regime) to the loadings of this isotherm before
+    /// differencing. The loadings should therefore increase monotonically along
+    /// `other`.
+    ///
+    /// Returns the loadings of this isotherm and the corresponding isosteric
+    /// heats. Failed profiles and loadings outside of the range of `other`
+    /// result in NaN.
+    #[expect(clippy::type_complexity)]
+    pub fn isosteric_heat(
+        &self,
+        other: &Self,
+    ) -> FeosResult<(Moles<Array1<f64>>, MolarEnergy<Array1<f64>>)> {
+        if self.components != 1 {
+            return Err(FeosError::IncompatibleComponents(self.components, 1));
+        }
+        let properties = self.isosteric_properties();
+        let mut other_properties: Vec<_> =
+            other.isosteric_properties().into_iter().flatten().collect();
+        other_properties.sort_by(|a, b| a[0].total_cmp(&b[0]));
+
+        let interpolate = |n: f64| {
+            other_properties.windows(2).find_map(|w| {
+                let [p0, p1] = [w[0], w[1]];
+                (p0[0] <= n && n <= p1[0] && p0[0] < p1[0]).then(|| {
+                    let x = (n / p0[0]).ln() / (p1[0] / p0[0]).ln();
+                    [0, 1, 2, 3].map(|i| p0[i] + x * (p1[i] - p0[i]))
+                })
+            })
+        };
+
+        let mut loading = Array1::from_elem(properties.len(), f64::NAN);
+        let mut heat = Array1::from_elem(properties.len(), f64::NAN);
+        for (i, p1) in properties.into_iter().enumerate() {
+            let Some([n, t1, mu1, h1]) = p1 else {
+                continue;
+            };
+            loading[i] = n;
+            if let Some([_, t2, mu2, h2]) = interpolate(n) {
+                let h_ads = (mu2 / t2 - mu1 / t1) / (1.0 / t2 - 1.0 / t1);
+                heat[i] = 0.5 * (h1 + h2) - h_ads;
+            }
+        }
+        Ok((
+            Moles::from_reduced(loading),
+            MolarEnergy::from_reduced(heat),
+        ))
+    }
+
+    /// Pressure interval that contains the spinodal at which the branch of
+    /// an adsorption or desorption isotherm ends.
+    ///
+    /// Beyond the spinodal, the profiles can no longer be continued on the
+    /// current branch and the loading jumps to the other branch. The spinodal
+    /// is therefore located between the first two consecutive converged
+    /// profiles, for which the relative change of the total loading exceeds
+    /// `tol`. The interval can be narrowed by recalculating the isotherm on a
+    /// finer pressure grid. Returns `None` if no jump in the loading is found,
+    /// e.g., for reversible isotherms.
+    pub fn spinodal(&self, tol: f64) -> Option<(Pressure, Pressure)> {
+        let pressure = self.pressure().to_reduced();
+        let loading = self.total_adsorption().to_reduced();
+        let converged: Vec<_> = pressure
+            .into_iter()
+            .zip(loading)
+            .filter(|(p, n)| p.is_finite() && n.is_finite())
+            .collect();
+        converged.windows(2).find_map(|w| {
+            let [(p0, n0), (p1, n1)] = [w[0], w[1]];
+            ((n1 - n0).abs() > tol * n0.abs().max(n1.abs()))
+                .then(|| (Pressure::from_reduced(p0), Pressure::from_reduced(p1)))
+        })
+    }
+
     pub fn pressure(&self) -> Pressure<Array1<f64>> {
         Pressure::from_shape_fn(self.profiles.len(), |i| match &self.profiles[i] {
             Ok(p) => {
@@ -351,6 +530,22 @@ where
         })
     }
 
+    /// Pressures of the isotherm of a pure component relative to the
+    /// saturation pressure at the temperature of the isotherm.
+    pub fn relative_pressure(&self) -> FeosResult<Array1<f64>> {
+        let bulk = &self
+            .profiles
+            .iter()
+            .find_map(|p| p.as_ref().ok())
+            .ok_or_else(|| {
+                FeosError::Error(String::from("The isotherm contains no converged profiles."))
+            })?
+            .profile
+            .bulk;
+        let p_sat = Self::saturation_pressure(&bulk.eos, bulk.temperature)?;
+        Ok(self.pressure().to_reduced() / p_sat.to_reduced())
+    }
+
     pub fn adsorption(&self) -> Moles<Array2<f64>> {
         Moles::from_shape_fn(
             (self.components, self.profiles.len()),
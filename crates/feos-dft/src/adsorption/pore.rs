@@ -1,39 +1,51 @@
 use crate::WeightFunctionInfo;
 use crate::adsorption::{ExternalPotential, FluidParameters};
-use crate::convolver::ConvolverFFT;
+use crate::convolver::BulkConvolver;
 use crate::functional::{HelmholtzEnergyFunctional, HelmholtzEnergyFunctionalDyn, MoleculeShape};
 use crate::functional_contribution::FunctionalContribution;
 use crate::geometry::{Axis, Geometry, Grid};
-use crate::profile::{DFTProfile, MAX_POTENTIAL};
+use crate::interface::PlanarInterface;
+use crate::profile::{DFTProfile, DFTSpecifications, MAX_POTENTIAL, richardson_extrapolation};
 use crate::solver::DFTSolver;
 use feos_core::{
-    Contributions, FeosResult, ReferenceSystem, ResidualDyn, State, StateBuilder, StateHD,
+    Contributions, FeosError, FeosResult, PhaseEquilibrium, ReferenceSystem, ResidualDyn, State,
+    StateBuilder, StateHD, Verbosity, log_result,
 };
-use nalgebra::{DVector, dvector};
+use nalgebra::{DMatrix, DVector, dvector};
 use ndarray::prelude::*;
-use ndarray::{Axis as Axis_nd, RemoveAxis};
+use ndarray::{Axis as Axis_nd, RemoveAxis, Zip};
 use num_dual::linalg::LU;
 use num_dual::{Dual64, DualNum};
 use quantity::{
-    _Moles, _Pressure, Density, Dimensionless, Energy, KELVIN, Length, MolarEnergy, Quantity, RGAS,
-    Temperature, Volume,
+    _Moles, _Pressure, Density, Dimensionless, Energy, Entropy, KELVIN, Length, MolarEnergy, Moles,
+    Pressure, Quantity, RGAS, Temperature, Volume,
 };
 use rustdct::DctNum;
+use std::sync::Arc;
 use typenum::Diff;
 
 const POTENTIAL_OFFSET: f64 = 2.0;
 const DEFAULT_GRID_POINTS: usize = 2048;
+const DEFAULT_MAX_GRID_SPACING: f64 = 0.5;
+const MAX_ITER_FUGACITY: usize = 100;
+const MAX_STEP_FUGACITY: f64 = 0.5;
+const TOL_FUGACITY: f64 = 1e-12;
 
 pub type _HenryCoefficient = Diff<_Moles, _Pressure>;
 pub type HenryCoefficient<T> = Quantity<T, _HenryCoefficient>;
 
 /// Parameters required to specify a 1D pore.
+#[derive(Clone)]
 pub struct Pore1D {
     pub geometry: Geometry,
     pub pore_size: Length,
     pub potential: ExternalPotential,
     pub n_grid: Option<usize>,
     pub potential_cutoff: Option<f64>,
+    pub potential_scaling: Option<DVector<f64>>,
+    pub convolver_coarsening: Option<usize>,
+    pub right_wall_potential: Option<ExternalPotential>,
+    pub max_grid_spacing: Option<f64>,
 }
 
 impl Pore1D {
@@ -50,6 +62,169 @@ impl Pore1D {
             potential,
             n_grid,
             potential_cutoff,
+            potential_scaling: None,
+            convolver_coarsening: None,
+            right_wall_potential: None,
+            max_grid_spacing: None,
+        }
+    }
+
+    /// Scale the external potential for individual components.
+    ///
+    /// A scaling factor of 0 removes the walls for the respective component
+    /// entirely, e.g., to model membranes that only interact with some species.
+    /// For segment-based functionals, every segment of a component is scaled by
+    /// the factor of that component.
+    pub fn potential_scaling(mut self, potential_scaling: DVector<f64>) -> Self {
+        self.potential_scaling = Some(potential_scaling);
+        self
+    }
+
+    /// Maximum spacing of the grid relative to the smallest segment diameter.
+    ///
+    /// The weight functions have to be resolved by several grid points. By
+    /// default, grids with a largest spacing above 0.5 times the smallest
+    /// segment diameter are reported in [PoreProfile::grid_spacing_warning].
+    /// If the maximum spacing is set explicitly, initializing the pore fails
+    /// instead. Use `f64::INFINITY` to disable the check.
+    pub fn max_grid_spacing(mut self, max_grid_spacing: f64) -> Self {
+        self.max_grid_spacing = Some(max_grid_spacing);
+        self
+    }
+
+    /// Use a different potential for the right wall of a slit pore.
+    ///
+    /// The left wall is modeled with `potential`, e.g., to describe slit
+    /// pores between walls of different materials. Asymmetric pores are
+    /// discretized over the full pore width with the pore center at $z=0$,
+    /// so that, in contrast to symmetric slit pores, which are only solved
+    /// for one half of the pore, extensive properties refer to the entire pore.
+    /// Only available for cartesian geometries.
+    pub fn right_wall_potential(mut self, potential: ExternalPotential) -> Self {
+        self.right_wall_potential = Some(potential);
+        self
+    }
+
+    /// Estimate the grand potential in the limit of infinite grid resolution.
+    ///
+    /// The pore is solved with `n_grid` grid points and with two and four
+    /// times as many grid points. Each refined profile is initialized with
+    /// the interpolated solution on the coarser grid. Returns the
+    /// Richardson-extrapolated grand potential and the estimated error of the
+    /// grand potential on the finest grid.
+    pub fn richardson_extrapolation<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<(Energy, Energy)> {
+        let mut n_grid = self.n_grid.unwrap_or(DEFAULT_GRID_POINTS);
+        let mut pore = self
+            .initialize(bulk, InitialGuess::Bulk, None)?
+            .solve(solver)?;
+        let mut grand_potentials = [pore.grand_potential.unwrap().to_reduced(), 0.0, 0.0];
+        for omega in grand_potentials.iter_mut().skip(1) {
+            n_grid *= 2;
+            let spec = Self {
+                n_grid: Some(n_grid),
+                ..self.clone()
+            };
+            let mut refined = spec.initialize(bulk, InitialGuess::Bulk, None)?;
+            refined.profile.density = pore.profile.grid.axes()[0]
+                .interpolate_density(&pore.profile.density, refined.profile.grid.grids()[0]);
+            pore = refined.solve(solver)?;
+            *omega = pore.grand_potential.unwrap().to_reduced();
+        }
+        let (omega, error) = richardson_extrapolation(grand_potentials);
+        Ok((Energy::from_reduced(omega), Energy::from_reduced(error)))
+    }
+
+    /// Evaluate the convolutions on a grid that is coarsened by `factor`.
+    ///
+    /// This trades accuracy for speed, see [DFTProfile::coarsen_convolution].
+    /// Only available for slit pores with a number of grid points that is
+    /// divisible by `factor`.
+    pub fn convolver_coarsening(mut self, factor: usize) -> Self {
+        self.convolver_coarsening = Some(factor);
+        self
+    }
+
+    /// Condensation pressure of the pore predicted by the Kelvin equation.
+    ///
+    /// The surface tension, the liquid molar volume and the saturation pressure are
+    /// taken from the solved planar interface. Assuming complete wetting, the
+    /// pressure follows from
+    /// $$\ln\frac{p}{p^\mathrm{sat}}=-\frac{2\gamma v^\mathrm{L}}{RTH}$$
+    /// where $H$ is the pore width for slit pores and the pore radius for cylindrical
+    /// (hemispherical meniscus) and spherical pores.
+    pub fn kelvin_condensation_pressure<F: HelmholtzEnergyFunctional>(
+        &self,
+        interface: &PlanarInterface<F>,
+    ) -> FeosResult<Pressure> {
+        let gamma = interface.surface_tension.ok_or_else(|| {
+            FeosError::Error(String::from(
+                "The planar interface has to be solved to calculate the Kelvin pressure.",
+            ))
+        })?;
+        let liquid = interface.vle.liquid();
+        let saturation_pressure = interface.vle.vapor().pressure(Contributions::Total);
+        // the pore size is the width of slit pores (cylindrical meniscus with radius H/2)
+        // and the radius of cylindrical and spherical pores (spherical meniscus with radius H)
+        let exponent = -2.0 * gamma / (liquid.density * RGAS * liquid.temperature * self.pore_size);
+        Ok(saturation_pressure * exponent.into_value().exp())
+    }
+}
+
+/// Initial guess for the density profile of a pore.
+///
+/// For pores that show capillary condensation, the initial guess determines
+/// whether the solver converges to the empty (adsorption) or the filled
+/// (desorption) branch of the isotherm.
+#[derive(Clone, Copy)]
+pub enum InitialGuess<'a, D: Dimension> {
+    /// Densities of the bulk phase weighted with the Boltzmann factor of the
+    /// external potential.
+    Bulk,
+    /// Densities of an ideal gas at the fugacities of the bulk phase weighted
+    /// with the Boltzmann factor of the external potential, i.e., a vapor-like
+    /// pore even if the bulk phase is a liquid.
+    Empty,
+    /// An explicit density profile, e.g., the solution at a neighboring state.
+    Explicit(&'a Density<Array<f64, D::Larger>>),
+}
+
+impl<'a, D: Dimension> From<Option<&'a Density<Array<f64, D::Larger>>>> for InitialGuess<'a, D> {
+    fn from(density: Option<&'a Density<Array<f64, D::Larger>>>) -> Self {
+        density.map_or(Self::Bulk, Self::Explicit)
+    }
+}
+
+impl<'a, D: Dimension> InitialGuess<'a, D>
+where
+    D::Larger: Dimension<Smaller = D>,
+{
+    /// The explicit density profile, if any.
+    pub(crate) fn density(self) -> Option<&'a Density<Array<f64, D::Larger>>> {
+        match self {
+            Self::Explicit(density) => Some(density),
+            _ => None,
+        }
+    }
+
+    /// Rescale the Boltzmann-weighted bulk densities of a new profile to the
+    /// ideal gas densities for an empty pore.
+    pub(crate) fn apply<F: HelmholtzEnergyFunctional>(self, profile: &mut DFTProfile<D, F>) {
+        if let Self::Empty = self {
+            let bulk = &profile.bulk;
+            let mu_res =
+                (bulk.residual_chemical_potential() / (RGAS * bulk.temperature)).into_value();
+            let mut density = profile.density.to_reduced();
+            for (mut rho, &c) in density
+                .outer_iter_mut()
+                .zip(bulk.eos.component_index().iter())
+            {
+                rho *= mu_res[c].exp();
+            }
+            profile.density = Density::from_reduced(density);
         }
     }
 }
@@ -57,31 +232,116 @@ impl Pore1D {
 /// Trait for the generic implementation of adsorption applications.
 pub trait PoreSpecification<D: Dimension> {
     /// Initialize a new single pore.
+    ///
+    /// The density profile is initialized according to `initial_guess`, see
+    /// [InitialGuess].
     fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
         &self,
         bulk: &State<F>,
-        density: Option<&Density<Array<f64, D::Larger>>>,
+        initial_guess: InitialGuess<'_, D>,
         external_potential: Option<&Array<f64, D::Larger>>,
     ) -> FeosResult<PoreProfile<D, F>>;
 
+    /// Initialize a new single pore in equilibrium with a bulk phase at the
+    /// given temperature and fugacities.
+    ///
+    /// The bulk state is the vapor-like solution of
+    /// $f_i=\rho_iRT\exp\left(\mu_i^\mathrm{res}/RT\right)$, see
+    /// [bulk_from_fugacity].
+    fn initialize_fugacity<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        functional: &F,
+        temperature: Temperature,
+        fugacity: &Pressure<DVector<f64>>,
+        initial_guess: InitialGuess<'_, D>,
+        external_potential: Option<&Array<f64, D::Larger>>,
+    ) -> FeosResult<PoreProfile<D, F>> {
+        let bulk = bulk_from_fugacity(functional, temperature, fugacity)?;
+        self.initialize(&bulk, initial_guess, external_potential)
+    }
+
     /// Return the pore volume using Helium at 298 K as reference.
     fn pore_volume(&self) -> FeosResult<Volume>
     where
         D::Larger: Dimension<Smaller = D>,
     {
-        let bulk = StateBuilder::new(&&Helium)
-            .temperature(298.0 * KELVIN)
-            .density(Density::from_reduced(1.0))
+        helium_pore_volume(self)
+    }
+}
+
+/// Bulk state with the given temperature and fugacities.
+///
+/// Starting from an ideal gas, the partial densities are determined from
+/// $\ln\rho_i+\mu_i^\mathrm{res}/RT=\ln\left(f_i/RT\right)$ with a Newton
+/// iteration in the logarithmic densities, which converges to the vapor-like
+/// solution if several bulk phases have the same fugacities.
+pub fn bulk_from_fugacity<F: HelmholtzEnergyFunctional>(
+    functional: &F,
+    temperature: Temperature,
+    fugacity: &Pressure<DVector<f64>>,
+) -> FeosResult<State<F>> {
+    if fugacity.len() != functional.components() {
+        return Err(FeosError::IncompatibleComponents(
+            functional.components(),
+            fugacity.len(),
+        ));
+    }
+    let rt = (RGAS * temperature).to_reduced();
+    let ln_rho_ig = fugacity.to_reduced().map(|f| (f / rt).ln());
+    if ln_rho_ig.iter().any(|x| !x.is_finite()) {
+        return Err(FeosError::Error(String::from(
+            "The fugacities have to be positive and finite.",
+        )));
+    }
+
+    let mut ln_rho = ln_rho_ig.clone();
+    for _ in 0..MAX_ITER_FUGACITY {
+        let partial_density = Density::from_reduced(ln_rho.map(f64::exp));
+        let bulk = StateBuilder::new(functional)
+            .temperature(temperature)
+            .partial_density(&partial_density)
             .build()?;
-        let pore = self.initialize(&bulk, None, None)?;
-        let pot = Dimensionless::from_reduced(
-            pore.profile
-                .external_potential
-                .index_axis(Axis(0), 0)
-                .mapv(|v| (-v).exp()),
-        );
-        Ok(pore.profile.integrate(&pot))
+        let mu_res = bulk.residual_chemical_potential().to_reduced() / rt;
+        let residual = &ln_rho + mu_res - &ln_rho_ig;
+        if residual.norm() < TOL_FUGACITY {
+            return Ok(bulk);
+        }
+
+        // derivatives with respect to the logarithmic densities
+        let dmu_dn = bulk.dmu_dni(Contributions::Residual).to_reduced();
+        let v = bulk.volume.to_reduced();
+        let jacobian = DMatrix::from_fn(ln_rho.len(), ln_rho.len(), |i, j| {
+            let delta = if i == j { 1.0 } else { 0.0 };
+            delta + dmu_dn[(i, j)] * v * partial_density.get(j).to_reduced() / rt
+        });
+        let delta = jacobian.lu().solve(&residual).ok_or_else(|| {
+            FeosError::Error(String::from("The bulk density iteration is singular."))
+        })?;
+        // limit the step size to stay on the vapor-like branch
+        let step = delta.amax().max(MAX_STEP_FUGACITY) / MAX_STEP_FUGACITY;
+        ln_rho -= delta / step;
     }
+    Err(FeosError::NotConverged(String::from("bulk_from_fugacity")))
+}
+
+fn helium_pore_volume<D: Dimension, S: PoreSpecification<D> + ?Sized>(
+    pore: &S,
+) -> FeosResult<Volume>
+where
+    D::Larger: Dimension<Smaller = D>,
+{
+    let bulk = StateBuilder::new(&&Helium)
+        .temperature(298.0 * KELVIN)
+        .density(Density::from_reduced(1.0))
+        .build()?;
+    let pore = pore.initialize(&bulk, InitialGuess::Bulk, None)?;
+    let pot = Dimensionless::from_reduced(
+        pore.profile
+            .external_potential
+            .index_axis(Axis(0), 0)
+            .mapv(|v| (-v).exp()),
+    );
+    Ok(pore.profile.integrate(&pot))
 }
 
 /// Density profile and properties of a confined system in arbitrary dimensions.
@@ -90,6 +350,8 @@ pub struct PoreProfile<D: Dimension, F> {
     pub profile: DFTProfile<D, F>,
     pub grand_potential: Option<Energy>,
     pub interfacial_tension: Option<Energy>,
+    /// Warning about a grid that is too coarse to resolve the segment diameters.
+    pub grid_spacing_warning: Option<String>,
 }
 
 /// Density profile and properties of a 1D confined system.
@@ -113,6 +375,10 @@ where
         self.interfacial_tension =
             Some(omega + self.profile.bulk.pressure(Contributions::Total) * self.profile.volume());
 
+        let verbosity = solver.map_or(Verbosity::None, |solver| solver.verbosity);
+        if let Some(warning) = &self.grid_spacing_warning {
+            log_result!(verbosity, "Warning: {warning}");
+        }
         Ok(())
     }
 
@@ -121,6 +387,88 @@ where
         Ok(self)
     }
 
+    /// Solve the pore with the tolerance and the maximum number of iterations
+    /// of the solver overridden for this call.
+    ///
+    /// The overrides apply to the last algorithm of the solver, which decides
+    /// whether the profile is converged, see [DFTSolver::tolerance] and
+    /// [DFTSolver::max_iter].
+    pub fn solve_with_tolerance(
+        mut self,
+        solver: Option<&DFTSolver>,
+        tol: Option<f64>,
+        max_iter: Option<usize>,
+    ) -> FeosResult<Self> {
+        let solver = DFTSolver::with_overrides(solver, tol, max_iter);
+        self.solve_inplace(Some(&solver), false)?;
+        Ok(self)
+    }
+
+    /// Fix the density profiles of the given components, e.g., of a solvent
+    /// or a scaffold, while the remaining components equilibrate.
+    ///
+    /// See [DFTProfile::freeze_components] for details.
+    pub fn freeze_components(
+        mut self,
+        components: &[usize],
+        density: &Density<Array<f64, D::Larger>>,
+    ) -> FeosResult<Self> {
+        self.profile.freeze_components(components, density)?;
+        self.grand_potential = None;
+        self.interfacial_tension = None;
+        Ok(self)
+    }
+
+    /// Fix the number of moles of each component in the pore, e.g., to model
+    /// a closed pore with a prescribed loading.
+    ///
+    /// The density profiles are scaled to the given numbers of moles and the
+    /// bulk densities are iterated together with the density profiles, see
+    /// [DFTSpecifications::Moles]. After solving the profile, `profile.bulk`
+    /// is the bulk fluid in equilibrium with the confined fluid and
+    /// determines the pressure and the chemical potentials of the closed pore.
+    ///
+    /// Returns an error if the current density profile of a component
+    /// contains no moles and therefore cannot be scaled.
+    pub fn fix_moles_inplace(&mut self, moles: &Moles<DVector<f64>>) -> FeosResult<()> {
+        let components = self.profile.bulk.eos.components();
+        if moles.len() != components {
+            return Err(FeosError::IncompatibleComponents(components, moles.len()));
+        }
+        let moles = moles.to_reduced();
+        if moles.iter().any(|&n| n <= 0.0) {
+            return Err(FeosError::Error(String::from(
+                "The numbers of moles in a closed pore have to be positive.",
+            )));
+        }
+        let component_index = self.profile.bulk.eos.component_index().into_owned();
+        let current = self.profile.moles().to_reduced();
+        if let Some(i) = current.iter().position(|n| !(n.is_finite() && *n > 0.0)) {
+            return Err(FeosError::Error(format!(
+                "The density profile of component {i} contains no moles and cannot be scaled to the loading of the closed pore."
+            )));
+        }
+        let mut density = self.profile.density.to_reduced();
+        density
+            .outer_iter_mut()
+            .zip(component_index.iter())
+            .for_each(|(mut rho, &i)| rho *= moles[i] / current[i]);
+        self.profile.density = Density::from_reduced(density);
+        self.profile.specification = Arc::new(DFTSpecifications::Moles {
+            moles: component_index.iter().map(|&i| moles[i]).collect(),
+        });
+        self.grand_potential = None;
+        self.interfacial_tension = None;
+        Ok(())
+    }
+
+    /// Fix the number of moles of each component in the pore, see
+    /// [PoreProfile::fix_moles_inplace].
+    pub fn fix_moles(mut self, moles: &Moles<DVector<f64>>) -> FeosResult<Self> {
+        self.fix_moles_inplace(moles)?;
+        Ok(self)
+    }
+
     pub fn update_bulk(mut self, bulk: &State<F>) -> Self {
         self.profile.bulk = bulk.clone();
         self.grand_potential = None;
@@ -128,6 +476,313 @@ where
         self
     }
 
+    /// Excess (Gibbs) adsorption of each component, i.e., the absolute number of
+    /// moles in the pore minus the bulk density times the (Helium) pore volume.
+    ///
+    /// The reference is the real bulk fluid, see [PoreProfile::ideal_excess_moles]
+    /// for an ideal gas as reference.
+    pub fn excess_moles<S: PoreSpecification<D>>(
+        &self,
+        pore: &S,
+    ) -> FeosResult<Moles<DVector<f64>>> {
+        Ok(self.profile.moles() - &self.profile.bulk.partial_density * pore.pore_volume()?)
+    }
+
+    /// Excess adsorption of each component with an ideal gas as reference,
+    /// i.e., the absolute number of moles in the pore minus the density of an
+    /// ideal gas at the same temperature and chemical potential as the bulk
+    /// times the (Helium) pore volume.
+    ///
+    /// The ideal gas density is $\rho_i^\mathrm{ig}=\rho_i\exp\left(\mu_i^\mathrm{res}/RT\right)$.
+    /// Both conventions coincide at low pressures. At high pressures, this one
+    /// corresponds to simulation studies that report the excess with respect
+    /// to an ideal gas.
+    pub fn ideal_excess_moles<S: PoreSpecification<D>>(
+        &self,
+        pore: &S,
+    ) -> FeosResult<Moles<DVector<f64>>> {
+        let bulk = &self.profile.bulk;
+        let mu_res = bulk.residual_chemical_potential() / (RGAS * bulk.temperature);
+        let rho_ig = Density::from_reduced(
+            bulk.partial_density
+                .to_reduced()
+                .component_mul(&mu_res.into_value().map(f64::exp)),
+        );
+        Ok(self.profile.moles() - rho_ig * pore.pore_volume()?)
+    }
+
+    /// Average partial densities of the adsorbed phase, i.e., the absolute
+    /// number of moles in the pore divided by the (Helium) pore volume.
+    ///
+    /// For filled pores, the result can be compared to the bulk liquid density
+    /// to check whether a pore model is filled to a physically reasonable
+    /// density. For partially filled pores, the result is still well defined but
+    /// is an average over the whole accessible volume that includes adsorbed
+    /// layers and vapor-like regions, not a local liquid density.
+    pub fn adsorbed_phase_density<S: PoreSpecification<D>>(
+        &self,
+        pore: &S,
+    ) -> FeosResult<Density<DVector<f64>>> {
+        Ok(self.profile.moles() / pore.pore_volume()?)
+    }
+
+    /// Excess grand potential $\Omega+pV$ of the pore with the volume $V$ of
+    /// the calculation domain as reference.
+    ///
+    /// The result is an energy and is not divided by the area of the walls.
+    /// For solved profiles it is identical to `interfacial_tension`, which
+    /// stores the same quantity. The grand potential is evaluated from the
+    /// current density profile without solving it again.
+    ///
+    /// The excess grand potential is the solvation free energy of the walls,
+    /// i.e., the reversible work to insert them into the bulk fluid, and drives
+    /// the interaction between surfaces. Symmetric slit pores are solved for
+    /// one half of the pore, so that the potential of mean force of two walls
+    /// at a distance $L$ is
+    /// $W(L)=2\left(\Omega^\mathrm{ex}(L)-\Omega^\mathrm{ex}(\infty)\right)$. Asymmetric
+    /// slit pores (see [Pore1D::right_wall_potential]) contain both walls and
+    /// $W(L)=\Omega^\mathrm{ex}(L)-\Omega^\mathrm{ex}(\infty)$. The solvation force
+    /// is $f=-\frac{\mathrm{d}W}{\mathrm{d}L}$.
+    pub fn excess_grand_potential(&self) -> FeosResult<Energy> {
+        Ok(self.profile.grand_potential()?
+            + self.profile.bulk.pressure(Contributions::Total) * self.profile.volume())
+    }
+
+    /// Excess Gibbs energy of the adsorbed phase with the (Helium) pore volume
+    /// as reference.
+    ///
+    /// The Gibbs energy $F+pV$ of the confined fluid is compared to the Gibbs
+    /// energy $\sum_i\mu_iN_i$ of the same amount of bulk fluid, i.e.,
+    /// $$G^\mathrm{ex}=F+pV-\sum_i\mu_iN_i=\Omega+pV$$
+    /// The grand potential is evaluated from the current density profile, so the
+    /// profile has to be solved, but it is not solved again.
+    pub fn excess_gibbs_energy<S: PoreSpecification<D>>(&self, pore: &S) -> FeosResult<Energy> {
+        Ok(self.profile.grand_potential()?
+            + self.profile.bulk.pressure(Contributions::Total) * pore.pore_volume()?)
+    }
+
+    /// Helmholtz energy $F=\Omega+\sum_i\mu_iN_i$ of the confined fluid minus
+    /// the Helmholtz energy of the same amount of fluid at the partial
+    /// densities `reference_density`.
+    ///
+    /// The thermal de Broglie wavelength cancels and is omitted in both terms.
+    fn excess_helmholtz_energy(
+        &self,
+        reference_density: &Density<DVector<f64>>,
+    ) -> FeosResult<Energy> {
+        let bulk = &self.profile.bulk;
+        let rt = (RGAS * bulk.temperature).to_reduced();
+        let moles = self.profile.moles();
+        let reference = StateBuilder::new(&bulk.eos)
+            .temperature(bulk.temperature)
+            .partial_density(reference_density)
+            .build()?;
+        let ln_rho = bulk.partial_density.to_reduced().map(f64::ln);
+        let ln_rho_ref = reference_density.to_reduced().map(f64::ln);
+        let mu = bulk.residual_chemical_potential().to_reduced() + ln_rho * rt;
+        let f_ref = reference.residual_molar_helmholtz_energy().to_reduced()
+            * moles.sum().to_reduced()
+            + rt * (ln_rho_ref.add_scalar(-1.0)).dot(&moles.to_reduced());
+        Ok(self.profile.grand_potential()?
+            + Energy::from_reduced(mu.dot(&moles.to_reduced()) - f_ref))
+    }
+
+    /// Excess isochoric heat capacity of the confined fluid.
+    ///
+    /// The isochoric heat capacity of the closed pore
+    /// $C_V=-T\left(\frac{\partial^2F}{\partial T^2}\right)_{V,N_i}$ is
+    /// approximated with central finite differences of the Helmholtz energy
+    /// of profiles that are solved at $T\pm\Delta T$ with the same numbers of
+    /// particles. The Helmholtz energy of the same amount of fluid at the
+    /// bulk densities is subtracted, so that the result is the heat capacity
+    /// of the confined fluid minus the residual isochoric heat capacity of the
+    /// bulk fluid and the ideal gas contributions cancel. The profile has to be
+    /// solved. The numbers of particles are only conserved within the
+    /// tolerance of the solver, which therefore has to be small compared to
+    /// the densities for dilute fluids.
+    ///
+    /// Returns an error if any of the profiles at the neighboring temperatures
+    /// does not converge.
+    pub fn excess_isochoric_heat_capacity<S: PoreSpecification<D>>(
+        &self,
+        pore: &S,
+        delta_temperature: Temperature,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<Entropy>
+    where
+        F: FluidParameters,
+    {
+        let t = self.profile.temperature;
+        let rho_bulk = &self.profile.bulk.partial_density;
+        let specification = Arc::new(DFTSpecifications::moles_from_profile(&self.profile));
+        let excess_helmholtz_energy = |temperature: Temperature| -> FeosResult<Energy> {
+            let bulk = StateBuilder::new(&self.profile.bulk.eos)
+                .temperature(temperature)
+                .partial_density(rho_bulk)
+                .build()?;
+            let mut profile =
+                pore.initialize(&bulk, InitialGuess::Explicit(&self.profile.density), None)?;
+            profile.profile.specification = specification.clone();
+            profile
+                .solve(solver)
+                .map_err(|e| {
+                    FeosError::Error(format!(
+                        "The heat capacity could not be calculated, because the profile at {temperature} did not converge: {e}"
+                    ))
+                })?
+                .excess_helmholtz_energy(rho_bulk)
+        };
+        let f_plus = excess_helmholtz_energy(t + delta_temperature)?;
+        let f_minus = excess_helmholtz_energy(t - delta_temperature)?;
+        let f = self.excess_helmholtz_energy(rho_bulk)?;
+        Ok(-t * (f_plus - 2.0 * f + f_minus) / (delta_temperature * delta_temperature))
+    }
+
+    /// Weighted densities of the functional contributions in the pore, see
+    /// [DFTProfile::weighted_densities_by_contribution].
+    #[expect(clippy::type_complexity)]
+    pub fn weighted_densities(&self) -> FeosResult<Vec<(&'static str, Array<f64, D::Larger>)>> {
+        self.profile.weighted_densities_by_contribution()
+    }
+
+    /// External potential acting on each segment in units of $k_\mathrm{B}T$.
+    pub fn external_potential(&self) -> &Array<f64, D::Larger> {
+        &self.profile.external_potential
+    }
+
+    /// External potential acting on each segment in units of energy.
+    pub fn external_potential_energy(&self) -> Energy<Array<f64, D::Larger>> {
+        Energy::from_reduced(
+            &self.profile.external_potential * self.profile.temperature.to_reduced(),
+        )
+    }
+
+    /// Insertion free energy
+    /// $w_\alpha(\mathbf{r})=-k_\mathrm{B}T\ln\frac{\rho_\alpha(\mathbf{r})}{\rho_\alpha^\mathrm{b}}-V_\alpha^\mathrm{ext}(\mathbf{r})$
+    /// of each segment, i.e., the reversible work to insert a test particle
+    /// at $\mathbf{r}$ in excess of the external potential.
+    ///
+    /// The insertion free energy can be compared to test-particle insertions
+    /// in molecular simulations. For spherical molecules, it equals the
+    /// difference of the local and the bulk excess chemical potential for a
+    /// converged profile, see [PoreProfile::excess_chemical_potential_difference].
+    /// Grid points with vanishing densities or overwhelming external
+    /// potentials are NaN.
+    pub fn insertion_free_energy(&self) -> Energy<Array<f64, D::Larger>> {
+        let density = self.profile.density.to_reduced();
+        let partial_density = self.profile.bulk.partial_density.to_reduced();
+        let component_index = self.profile.bulk.eos.component_index();
+        let mut w = Array::from_elem(density.raw_dim(), f64::NAN);
+        for (s, &i) in component_index.iter().enumerate() {
+            Zip::from(w.index_axis_mut(Axis_nd(0), s))
+                .and(density.index_axis(Axis_nd(0), s))
+                .and(self.profile.external_potential.index_axis(Axis_nd(0), s))
+                .for_each(|w, &rho, &v| {
+                    if rho > 0.0 && v < MAX_POTENTIAL {
+                        *w = -(rho / partial_density[i]).ln() - v;
+                    }
+                });
+        }
+        Energy::from_reduced(w * self.profile.temperature.to_reduced())
+    }
+
+    /// Difference of the local excess chemical potential
+    /// $\frac{\delta F^\mathrm{res}}{\delta\rho_\alpha(\mathbf{r})}$ and its
+    /// bulk value for each segment.
+    ///
+    /// For a converged profile of spherical molecules, the result agrees with
+    /// [PoreProfile::insertion_free_energy], so their difference vanishes
+    /// everywhere in the pore and provides a strong consistency check of the
+    /// density profile. For chain molecules, the insertion free energy
+    /// additionally contains the contributions of the bonds.
+    pub fn excess_chemical_potential_difference(
+        &self,
+    ) -> FeosResult<Energy<Array<f64, D::Larger>>> {
+        let temperature = self.profile.temperature.to_reduced();
+        let partial_density = self.profile.bulk.partial_density.to_reduced();
+        let bulk_density: Array1<f64> = self
+            .profile
+            .bulk
+            .eos
+            .component_index()
+            .iter()
+            .map(|&i| partial_density[i])
+            .collect();
+        let bulk_convolver =
+            BulkConvolver::new(self.profile.bulk.eos.weight_functions(temperature));
+        let (_, dfdrho_bulk) = self.profile.bulk.eos.functional_derivative(
+            temperature,
+            &bulk_density,
+            bulk_convolver.as_ref(),
+        )?;
+        let mut dfdrho = self.profile.functional_derivative()?;
+        dfdrho
+            .outer_iter_mut()
+            .zip(dfdrho_bulk)
+            .for_each(|(mut df, df_b)| df -= df_b);
+        Ok(Energy::from_reduced(dfdrho * temperature))
+    }
+
+    /// Ideal gas, external potential and excess contributions to the grand
+    /// potential density $\omega(\mathbf{r})$ in the pore.
+    ///
+    /// The ideal gas contribution
+    /// $k_\mathrm{B}T\sum_\alpha\rho_\alpha\left(\ln\frac{\rho_\alpha}{\rho_\alpha^\mathrm{b}}-1\right)$
+    /// and the external potential contribution
+    /// $\sum_\alpha\rho_\alpha V_\alpha^\mathrm{ext}$ are evaluated
+    /// explicitly. The excess contribution
+    /// $f^\mathrm{res}-\sum_\alpha\rho_\alpha\mu_\alpha^\mathrm{res}$ is the
+    /// remainder of [DFTProfile::grand_potential_density], so the three
+    /// contributions sum up to the total grand potential density. In the bulk
+    /// region of a pore, the external potential contribution vanishes and the
+    /// ideal gas and excess contributions approach the negative ideal gas
+    /// and residual pressures, respectively.
+    ///
+    /// Untested with heterosegmented functionals.
+    #[expect(clippy::type_complexity)]
+    pub fn grand_potential_density_contributions(
+        &self,
+    ) -> FeosResult<(
+        Pressure<Array<f64, D>>,
+        Pressure<Array<f64, D>>,
+        Pressure<Array<f64, D>>,
+    )> {
+        let t = self.profile.temperature.to_reduced();
+        let rho = self.profile.density.to_reduced();
+        let rho_bulk = self.profile.bulk.partial_density.to_reduced();
+        let component_index = self.profile.bulk.eos.component_index();
+        let omega = self.profile.grand_potential_density()?.to_reduced();
+
+        let mut ideal = Array::zeros(omega.raw_dim());
+        let mut external = Array::zeros(omega.raw_dim());
+        for ((rho, v), &c) in rho
+            .outer_iter()
+            .zip(self.profile.external_potential.outer_iter())
+            .zip(component_index.iter())
+        {
+            // regions without fluid (e.g., inside of the walls) do not contribute
+            ideal.zip_mut_with(&rho, |f, &rho| {
+                if rho > 0.0 {
+                    *f += rho * ((rho / rho_bulk[c]).ln() - 1.0) * t
+                }
+            });
+            Zip::from(&mut external)
+                .and(&rho)
+                .and(&v)
+                .for_each(|f, &rho, &v| {
+                    if rho > 0.0 {
+                        *f += rho * v * t
+                    }
+                });
+        }
+        let excess = omega - &ideal - &external;
+        Ok((
+            Pressure::from_reduced(ideal),
+            Pressure::from_reduced(external),
+            Pressure::from_reduced(excess),
+        ))
+    }
+
     pub fn partial_molar_enthalpy_of_adsorption(&self) -> FeosResult<MolarEnergy<DVector<f64>>> {
         let a = self.profile.dn_dmu()?;
         let a_unit = a.get2(0, 0);
@@ -159,8 +814,7 @@ where
             .into_iter()
             .map(|c| c.weight_functions(temperature))
             .collect();
-        let convolver =
-            ConvolverFFT::<_, D>::plan(&self.profile.grid, &weight_functions, self.profile.lanczos);
+        let convolver = self.profile.plan_convolver(&weight_functions);
         let bonds = self
             .profile
             .bulk
@@ -185,41 +839,327 @@ where
     }
 }
 
+impl<F: HelmholtzEnergyFunctional> PoreProfile1D<F> {
+    /// Density of each segment at the center of the pore.
+    ///
+    /// For all geometries of [Pore1D] the pore center is located at $z=0$ and
+    /// the density at the grid point closest to the center is returned.
+    pub fn center_density(&self) -> FeosResult<Density<Array1<f64>>> {
+        let center = self.profile.grid.grids()[0]
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+            .map(|(i, _)| i)
+            .ok_or_else(|| {
+                FeosError::Error(String::from(
+                    "The density profile does not contain any grid points.",
+                ))
+            })?;
+        Ok(self
+            .profile
+            .density
+            .index_axis(Axis_nd(1), center)
+            .to_owned())
+    }
+
+    /// Check whether the density at the pore center deviates from the bulk density
+    /// by less than the relative tolerance `tol` for every segment.
+    ///
+    /// If true, the pore is large enough to be treated as two independent walls
+    /// rather than a confined fluid.
+    pub fn is_bulk_core(&self, tol: f64) -> FeosResult<bool> {
+        let rho_center = self.center_density()?.into_reduced();
+        let rho_bulk = self.profile.bulk.partial_density.to_reduced();
+        Ok(self
+            .profile
+            .bulk
+            .eos
+            .component_index()
+            .iter()
+            .zip(rho_center)
+            .all(|(&c, rho)| ((rho - rho_bulk[c]) / rho_bulk[c]).abs() < tol))
+    }
+
+    /// Depletion thickness of each component at purely repulsive walls, i.e.,
+    /// the negative excess adsorption per area divided by the bulk density.
+    ///
+    /// The excess adsorption is calculated with [PoreProfile::excess_moles]
+    /// and is negative if the fluid is depleted at the wall, so that the
+    /// depletion thickness is positive and corresponds to the width of the
+    /// volume that is effectively excluded in addition to the (Helium) pore
+    /// volume. At attractive walls, the result is negative. The area is the
+    /// cross section of the calculation domain, i.e., the result is the
+    /// thickness at a single wall for [Wall1D](super::Wall1D) and symmetric
+    /// slit pores and the sum over both walls for slit pores with different
+    /// walls. Only available for slit pores.
+    pub fn depletion_thickness<S: PoreSpecification<Ix1>>(
+        &self,
+        pore: &S,
+    ) -> FeosResult<Length<DVector<f64>>> {
+        if self.profile.grid.axes()[0].geometry != Geometry::Cartesian {
+            return Err(FeosError::Error(String::from(
+                "The depletion thickness is only available for slit pores.",
+            )));
+        }
+        let excess = self.excess_moles(pore)?.to_reduced();
+        let rho_bulk = self.profile.bulk.partial_density.to_reduced();
+        Ok(Length::from_reduced(-excess.component_div(&rho_bulk)))
+    }
+
+    /// Tangential component of the pressure tensor $p_\mathrm{T}(z)=-\omega(z)$
+    /// in slit pores.
+    ///
+    /// The integral of the tangential pressure over the calculation domain is
+    /// the negative grand potential per area. Together with the normal
+    /// pressure, which is constant across the pore, it determines the
+    /// solvation force acting on the walls.
+    pub fn tangential_pressure_profile(&self) -> FeosResult<Pressure<Array1<f64>>> {
+        if self.profile.grid.axes()[0].geometry != Geometry::Cartesian {
+            return Err(FeosError::Error(String::from(
+                "The tangential pressure profile is only available for slit pores.",
+            )));
+        }
+        Ok(-self.profile.grand_potential_density()?)
+    }
+
+    /// Normal and tangential components of the pressure tensor of the fluid
+    /// in slit pores.
+    ///
+    /// The normal pressure follows from the mechanical equilibrium
+    /// $\frac{\mathrm{d}p_\mathrm{N}}{\mathrm{d}z}=-\sum_i\rho_i(z)\frac{\mathrm{d}V_i^\mathrm{ext}}{\mathrm{d}z}$
+    /// integrated from the end of the calculation domain, where the density
+    /// vanishes inside the wall. The normal pressure at the pore center is
+    /// the force per area that the fluid exerts on the walls (if the wall
+    /// potentials do not reach across the pore), i.e., the solvation force
+    /// is its difference to the bulk pressure. The tangential pressure is
+    /// given by [PoreProfile1D::tangential_pressure_profile]. Not available
+    /// for heterosegmented functionals.
+    #[expect(clippy::type_complexity)]
+    pub fn stress_profile(&self) -> FeosResult<(Pressure<Array1<f64>>, Pressure<Array1<f64>>)> {
+        let tangential_pressure = self.tangential_pressure_profile()?;
+        let t = self.profile.temperature.to_reduced();
+        let axis = &self.profile.grid.axes()[0];
+        let z = &axis.grid;
+        let n = z.len();
+
+        // the force density of the external potential is expressed with the
+        // Euler-Lagrange equation as -m_i∇ρ_i-ρ_i∇(δF_res/δρ_i), which avoids
+        // differentiating the (steep) wall potential
+        if let MoleculeShape::Heterosegmented(_) = self.profile.bulk.eos.molecule_shape() {
+            return Err(FeosError::Error(String::from(
+                "The normal pressure profile is not available for heterosegmented functionals.",
+            )));
+        }
+        let rho = self.profile.density.to_reduced();
+        let dfdrho = self.profile.functional_derivative()?;
+        let mut normal_pressure = Array1::<f64>::zeros(n);
+        for ((rho, df), &m) in rho
+            .outer_iter()
+            .zip(dfdrho.outer_iter())
+            .zip(self.profile.bulk.eos.m().iter())
+        {
+            // integrate ρ_i∇(δF_res/δρ_i) from the end of the domain; in
+            // symmetric pores, the profiles are mirrored at the pore center
+            let mut p = 0.0;
+            for k in (0..n).rev() {
+                let (z_l, df_l) = match k {
+                    0 if axis.edges[0] == 0.0 => (-z[0], df[0]),
+                    0 => (z[0], df[0]),
+                    _ => (z[k - 1], df[k - 1]),
+                };
+                let (z_r, df_r) = if k + 1 < n {
+                    (z[k + 1], df[k + 1])
+                } else {
+                    (z[k], df[k])
+                };
+                let dp = rho[k] * (df_r - df_l) / (z_r - z_l) * (axis.edges[k + 1] - axis.edges[k]);
+                normal_pressure[k] += m * rho[k] - p - 0.5 * dp;
+                p += dp;
+            }
+        }
+        Ok((
+            Pressure::from_reduced(normal_pressure * t),
+            tangential_pressure,
+        ))
+    }
+
+    /// Radius of the liquid-vapor meniscus in cylindrical pores.
+    ///
+    /// The meniscus is located at the innermost radius at which the total
+    /// density increases beyond the mean of the coexisting liquid and vapor
+    /// densities of `vle`, i.e., at the surface of the liquid film that
+    /// surrounds a vapor core. The radius can be compared to the Kelvin radius
+    /// of the classical theory of capillary condensation. Returns `None` if the
+    /// density profile does not contain a vapor core and a liquid film.
+    pub fn meniscus_radius(&self, vle: &PhaseEquilibrium<F, 2>) -> FeosResult<Option<Length>> {
+        if self.profile.grid.axes()[0].geometry != Geometry::Cylindrical {
+            return Err(FeosError::Error(String::from(
+                "The meniscus radius is only available for cylindrical pores.",
+            )));
+        }
+        let r = self.profile.grid.grids()[0];
+        let rho = self.profile.density.sum_axis(Axis_nd(0)).to_reduced();
+        let rho_mid = 0.5 * (vle.liquid().density + vle.vapor().density).to_reduced();
+        if rho[0] >= rho_mid {
+            return Ok(None);
+        }
+        Ok((1..r.len()).find(|&i| rho[i] >= rho_mid).map(|i| {
+            let x = (rho_mid - rho[i - 1]) / (rho[i] - rho[i - 1]);
+            Length::from_reduced(r[i - 1] + x * (r[i] - r[i - 1]))
+        }))
+    }
+
+    /// Wavelength and number of the layers of the confined fluid at the wall.
+    ///
+    /// Starting at the wall (for slit pores with different walls, the wall at
+    /// positive $z$), the local maxima of the total density are identified.
+    /// A maximum is counted as a layer if it exceeds the adjacent minima by
+    /// more than `tol` times the total bulk density; the search stops at the
+    /// first maximum that does not. The positions of the maxima are refined
+    /// by parabolas through the neighboring grid points and the wavelength is
+    /// the mean distance between consecutive layers. If the grid resolves the
+    /// layering, the wavelength is close to the segment diameter. Returns
+    /// `None` if less than two layers are resolved.
+    pub fn layering_wavelength(&self, tol: f64) -> Option<(Length, usize)> {
+        // total density from the wall towards the pore center
+        let rho_total = self.profile.density.sum_axis(Axis_nd(0)).to_reduced();
+        let (z, rho): (Vec<f64>, Vec<f64>) = self.profile.grid.grids()[0]
+            .iter()
+            .zip(rho_total.iter())
+            .filter(|&(&z, _)| z >= 0.0)
+            .rev()
+            .unzip();
+        let rho_bulk = self.profile.bulk.partial_density.to_reduced();
+        let rho_bulk: f64 = self
+            .profile
+            .bulk
+            .eos
+            .component_index()
+            .iter()
+            .map(|&c| rho_bulk[c])
+            .sum();
+        let threshold = tol * rho_bulk;
+
+        // maxima that are separated by sufficiently deep minima, starting at
+        // the first grid point with a significant density to skip the wall
+        let start = rho.iter().position(|&r| r >= 0.5 * rho_bulk)?.max(1);
+        let mut layers = Vec::new();
+        let mut maximum = None;
+        let mut minimum: f64 = 0.0;
+        for k in start..rho.len().saturating_sub(1) {
+            if rho[k] > rho[k - 1] && rho[k] >= rho[k + 1] {
+                maximum = Some(k);
+            } else if rho[k] < rho[k - 1] && rho[k] <= rho[k + 1] {
+                if let Some(m) = maximum.take() {
+                    if rho[m] - minimum.max(rho[k]) <= threshold {
+                        break;
+                    }
+                    layers.push(parabola_vertex(&z[m - 1..=m + 1], &rho[m - 1..=m + 1]));
+                }
+                minimum = rho[k];
+            }
+        }
+        let n = layers.len();
+        (n >= 2)
+            .then(|| Length::from_reduced((layers[0] - layers[n - 1]).abs() / (n - 1) as f64))
+            .map(|wavelength| (wavelength, n))
+    }
+}
+
+/// Position of the vertex of the parabola through three points.
+fn parabola_vertex(z: &[f64], rho: &[f64]) -> f64 {
+    let (a, b) = (z[1] - z[0], z[1] - z[2]);
+    let (fa, fb) = (rho[1] - rho[0], rho[1] - rho[2]);
+    let denominator = a * fb - b * fa;
+    if denominator == 0.0 {
+        return z[1];
+    }
+    z[1] - 0.5 * (a * a * fb - b * b * fa) / denominator
+}
+
 impl PoreSpecification<Ix1> for Pore1D {
     fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
         &self,
         bulk: &State<F>,
-        density: Option<&Density<Array2<f64>>>,
+        initial_guess: InitialGuess<'_, Ix1>,
         external_potential: Option<&Array2<f64>>,
     ) -> FeosResult<PoreProfile1D<F>> {
         let dft: &F = &bulk.eos;
         let n_grid = self.n_grid.unwrap_or(DEFAULT_GRID_POINTS);
 
+        if let Some(scaling) = &self.potential_scaling
+            && scaling.len() != dft.components()
+        {
+            return Err(FeosError::IncompatibleComponents(
+                dft.components(),
+                scaling.len(),
+            ));
+        }
+
+        if self.right_wall_potential.is_some() && self.geometry != Geometry::Cartesian {
+            return Err(FeosError::Error(String::from(
+                "Different potentials for the two walls are only available for slit pores.",
+            )));
+        }
+
         let axis = match self.geometry {
             Geometry::Cartesian => {
                 let potential_offset = POTENTIAL_OFFSET
                     * bulk
                         .eos
-                        .sigma_ff()
+                        .sigma_ff_t(bulk.temperature.to_reduced())
                         .iter()
                         .max_by(|a, b| a.total_cmp(b))
                         .unwrap();
-                Axis::new_cartesian(n_grid, 0.5 * self.pore_size, Some(potential_offset))
+                if self.right_wall_potential.is_some() {
+                    // discretize the full pore with the pore center at z=0
+                    let mut axis =
+                        Axis::new_cartesian(n_grid, self.pore_size, Some(2.0 * potential_offset));
+                    let shift = 0.5 * self.pore_size.to_reduced() + potential_offset;
+                    axis.grid -= shift;
+                    axis.edges -= shift;
+                    axis
+                } else {
+                    Axis::new_cartesian(n_grid, 0.5 * self.pore_size, Some(potential_offset))
+                }
             }
             Geometry::Cylindrical => Axis::new_polar(n_grid, self.pore_size),
             Geometry::Spherical => Axis::new_spherical(n_grid, self.pore_size),
         };
 
+        // check the resolution of the grid
+        let sigma_min = dft.sigma_ff_t(bulk.temperature.to_reduced()).min();
+        let spacing = axis
+            .edges
+            .windows(2)
+            .into_iter()
+            .fold(0.0, |dz: f64, e| dz.max(e[1] - e[0]));
+        let max_spacing = self.max_grid_spacing.unwrap_or(DEFAULT_MAX_GRID_SPACING);
+        let grid_spacing_warning = (spacing > max_spacing * sigma_min).then(|| {
+            format!(
+                "The grid spacing ({spacing:.3} Å) exceeds {max_spacing} times the smallest segment diameter ({sigma_min:.3} Å). Increase n_grid to resolve the density profile."
+            )
+        });
+        if let (Some(message), Some(_)) = (&grid_spacing_warning, self.max_grid_spacing) {
+            return Err(FeosError::Error(message.clone()));
+        }
+
         // calculate external potential
         let external_potential = external_potential.map_or_else(
             || {
                 external_potential_1d(
                     self.pore_size,
                     bulk.temperature,
-                    &self.potential,
+                    [
+                        &self.potential,
+                        self.right_wall_potential
+                            .as_ref()
+                            .unwrap_or(&self.potential),
+                    ],
                     dft,
                     &axis,
                     self.potential_cutoff,
+                    self.potential_scaling.as_ref(),
                 )
             },
             |e| e.clone(),
@@ -228,10 +1168,34 @@ impl PoreSpecification<Ix1> for Pore1D {
         // initialize grid
         let grid = Grid::new_1d(axis);
 
+        let mut profile = DFTProfile::new(
+            grid,
+            bulk,
+            Some(external_potential),
+            initial_guess.density(),
+            Some(1),
+        );
+        initial_guess.apply(&mut profile);
+        if let Some(factor) = self.convolver_coarsening {
+            profile.coarsen_convolution(factor)?;
+        }
+
         Ok(PoreProfile {
-            profile: DFTProfile::new(grid, bulk, Some(external_potential), density, Some(1)),
+            profile,
             grand_potential: None,
             interfacial_tension: None,
+            grid_spacing_warning,
+        })
+    }
+
+    fn pore_volume(&self) -> FeosResult<Volume> {
+        // the pore volume is a property of the pore geometry and independent
+        // of the component-specific scaling of the potential and of the
+        // resolution required for the (larger) segments of the fluid
+        helium_pore_volume(&Self {
+            potential_scaling: None,
+            max_grid_spacing: None,
+            ..self.clone()
         })
     }
 }
@@ -239,10 +1203,11 @@ impl PoreSpecification<Ix1> for Pore1D {
 fn external_potential_1d<P: HelmholtzEnergyFunctional + FluidParameters>(
     pore_width: Length,
     temperature: Temperature,
-    potential: &ExternalPotential,
+    [potential, right_wall_potential]: [&ExternalPotential; 2],
     fluid_parameters: &P,
     axis: &Axis,
     potential_cutoff: Option<f64>,
+    potential_scaling: Option<&DVector<f64>>,
 ) -> Array2<f64> {
     let potential_cutoff = potential_cutoff.unwrap_or(MAX_POTENTIAL);
     let effective_pore_size = match axis.geometry {
@@ -257,7 +1222,7 @@ fn external_potential_1d<P: HelmholtzEnergyFunctional + FluidParameters>(
                 &(effective_pore_size + &axis.grid),
                 fluid_parameters,
                 t,
-            ) + &potential.calculate_cartesian_potential(
+            ) + &right_wall_potential.calculate_cartesian_potential(
                 &(effective_pore_size - &axis.grid),
                 fluid_parameters,
                 t,
@@ -277,11 +1242,27 @@ fn external_potential_1d<P: HelmholtzEnergyFunctional + FluidParameters>(
         ),
     } / t;
 
+    // scale the potential for individual components
+    let scaling: Vec<_> = fluid_parameters
+        .component_index()
+        .iter()
+        .map(|&c| potential_scaling.map_or(1.0, |s| s[c]))
+        .collect();
+    for (mut v, &s) in external_potential.outer_iter_mut().zip(&scaling) {
+        v.mapv_inplace(|v| if s == 0.0 { 0.0 } else { v * s });
+    }
+
     for (i, &z) in axis.grid.iter().enumerate() {
-        if z > effective_pore_size {
-            external_potential
+        if z.abs() > effective_pore_size {
+            for (v, &s) in external_potential
                 .index_axis_mut(Axis_nd(1), i)
-                .fill(potential_cutoff);
+                .iter_mut()
+                .zip(&scaling)
+            {
+                if s != 0.0 {
+                    *v = potential_cutoff;
+                }
+            }
         }
     }
     external_potential.map_inplace(|x| {
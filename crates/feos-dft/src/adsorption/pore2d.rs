@@ -1,8 +1,8 @@
-use super::{FluidParameters, PoreProfile, PoreSpecification};
+use super::{FluidParameters, InitialGuess, PoreProfile, PoreSpecification};
 use crate::{Axis, DFTProfile, Grid, HelmholtzEnergyFunctional};
 use feos_core::{FeosResult, State};
 use ndarray::{Array3, Ix2};
-use quantity::{Angle, Density, Length};
+use quantity::{Angle, Length};
 
 pub struct Pore2D {
     system_size: [Length<f64>; 2],
@@ -26,7 +26,7 @@ impl PoreSpecification<Ix2> for Pore2D {
     fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
         &self,
         bulk: &State<F>,
-        density: Option<&Density<Array3<f64>>>,
+        initial_guess: InitialGuess<'_, Ix2>,
         external_potential: Option<&Array3<f64>>,
     ) -> FeosResult<PoreProfile<Ix2, F>> {
         // generate grid
@@ -34,10 +34,20 @@ impl PoreSpecification<Ix2> for Pore2D {
         let y = Axis::new_cartesian(self.n_grid[1], self.system_size[1], None);
         let grid = Grid::Periodical2(x, y, self.angle);
 
+        let mut profile = DFTProfile::new(
+            grid,
+            bulk,
+            external_potential.cloned(),
+            initial_guess.density(),
+            Some(1),
+        );
+        initial_guess.apply(&mut profile);
+
         Ok(PoreProfile {
-            profile: DFTProfile::new(grid, bulk, external_potential.cloned(), density, Some(1)),
+            profile,
             grand_potential: None,
             interfacial_tension: None,
+            grid_spacing_warning: None,
         })
     }
 }
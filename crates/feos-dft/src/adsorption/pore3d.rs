@@ -1,4 +1,4 @@
-use super::pore::{PoreProfile, PoreSpecification};
+use super::pore::{InitialGuess, PoreProfile, PoreSpecification};
 use crate::adsorption::FluidParameters;
 use crate::functional::HelmholtzEnergyFunctional;
 use crate::geometry::{Axis, Grid};
@@ -6,7 +6,7 @@ use crate::profile::{CUTOFF_RADIUS, DFTProfile, MAX_POTENTIAL};
 use feos_core::{FeosError, FeosResult, ReferenceSystem, State};
 use ndarray::Zip;
 use ndarray::prelude::*;
-use quantity::{Angle, DEGREES, Density, Length};
+use quantity::{Angle, DEGREES, Length};
 
 /// Parameters required to specify a 3D pore.
 pub struct Pore3D {
@@ -52,7 +52,7 @@ impl PoreSpecification<Ix3> for Pore3D {
     fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
         &self,
         bulk: &State<F>,
-        density: Option<&Density<Array4<f64>>>,
+        initial_guess: InitialGuess<'_, Ix3>,
         external_potential: Option<&Array4<f64>>,
     ) -> FeosResult<PoreProfile3D<F>> {
         let dft: &F = &bulk.eos;
@@ -94,10 +94,20 @@ impl PoreSpecification<Ix3> for Pore3D {
         )?;
         let grid = Grid::Periodical3(x, y, z, self.angles.unwrap_or([90.0 * DEGREES; 3]));
 
+        let mut profile = DFTProfile::new(
+            grid,
+            bulk,
+            Some(external_potential),
+            initial_guess.density(),
+            Some(1),
+        );
+        initial_guess.apply(&mut profile);
+
         Ok(PoreProfile {
-            profile: DFTProfile::new(grid, bulk, Some(external_potential), density, Some(1)),
+            profile,
             grand_potential: None,
             interfacial_tension: None,
+            grid_spacing_warning: None,
         })
     }
 }
@@ -143,7 +153,7 @@ pub fn external_potential_3d<F: HelmholtzEnergyFunctional + FluidParameters>(
     let cutoff_radius2 = cutoff_radius.powi(2);
 
     // calculate external potential
-    let sigma_ff = functional.sigma_ff();
+    let sigma_ff = functional.sigma_ff_t(reduced_temperature);
     let epsilon_k_ff = functional.epsilon_k_ff();
 
     Zip::indexed(&mut external_potential).par_for_each(|(i, ix, iy, iz), u| {
@@ -0,0 +1,217 @@
+use super::{Adsorption, FluidParameters, Pore1D};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::solver::DFTSolver;
+use feos_core::{FeosError, FeosResult, ReferenceSystem};
+use ndarray::{Array1, Array2, Ix1};
+use quantity::{Length, Moles, Pressure, Temperature};
+
+/// Minimum relative difference of the loadings of the empty and the filled
+/// pore for a transition to be detected.
+const TOL_LOADING: f64 = 1e-3;
+
+/// Pore-filling (capillary condensation) transitions of a pure component on a
+/// grid of temperatures and pore sizes.
+///
+/// Entries for which no transition was found, because the empty and the
+/// filled pore cannot be distinguished or the calculation did not converge,
+/// are NaN.
+pub struct PoreFillingDiagram {
+    pub temperature: Temperature<Array1<f64>>,
+    pub pore_size: Length<Array1<f64>>,
+    /// Pressures of the transitions (temperatures x pore sizes).
+    pub pressure: Pressure<Array2<f64>>,
+    /// Loadings of the empty pore at the transitions.
+    pub empty_loading: Moles<Array2<f64>>,
+    /// Loadings of the filled pore at the transitions.
+    pub filled_loading: Moles<Array2<f64>>,
+}
+
+impl Pore1D {
+    /// Calculate the saturation pressure of a pure component inside the pore,
+    /// i.e., the bulk pressure at which the empty and the filled pore have the
+    /// same grand potential.
+    ///
+    /// The adsorption and the desorption isotherm are calculated at the
+    /// relative pressures $p/p^\mathrm{sat}$ in `activity`, which have to be
+    /// sorted in ascending order, and the pressure is interpolated linearly
+    /// between the neighboring activities at which the grand potentials of
+    /// the two branches cross, see [Pore1D::pore_filling_diagram]. The
+    /// result is the microscopic analog of the condensation pressure
+    /// predicted by the Kelvin equation, see
+    /// [Pore1D::kelvin_condensation_pressure].
+    ///
+    /// Returns an error if no transition is found in the range of `activity`.
+    pub fn confined_saturation_pressure<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        functional: &F,
+        temperature: Temperature,
+        activity: &Array1<f64>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<Pressure> {
+        if functional.components() != 1 {
+            return Err(FeosError::IncompatibleComponents(
+                functional.components(),
+                1,
+            ));
+        }
+        let adsorption = Adsorption::adsorption_isotherm_relative(
+            functional,
+            temperature,
+            activity,
+            self,
+            solver,
+        )?;
+        let desorption = Adsorption::desorption_isotherm_relative(
+            functional,
+            temperature,
+            activity,
+            self,
+            solver,
+        )?;
+        let [p, _, _] = pore_filling_transition(&adsorption, &desorption).ok_or_else(|| {
+            FeosError::Error(String::from(
+                "No pore-filling transition was found in the given range of activities.",
+            ))
+        })?;
+        Ok(Pressure::from_reduced(p))
+    }
+
+    /// Calculate the pore-filling transitions of a pure component for every
+    /// combination of the given temperatures and pore sizes.
+    ///
+    /// For every temperature and pore size, the adsorption and the desorption
+    /// isotherm are calculated at the relative pressures $p/p^\mathrm{sat}$
+    /// in `activity`, which have to be sorted in ascending order. The
+    /// transition is located where the grand potentials of the two branches
+    /// cross and the pressure and the loadings are interpolated linearly
+    /// between the neighboring activities, so the resolution is determined by
+    /// the spacing of `activity`. All other properties of the pore are taken
+    /// from `self`. The temperatures have to be below the critical temperature
+    /// of the bulk fluid and should be sorted in ascending order for
+    /// [PoreFillingDiagram::critical_point].
+    pub fn pore_filling_diagram<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        functional: &F,
+        temperature: &Temperature<Array1<f64>>,
+        pore_size: &Length<Array1<f64>>,
+        activity: &Array1<f64>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<PoreFillingDiagram> {
+        if functional.components() != 1 {
+            return Err(FeosError::IncompatibleComponents(
+                functional.components(),
+                1,
+            ));
+        }
+        let shape = (temperature.len(), pore_size.len());
+        let mut pressure = Array2::from_elem(shape, f64::NAN);
+        let mut empty_loading = Array2::from_elem(shape, f64::NAN);
+        let mut filled_loading = Array2::from_elem(shape, f64::NAN);
+        for i in 0..temperature.len() {
+            let t = temperature.get(i);
+            for j in 0..pore_size.len() {
+                let pore = Pore1D {
+                    pore_size: pore_size.get(j),
+                    ..self.clone()
+                };
+                let (Ok(adsorption), Ok(desorption)) = (
+                    Adsorption::adsorption_isotherm_relative(
+                        functional, t, activity, &pore, solver,
+                    ),
+                    Adsorption::desorption_isotherm_relative(
+                        functional, t, activity, &pore, solver,
+                    ),
+                ) else {
+                    continue;
+                };
+                if let Some([p, n_empty, n_filled]) =
+                    pore_filling_transition(&adsorption, &desorption)
+                {
+                    pressure[(i, j)] = p;
+                    empty_loading[(i, j)] = n_empty;
+                    filled_loading[(i, j)] = n_filled;
+                }
+            }
+        }
+        Ok(PoreFillingDiagram {
+            temperature: temperature.clone(),
+            pore_size: pore_size.clone(),
+            pressure: Pressure::from_reduced(pressure),
+            empty_loading: Moles::from_reduced(empty_loading),
+            filled_loading: Moles::from_reduced(filled_loading),
+        })
+    }
+}
+
+/// Pressure and loadings of the empty and the filled pore in reduced units at
+/// the first crossing of the grand potentials of the adsorption and the
+/// desorption branch.
+fn pore_filling_transition<F: HelmholtzEnergyFunctional + FluidParameters>(
+    adsorption: &Adsorption<Ix1, F>,
+    desorption: &Adsorption<Ix1, F>,
+) -> Option<[f64; 3]> {
+    let p = adsorption.pressure().to_reduced();
+    let n_ads = adsorption.total_adsorption().to_reduced();
+    let n_des = desorption.total_adsorption().to_reduced();
+    let omega_ads = adsorption.grand_potential().to_reduced();
+    let omega_des = desorption.grand_potential().to_reduced();
+
+    // the branches have to be distinct and their grand potentials have to cross
+    let delta = |k: usize| {
+        let distinct = (n_des[k] - n_ads[k]).abs() > TOL_LOADING * n_des[k].abs();
+        distinct.then_some(omega_ads[k] - omega_des[k])
+    };
+    (0..p.len().saturating_sub(1)).find_map(|k| {
+        let (d0, d1) = (delta(k)?, delta(k + 1)?);
+        (d0 <= 0.0 && d1 > 0.0).then(|| {
+            let x = d0 / (d0 - d1);
+            [
+                p[k] + x * (p[k + 1] - p[k]),
+                n_ads[k] + x * (n_ads[k + 1] - n_ads[k]),
+                n_des[k] + x * (n_des[k + 1] - n_des[k]),
+            ]
+        })
+    })
+}
+
+impl PoreFillingDiagram {
+    /// Estimate the pore critical point, i.e., the temperature and pressure at
+    /// which the pore-filling transition vanishes, for every pore size.
+    ///
+    /// The squared difference of the loadings of the filled and the empty pore
+    /// is extrapolated linearly to zero from the two highest temperatures with
+    /// a transition, in accordance with the mean-field critical exponent
+    /// $\beta=\frac{1}{2}$ of DFT. The logarithm of the pressure is
+    /// extrapolated linearly to the resulting temperature. The critical
+    /// temperature is bounded by the next temperature without a transition.
+    /// Pore sizes without at least two transitions or with a transition at the
+    /// highest temperature result in NaN.
+    pub fn critical_point(&self) -> (Temperature<Array1<f64>>, Pressure<Array1<f64>>) {
+        let t = self.temperature.to_reduced();
+        let p = self.pressure.to_reduced();
+        let dn = self.filled_loading.to_reduced() - self.empty_loading.to_reduced();
+        let n = t.len();
+        let mut t_c = Array1::from_elem(self.pore_size.len(), f64::NAN);
+        let mut p_c = Array1::from_elem(self.pore_size.len(), f64::NAN);
+        for j in 0..self.pore_size.len() {
+            // highest temperature with a transition
+            let Some(i2) = (0..n).rev().find(|&i| dn[(i, j)].is_finite()) else {
+                continue;
+            };
+            if i2 + 1 == n || i2 == 0 || !dn[(i2 - 1, j)].is_finite() {
+                continue;
+            }
+            let i1 = i2 - 1;
+            let (dn1, dn2) = (dn[(i1, j)].powi(2), dn[(i2, j)].powi(2));
+            let mut t_crit = t[i2] + (t[i2] - t[i1]) * dn2 / (dn1 - dn2);
+            if !t_crit.is_finite() || t_crit < t[i2] {
+                continue;
+            }
+            t_crit = t_crit.min(t[i2 + 1]);
+            let slope = (p[(i2, j)] / p[(i1, j)]).ln() / (t[i2] - t[i1]);
+            t_c[j] = t_crit;
+            p_c[j] = p[(i2, j)] * (slope * (t_crit - t[i2])).exp();
+        }
+        (Temperature::from_reduced(t_c), Pressure::from_reduced(p_c))
+    }
+}
@@ -0,0 +1,137 @@
+use feos_core::{FeosError, FeosResult, ReferenceSystem};
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2};
+use quantity::Moles;
+
+const MAX_ITER_NNLS_FACTOR: usize = 3;
+const TOL_NNLS: f64 = 1e-12;
+
+/// Pore-size distribution from a kernel of single-pore isotherms.
+///
+/// The kernel contains the loadings of single pores with different widths
+/// (rows) at the pressures of the measured isotherm (columns), e.g., the total
+/// adsorptions of isotherms calculated for a range of pore widths. The
+/// distribution $f_j\geq 0$ is the solution of the non-negative least-squares
+/// problem
+/// $$\min_f\sum_k\left(\sum_jN_{jk}f_j-N_k\right)^2+\lambda\sum_jf_j^2$$
+/// in which the Tikhonov regularization $\lambda$ is given relative to the
+/// square of the largest kernel entry. The distribution is the number of
+/// pores of every width that reproduces the measured loadings $N_k$.
+pub fn pore_size_distribution(
+    kernel: &Moles<Array2<f64>>,
+    loading: &Moles<Array1<f64>>,
+    regularization: f64,
+) -> FeosResult<Array1<f64>> {
+    let kernel = kernel.to_reduced();
+    let loading = loading.to_reduced();
+    let (n_pores, n_points) = kernel.dim();
+    if n_pores == 0 || n_points != loading.len() {
+        return Err(FeosError::Error(format!(
+            "The kernel with shape {:?} is incompatible with {} loadings.",
+            kernel.dim(),
+            loading.len()
+        )));
+    }
+    if regularization.is_nan() || regularization < 0.0 {
+        return Err(FeosError::Error(String::from(
+            "The regularization has to be non-negative.",
+        )));
+    }
+    if kernel.iter().chain(loading.iter()).any(|x| !x.is_finite()) {
+        return Err(FeosError::Error(String::from(
+            "The kernel and the loadings have to be finite.",
+        )));
+    }
+
+    // scale the problem and append the regularization to the system of equations
+    let scale = kernel.iter().fold(0.0, |s: f64, x| s.max(x.abs()));
+    if scale == 0.0 {
+        return Err(FeosError::Error(String::from(
+            "The kernel must not vanish.",
+        )));
+    }
+    let lambda = regularization.sqrt();
+    let a = DMatrix::from_fn(n_points + n_pores, n_pores, |k, j| {
+        if k < n_points {
+            kernel[(j, k)] / scale
+        } else if k - n_points == j {
+            lambda
+        } else {
+            0.0
+        }
+    });
+    let b = DVector::from_fn(n_points + n_pores, |k, _| {
+        if k < n_points {
+            loading[k] / scale
+        } else {
+            0.0
+        }
+    });
+    Ok(Array1::from_iter(nnls(&a, &b)?.iter().copied()))
+}
+
+/// Non-negative least squares using the active-set algorithm of Lawson and Hanson.
+fn nnls(a: &DMatrix<f64>, b: &DVector<f64>) -> FeosResult<DVector<f64>> {
+    let n = a.ncols();
+    let mut x = DVector::zeros(n);
+    let mut passive = vec![false; n];
+    let tol = TOL_NNLS * a.norm() * b.norm().max(1.0);
+
+    for _ in 0..MAX_ITER_NNLS_FACTOR * n {
+        // gradient of the objective function
+        let w = a.tr_mul(&(b - a * &x));
+        let Some((j, _)) = (0..n)
+            .filter(|&j| !passive[j])
+            .map(|j| (j, w[j]))
+            .filter(|&(_, w)| w > tol)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+        else {
+            return Ok(x);
+        };
+        passive[j] = true;
+
+        loop {
+            let s = passive_least_squares(a, b, &passive)?;
+            if (0..n).all(|i| !passive[i] || s[i] > 0.0) {
+                x = s;
+                break;
+            }
+            // step towards s until the first passive variable vanishes
+            let (k, alpha) = (0..n)
+                .filter(|&i| passive[i] && s[i] <= 0.0)
+                .map(|i| (i, x[i] / (x[i] - s[i])))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap();
+            x += (s - &x) * alpha;
+            x[k] = 0.0;
+            for i in 0..n {
+                if passive[i] && x[i] <= TOL_NNLS {
+                    passive[i] = false;
+                    x[i] = 0.0;
+                }
+            }
+        }
+    }
+    Err(FeosError::NotConverged(String::from(
+        "pore_size_distribution",
+    )))
+}
+
+/// Unconstrained least-squares solution for the passive variables.
+fn passive_least_squares(
+    a: &DMatrix<f64>,
+    b: &DVector<f64>,
+    passive: &[bool],
+) -> FeosResult<DVector<f64>> {
+    let index: Vec<_> = (0..passive.len()).filter(|&i| passive[i]).collect();
+    let a_p = a.select_columns(&index);
+    let s_p = a_p
+        .svd(true, true)
+        .solve(b, f64::EPSILON)
+        .map_err(|e| FeosError::Error(e.to_string()))?;
+    let mut s = DVector::zeros(passive.len());
+    for (&i, &s_i) in index.iter().zip(s_p.iter()) {
+        s[i] = s_i;
+    }
+    Ok(s)
+}
@@ -0,0 +1,519 @@
+use super::{
+    ExternalPotential, FluidParameters, InitialGuess, PoreProfile, PoreProfile1D, PoreProfile2D,
+    PoreSpecification, bulk_from_fugacity,
+};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::{Axis, Grid};
+use crate::profile::{DFTProfile, MAX_POTENTIAL};
+use crate::solver::DFTSolver;
+use feos_core::{
+    Contributions, FeosError, FeosResult, PhaseEquilibrium, ReferenceSystem, State, StateBuilder,
+};
+use nalgebra::dvector;
+use ndarray::prelude::*;
+use ndarray::{Axis as Axis_nd, Ix2, Zip};
+use quantity::{
+    Area, Density, Energy, Force, Length, MolarEnergy, Pressure, RGAS, SurfaceTension, Temperature,
+    Volume,
+};
+use std::f64::consts::PI;
+
+const DEFAULT_GRID_POINTS: usize = 1024;
+const THICK_FILM_FRACTION: f64 = 0.25;
+const TOL_CONTACT_LINE: f64 = 1e-2;
+
+/// Parameters required to specify a single planar wall in contact with a
+/// semi-infinite bulk fluid.
+///
+/// The wall is located at $z=0$ and the system has to be wide enough for
+/// the density to reach the bulk value at $z=L$.
+#[derive(Clone)]
+pub struct Wall1D {
+    pub width: Length,
+    pub potential: ExternalPotential,
+    pub n_grid: Option<usize>,
+    pub potential_cutoff: Option<f64>,
+}
+
+impl Wall1D {
+    pub fn new(
+        width: Length,
+        potential: ExternalPotential,
+        n_grid: Option<usize>,
+        potential_cutoff: Option<f64>,
+    ) -> Self {
+        Self {
+            width,
+            potential,
+            n_grid,
+            potential_cutoff,
+        }
+    }
+
+    /// Calculate the adsorbed films of a pure component at the wall for the
+    /// given bulk activities $p/p^\mathrm{sat}$.
+    ///
+    /// At every activity, the profile is solved once starting from a thin
+    /// film and once starting from a thick (liquid-like) film. The thin-film
+    /// branch is continued from low to high activities and the thick-film
+    /// branch from high to low activities, so that a prewetting transition
+    /// shows up as a jump in the film thickness with a hysteresis between
+    /// the two branches. The activities have to be sorted in ascending order.
+    pub fn wetting_isotherm<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        functional: &F,
+        temperature: Temperature,
+        activity: &Array1<f64>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<WettingIsotherm<F>> {
+        if functional.components() != 1 {
+            return Err(FeosError::IncompatibleComponents(
+                functional.components(),
+                1,
+            ));
+        }
+        let vle = PhaseEquilibrium::pure(functional, temperature, None, Default::default())?;
+        let saturation_pressure = vle.vapor().pressure(Contributions::Total);
+        let bulk = |a: f64| {
+            StateBuilder::new(functional)
+                .temperature(temperature)
+                .pressure(saturation_pressure * a)
+                .vapor()
+                .build()
+        };
+
+        // calculate the external potential only once
+        let external_potential = self
+            .initialize(&bulk(activity[0])?, InitialGuess::Bulk, None)?
+            .profile
+            .external_potential;
+        let external_potential = Some(&external_potential);
+
+        // thin-film branch from low to high activities
+        let mut thin_film: Vec<FeosResult<PoreProfile1D<F>>> = Vec::with_capacity(activity.len());
+        for &a in activity {
+            let bulk = bulk(a)?;
+            let density = match thin_film.last() {
+                Some(Ok(p)) => Some(p.profile.density.clone()),
+                _ => None,
+            };
+            let p = self.initialize(&bulk, density.as_ref().into(), external_potential)?;
+            let p2 = self.initialize(&bulk, InitialGuess::Bulk, external_potential)?;
+            thin_film.push(p.solve(solver).or_else(|_| p2.solve(solver)));
+        }
+
+        // thick-film branch from high to low activities
+        let mut thick_film: Vec<FeosResult<PoreProfile1D<F>>> = Vec::with_capacity(activity.len());
+        for &a in activity.iter().rev() {
+            let bulk = bulk(a)?;
+            let initial_film = self.thick_film_density(&bulk, vle.liquid(), external_potential)?;
+            let density = match thick_film.last() {
+                Some(Ok(p)) => p.profile.density.clone(),
+                _ => initial_film.clone(),
+            };
+            let p = self.initialize(&bulk, InitialGuess::Explicit(&density), external_potential)?;
+            let p2 = self.initialize(
+                &bulk,
+                InitialGuess::Explicit(&initial_film),
+                external_potential,
+            )?;
+            thick_film.push(p.solve(solver).or_else(|_| p2.solve(solver)));
+        }
+        thick_film.reverse();
+
+        Ok(WettingIsotherm {
+            pore_volume: self.pore_volume()?,
+            vle,
+            activity: activity.clone(),
+            thin_film,
+            thick_film,
+        })
+    }
+
+    /// Calculate the equilibrium thickness of the adsorbed film of a pure
+    /// component at the wall as a function of the undersaturation
+    /// $\Delta\mu=\mu^\mathrm{sat}-\mu$ of the bulk vapor.
+    ///
+    /// The bulk vapor at every undersaturation is determined from its
+    /// fugacity $f=f^\mathrm{sat}\exp\left(-\frac{\Delta\mu}{RT}\right)$ and
+    /// the film thickness is calculated with [Wall1D::wetting_isotherm], so
+    /// the undersaturations have to be positive and sorted in descending
+    /// order. For complete wetting driven by dispersion (van der Waals)
+    /// forces, the film thickness diverges as $l\sim\Delta\mu^{-1/3}$ upon
+    /// approaching saturation, which is a useful validation of the results.
+    pub fn film_thickness<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        functional: &F,
+        temperature: Temperature,
+        undersaturation: &MolarEnergy<Array1<f64>>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<Length<Array1<f64>>> {
+        if functional.components() != 1 {
+            return Err(FeosError::IncompatibleComponents(
+                functional.components(),
+                1,
+            ));
+        }
+        let vle = PhaseEquilibrium::pure(functional, temperature, None, Default::default())?;
+        let saturation_pressure = vle.vapor().pressure(Contributions::Total);
+        let rt = RGAS * temperature;
+        let fugacity = fugacity(vle.vapor());
+        let activity = undersaturation
+            .into_iter()
+            .map(|dmu| {
+                let f = fugacity * (-(dmu / rt).into_value()).exp();
+                let bulk = bulk_from_fugacity(
+                    functional,
+                    temperature,
+                    &Pressure::from_reduced(dvector![f.to_reduced()]),
+                )?;
+                Ok((bulk.pressure(Contributions::Total) / saturation_pressure).into_value())
+            })
+            .collect::<FeosResult<Array1<f64>>>()?;
+        Ok(self
+            .wetting_isotherm(functional, temperature, &activity, solver)?
+            .equilibrium_film_thickness())
+    }
+
+    /// Initial density profile with a liquid film of thickness
+    /// `THICK_FILM_FRACTION` times the width of the system that is connected
+    /// to the bulk vapor by a smooth hyperbolic tangent.
+    fn thick_film_density<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        liquid: &State<F>,
+        external_potential: Option<&Array2<f64>>,
+    ) -> FeosResult<Density<Array2<f64>>> {
+        let vapor = self
+            .initialize(bulk, InitialGuess::Bulk, external_potential)?
+            .profile;
+        let liquid = self
+            .initialize(liquid, InitialGuess::Bulk, external_potential)?
+            .profile;
+        let l_film = THICK_FILM_FRACTION * self.width.to_reduced();
+        let width = bulk.eos.sigma_ff_t(bulk.temperature.to_reduced()).max();
+        let rho_v = vapor.density.to_reduced();
+        let rho_l = liquid.density.to_reduced();
+        let mut density = rho_v.clone();
+        for (i, &z) in vapor.grid.grids()[0].iter().enumerate() {
+            let s = 0.5 * (1.0 - ((z - l_film) / width).tanh());
+            density.index_axis_mut(Axis_nd(1), i).assign(
+                &(&rho_v.index_axis(Axis_nd(1), i) * (1.0 - s)
+                    + &rho_l.index_axis(Axis_nd(1), i) * s),
+            );
+        }
+        Ok(Density::from_reduced(density))
+    }
+}
+
+impl PoreSpecification<Ix1> for Wall1D {
+    fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        initial_guess: InitialGuess<'_, Ix1>,
+        external_potential: Option<&Array2<f64>>,
+    ) -> FeosResult<PoreProfile1D<F>> {
+        let dft: &F = &bulk.eos;
+        let n_grid = self.n_grid.unwrap_or(DEFAULT_GRID_POINTS);
+        let axis = Axis::new_cartesian(n_grid, self.width, None);
+
+        // calculate external potential
+        let external_potential = external_potential.cloned().unwrap_or_else(|| {
+            let t = bulk.temperature.to_reduced();
+            let potential_cutoff = self.potential_cutoff.unwrap_or(MAX_POTENTIAL);
+            (self
+                .potential
+                .calculate_cartesian_potential(&axis.grid, dft, t)
+                / t)
+                .mapv(|v| v.min(potential_cutoff))
+        });
+
+        // initialize grid
+        let grid = Grid::new_1d(axis);
+
+        let mut profile = DFTProfile::new(
+            grid,
+            bulk,
+            Some(external_potential),
+            initial_guess.density(),
+            Some(1),
+        );
+        initial_guess.apply(&mut profile);
+
+        Ok(PoreProfile {
+            profile,
+            grand_potential: None,
+            interfacial_tension: None,
+            grid_spacing_warning: None,
+        })
+    }
+}
+
+/// Parameters required to specify a single wall with a periodic, sinusoidal
+/// corrugation in contact with a semi-infinite bulk fluid.
+///
+/// The surface of the wall is located at
+/// $z_\mathrm{w}(x)=A\left(1+\cos\frac{2\pi x}{\lambda}\right)$ with the
+/// amplitude $A$ and the period $\lambda$. The external potential is
+/// evaluated in the locally planar approximation, i.e., as the potential of a
+/// planar wall at the distance $z-z_\mathrm{w}(x)$.
+#[derive(Clone)]
+pub struct CorrugatedWall2D {
+    pub width: Length,
+    pub period: Length,
+    pub amplitude: Length,
+    pub potential: ExternalPotential,
+    pub n_grid: [usize; 2],
+    pub potential_cutoff: Option<f64>,
+}
+
+impl CorrugatedWall2D {
+    pub fn new(
+        width: Length,
+        period: Length,
+        amplitude: Length,
+        potential: ExternalPotential,
+        n_grid: [usize; 2],
+        potential_cutoff: Option<f64>,
+    ) -> Self {
+        Self {
+            width,
+            period,
+            amplitude,
+            potential,
+            n_grid,
+            potential_cutoff,
+        }
+    }
+
+    /// Solve the density profile at the wall and calculate the effective
+    /// solid-fluid interfacial tension.
+    ///
+    /// The excess grand potential $\Omega+pV$ of the calculation domain is
+    /// divided by the projected area of the wall, so that the result is
+    /// averaged over one period of the corrugation. The dividing surface is
+    /// located at $z=0$. Therefore, only differences between interfacial
+    /// tensions at the same wall, e.g., $\gamma_\mathrm{sv}-\gamma_\mathrm{sl}$
+    /// in Young's equation, are independent of this choice.
+    pub fn solid_fluid_tension<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<SurfaceTension> {
+        let profile = self
+            .initialize(bulk, InitialGuess::Bulk, None)?
+            .solve(solver)?;
+        Ok(profile.interfacial_tension.unwrap() / Area::from_reduced(self.period.to_reduced()))
+    }
+}
+
+impl PoreSpecification<Ix2> for CorrugatedWall2D {
+    fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        initial_guess: InitialGuess<'_, Ix2>,
+        external_potential: Option<&Array3<f64>>,
+    ) -> FeosResult<PoreProfile2D<F>> {
+        let dft: &F = &bulk.eos;
+        // the reflective boundaries of the lateral axis at x=0 and x=period
+        // reproduce the periodicity of the symmetric corrugation
+        let x = Axis::new_cartesian(self.n_grid[0], self.period, None);
+        let z = Axis::new_cartesian(self.n_grid[1], self.width, None);
+
+        // calculate external potential
+        let external_potential = external_potential.cloned().unwrap_or_else(|| {
+            let t = bulk.temperature.to_reduced();
+            let potential_cutoff = self.potential_cutoff.unwrap_or(MAX_POTENTIAL);
+            let amplitude = self.amplitude.to_reduced();
+            let period = self.period.to_reduced();
+            let mut external_potential =
+                Array3::zeros((dft.component_index().len(), x.grid.len(), z.grid.len()));
+            for (i, &x) in x.grid.iter().enumerate() {
+                let z_wall = amplitude * (1.0 + (2.0 * PI * x / period).cos());
+                let distance = &z.grid - z_wall;
+                let potential = self
+                    .potential
+                    .calculate_cartesian_potential(&distance, dft, t)
+                    / t;
+                Zip::from(external_potential.index_axis_mut(Axis_nd(1), i))
+                    .and(&potential)
+                    .and_broadcast(&distance)
+                    .for_each(|v, &p, &d| {
+                        *v = if d > 0.0 {
+                            p.min(potential_cutoff)
+                        } else {
+                            potential_cutoff
+                        }
+                    });
+            }
+            external_potential
+        });
+
+        // initialize grid
+        let grid = Grid::Cartesian2(x, z);
+
+        let mut profile = DFTProfile::new(
+            grid,
+            bulk,
+            Some(external_potential),
+            initial_guess.density(),
+            Some(1),
+        );
+        initial_guess.apply(&mut profile);
+
+        Ok(PoreProfile {
+            profile,
+            grand_potential: None,
+            interfacial_tension: None,
+            grid_spacing_warning: None,
+        })
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> PoreProfile2D<F> {
+    /// Line tension of a three-phase contact line at a planar wall.
+    ///
+    /// The profile has to be solved on a [CorrugatedWall2D] without
+    /// corrugation at liquid-vapor coexistence, with the coexisting phases
+    /// on either side of the lateral axis and a liquid-vapor interface that
+    /// extends from the wall at $z=0$ to the bulk at $z=L_z$. The excess
+    /// grand potential of the domain is decomposed as
+    /// $$\Omega+pV=x_\mathrm{c}\gamma_0+\left(L_x-x_\mathrm{c}\right)\gamma_L+L_z\gamma_\mathrm{lv}+\tau$$
+    /// per unit length of the contact line. The wall tensions $\gamma_0$ and
+    /// $\gamma_L$ are obtained from the columns at the lateral boundaries and
+    /// the liquid-vapor tension $\gamma_\mathrm{lv}$ from the row at $z=L_z$,
+    /// in which the contact line position $x_\mathrm{c}$ is located as the
+    /// equimolar dividing surface. Like the wall tensions, the line tension
+    /// depends on the choice of the dividing surfaces unless the contact
+    /// angle is 90°. The domain has to be large enough for the boundary
+    /// columns and rows to resemble the planar interfaces.
+    pub fn line_tension(&self) -> FeosResult<Force> {
+        let Grid::Cartesian2(x, z) = &self.profile.grid else {
+            return Err(FeosError::Error(String::from(
+                "The line tension is only available for two-dimensional walls.",
+            )));
+        };
+        let (weights, _) = self.profile.grid.integration_weights();
+        let (wx, wz) = (weights[0], weights[1]);
+        let pressure = self
+            .profile
+            .bulk
+            .pressure(Contributions::Total)
+            .to_reduced();
+        let omega = self.profile.grand_potential_density()?.to_reduced() + pressure;
+        let rho = self.profile.density.sum_axis(Axis_nd(0)).to_reduced();
+
+        // planar interfaces at the boundaries of the domain
+        let top = z.grid.len() - 1;
+        let (rho_0, rho_end) = (rho[(0, top)], rho[(x.grid.len() - 1, top)]);
+        if (rho_0 - rho_end).abs() < TOL_CONTACT_LINE * rho_0.max(rho_end) {
+            return Err(FeosError::Error(String::from(
+                "The profile does not contain a liquid-vapor interface.",
+            )));
+        }
+        let gamma_0 = omega.row(0).dot(wz);
+        let gamma_end = omega.row(x.grid.len() - 1).dot(wz);
+        let gamma_lv = omega.column(top).dot(wx);
+        let x_c = (&rho.column(top) - rho_end).dot(wx) / (rho_0 - rho_end);
+
+        let omega_ex = wx.dot(&omega.dot(wz));
+        let tau = omega_ex - x_c * gamma_0 - (x.length() - x_c) * gamma_end - z.length() * gamma_lv;
+        Ok(Energy::from_reduced(tau) / Length::from_reduced(1.0))
+    }
+}
+
+/// Adsorbed films at a single wall on the thin-film and the thick-film branch.
+pub struct WettingIsotherm<F> {
+    pub vle: PhaseEquilibrium<F, 2>,
+    pub activity: Array1<f64>,
+    pub thin_film: Vec<FeosResult<PoreProfile1D<F>>>,
+    pub thick_film: Vec<FeosResult<PoreProfile1D<F>>>,
+    pore_volume: Volume,
+}
+
+impl<F: HelmholtzEnergyFunctional> WettingIsotherm<F> {
+    /// Thickness of the adsorbed film, i.e., the excess adsorption per area
+    /// divided by the difference between the liquid and the bulk density.
+    fn film_thickness_of(&self, profiles: &[FeosResult<PoreProfile1D<F>>]) -> Length<Array1<f64>> {
+        let liquid_density = self.vle.liquid().density;
+        Length::from_shape_fn(profiles.len(), |i| match &profiles[i] {
+            Ok(p) => {
+                let bulk_density = p.profile.bulk.density;
+                (p.profile.total_moles() - bulk_density * self.pore_volume)
+                    / (Area::from_reduced(1.0) * (liquid_density - bulk_density))
+            }
+            Err(_) => Length::from_reduced(f64::NAN),
+        })
+    }
+
+    fn grand_potential_of(profiles: &[FeosResult<PoreProfile1D<F>>]) -> Energy<Array1<f64>> {
+        Energy::from_shape_fn(profiles.len(), |i| match &profiles[i] {
+            Ok(p) => p.grand_potential.unwrap(),
+            Err(_) => Energy::from_reduced(f64::NAN),
+        })
+    }
+
+    /// Undersaturation $\Delta\mu=\mu^\mathrm{sat}-\mu$ of the bulk vapor
+    /// at every activity.
+    pub fn undersaturation(&self) -> FeosResult<MolarEnergy<Array1<f64>>> {
+        let vapor = self.vle.vapor();
+        let saturation_pressure = vapor.pressure(Contributions::Total);
+        let rt = RGAS * vapor.temperature;
+        let fugacity_sat = fugacity(vapor);
+        self.activity
+            .iter()
+            .map(|&a| {
+                let bulk = StateBuilder::new(&vapor.eos)
+                    .temperature(vapor.temperature)
+                    .pressure(saturation_pressure * a)
+                    .vapor()
+                    .build()?;
+                Ok(rt * (fugacity_sat / fugacity(&bulk)).into_value().ln())
+            })
+            .collect::<FeosResult<Vec<_>>>()
+            .map(|dmu| MolarEnergy::from_shape_fn(dmu.len(), |i| dmu[i]))
+    }
+
+    /// Film thickness on the thin-film and the thick-film branch.
+    ///
+    /// At walls at which the fluid is depleted, the excess adsorption and
+    /// thus the film thickness are negative.
+    pub fn film_thickness(&self) -> (Length<Array1<f64>>, Length<Array1<f64>>) {
+        (
+            self.film_thickness_of(&self.thin_film),
+            self.film_thickness_of(&self.thick_film),
+        )
+    }
+
+    /// Grand potential on the thin-film and the thick-film branch.
+    pub fn grand_potential(&self) -> (Energy<Array1<f64>>, Energy<Array1<f64>>) {
+        (
+            Self::grand_potential_of(&self.thin_film),
+            Self::grand_potential_of(&self.thick_film),
+        )
+    }
+
+    /// Film thickness of the branch with the lower grand potential.
+    pub fn equilibrium_film_thickness(&self) -> Length<Array1<f64>> {
+        let (l_thin, l_thick) = self.film_thickness();
+        let (omega_thin, omega_thick) = self.grand_potential();
+        let mut l = l_thin.to_reduced();
+        Zip::from(&mut l)
+            .and(&l_thick.to_reduced())
+            .and(&omega_thin.to_reduced())
+            .and(&omega_thick.to_reduced())
+            .for_each(|l, &l_thick, &omega_thin, &omega_thick| {
+                if omega_thin.is_nan() || omega_thick < omega_thin {
+                    *l = l_thick
+                }
+            });
+        Length::from_reduced(l)
+    }
+}
+
+/// Fugacity of a pure component $f=\rho RT\exp\left(\mu^\mathrm{res}/RT\right)$.
+fn fugacity<F: HelmholtzEnergyFunctional>(state: &State<F>) -> Pressure {
+    let rt = RGAS * state.temperature;
+    let mu_res = (state.residual_chemical_potential().get(0) / rt).into_value();
+    state.density * rt * mu_res.exp()
+}
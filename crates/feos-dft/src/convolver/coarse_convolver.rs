@@ -0,0 +1,176 @@
+use super::Convolver;
+use crate::weight_functions::WeightFunction;
+use ndarray::Axis as Axis_nd;
+use ndarray::*;
+use num_dual::DualNum;
+use std::sync::Arc;
+
+/// Convolver that evaluates the convolutions on a grid that is coarsened
+/// by an integer factor in every dimension.
+///
+/// Profiles are averaged over blocks of `factor` grid points before the
+/// convolution and the results are linearly interpolated back to the
+/// original grid. The functional derivative uses the transposed operations
+/// to stay consistent with the weighted densities.
+pub struct CoarseConvolver<T, D: Dimension> {
+    /// Convolver on the coarse grid
+    convolver: Arc<dyn Convolver<T, D>>,
+    /// Coarsening factor
+    factor: usize,
+    /// Periodicity of the individual axes
+    periodic: Vec<bool>,
+}
+
+impl<T, D: Dimension + 'static> CoarseConvolver<T, D>
+where
+    T: DualNum<f64> + Copy + Send + Sync + 'static,
+    D::Larger: Dimension<Smaller = D>,
+{
+    #[expect(clippy::new_ret_no_self)]
+    pub fn new(
+        convolver: Arc<dyn Convolver<T, D>>,
+        factor: usize,
+        periodic: Vec<bool>,
+    ) -> Arc<dyn Convolver<T, D>> {
+        Arc::new(Self {
+            convolver,
+            factor,
+            periodic,
+        })
+    }
+
+    /// Average a profile over blocks of `factor` grid points in all spatial dimensions.
+    fn restrict<D2: Dimension>(&self, f: ArrayView<T, D2>) -> Array<T, D2> {
+        let offset = f.ndim() - self.periodic.len();
+        let mut f = f.into_dyn().to_owned();
+        let weight = 1.0 / self.factor as f64;
+        for ax in offset..f.ndim() {
+            let mut shape = f.shape().to_vec();
+            shape[ax] /= self.factor;
+            let mut coarse = ArrayD::zeros(shape);
+            for (j, mut c) in coarse.axis_iter_mut(Axis_nd(ax)).enumerate() {
+                for m in 0..self.factor {
+                    let f_m = f.index_axis(Axis_nd(ax), j * self.factor + m);
+                    c.zip_mut_with(&f_m, |c, &f| *c += f * weight);
+                }
+            }
+            f = coarse;
+        }
+        f.into_dimensionality().unwrap()
+    }
+
+    /// Indices of the neighboring coarse grid points and the interpolation
+    /// weight for every fine grid point along an axis with `n` coarse points.
+    fn stencil(&self, n: usize, periodic: bool) -> Vec<(usize, usize, f64)> {
+        let n = n as isize;
+        let index = |j: isize| {
+            if periodic {
+                j.rem_euclid(n) as usize
+            } else {
+                j.clamp(0, n - 1) as usize
+            }
+        };
+        (0..n as usize * self.factor)
+            .map(|i| {
+                let x = (i as f64 + 0.5) / self.factor as f64 - 0.5;
+                let j = x.floor();
+                (index(j as isize), index(j as isize + 1), x - j)
+            })
+            .collect()
+    }
+
+    /// Linearly interpolate a profile from the coarse grid to the original grid.
+    fn prolong<D2: Dimension>(&self, f: Array<T, D2>) -> Array<T, D2> {
+        let offset = f.ndim() - self.periodic.len();
+        let mut f = f.into_dyn();
+        for (ax, &periodic) in (offset..f.ndim()).zip(&self.periodic) {
+            let mut shape = f.shape().to_vec();
+            shape[ax] *= self.factor;
+            let mut fine = ArrayD::zeros(shape);
+            let stencil = self.stencil(f.shape()[ax], periodic);
+            for (mut c, &(j0, j1, w)) in fine.axis_iter_mut(Axis_nd(ax)).zip(&stencil) {
+                let f0 = f.index_axis(Axis_nd(ax), j0);
+                let f1 = f.index_axis(Axis_nd(ax), j1);
+                Zip::from(&mut c)
+                    .and(&f0)
+                    .and(&f1)
+                    .for_each(|c, &f0, &f1| *c = f0 * (1.0 - w) + f1 * w);
+            }
+            f = fine;
+        }
+        f.into_dimensionality().unwrap()
+    }
+
+    /// Transpose of `prolong` divided by `factor`, i.e., a weighted average
+    /// of the profile on the original grid for every coarse grid point.
+    fn prolong_transpose<D2: Dimension>(&self, f: ArrayView<T, D2>) -> Array<T, D2> {
+        let offset = f.ndim() - self.periodic.len();
+        let mut f = f.into_dyn().to_owned();
+        let weight = 1.0 / self.factor as f64;
+        for (ax, &periodic) in (offset..f.ndim()).zip(&self.periodic) {
+            let mut shape = f.shape().to_vec();
+            shape[ax] /= self.factor;
+            let mut coarse = ArrayD::zeros(shape);
+            let stencil = self.stencil(coarse.shape()[ax], periodic);
+            for (f_i, &(j0, j1, w)) in f.axis_iter(Axis_nd(ax)).zip(&stencil) {
+                coarse
+                    .index_axis_mut(Axis_nd(ax), j0)
+                    .zip_mut_with(&f_i, |c, &f| *c += f * ((1.0 - w) * weight));
+                coarse
+                    .index_axis_mut(Axis_nd(ax), j1)
+                    .zip_mut_with(&f_i, |c, &f| *c += f * (w * weight));
+            }
+            f = coarse;
+        }
+        f.into_dimensionality().unwrap()
+    }
+
+    /// Transpose of `restrict` multiplied by `factor`, i.e., a piecewise
+    /// constant interpolation from the coarse grid to the original grid.
+    fn restrict_transpose<D2: Dimension>(&self, f: Array<T, D2>) -> Array<T, D2> {
+        let offset = f.ndim() - self.periodic.len();
+        let mut f = f.into_dyn();
+        for ax in offset..f.ndim() {
+            let mut shape = f.shape().to_vec();
+            shape[ax] *= self.factor;
+            let mut fine = ArrayD::zeros(shape);
+            for (i, mut c) in fine.axis_iter_mut(Axis_nd(ax)).enumerate() {
+                c.assign(&f.index_axis(Axis_nd(ax), i / self.factor));
+            }
+            f = fine;
+        }
+        f.into_dimensionality().unwrap()
+    }
+}
+
+impl<T, D: Dimension + 'static> Convolver<T, D> for CoarseConvolver<T, D>
+where
+    T: DualNum<f64> + Copy + Send + Sync + 'static,
+    D::Larger: Dimension<Smaller = D>,
+{
+    fn convolve(&self, profile: Array<T, D>, weight_function: &WeightFunction<T>) -> Array<T, D> {
+        let profile = self.restrict(profile.view());
+        self.prolong(self.convolver.convolve(profile, weight_function))
+    }
+
+    fn weighted_densities(&self, density: &Array<T, D::Larger>) -> Vec<Array<T, D::Larger>> {
+        self.convolver
+            .weighted_densities(&self.restrict(density.view()))
+            .into_iter()
+            .map(|n| self.prolong(n))
+            .collect()
+    }
+
+    fn functional_derivative(
+        &self,
+        partial_derivatives: &[Array<T, D::Larger>],
+    ) -> Array<T, D::Larger> {
+        // use the transposed operators to obtain the exact functional
+        // derivative of the coarsened functional
+        let partial_derivatives: Vec<_> = partial_derivatives
+            .iter()
+            .map(|pd| self.prolong_transpose(pd.view()))
+            .collect();
+        self.restrict_transpose(self.convolver.functional_derivative(&partial_derivatives))
+    }
+}
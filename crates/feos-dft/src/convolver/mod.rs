@@ -9,8 +9,10 @@ use rustdct::DctNum;
 use std::ops::{AddAssign, MulAssign, SubAssign};
 use std::sync::Arc;
 
+mod coarse_convolver;
 mod periodic_convolver;
 mod transform;
+pub use coarse_convolver::CoarseConvolver;
 pub use periodic_convolver::PeriodicConvolver;
 use transform::*;
 
@@ -21,6 +23,11 @@ use transform::*;
 /// Helmholtz energy functional.
 ///
 /// Parametrized over data types `T` and dimension of the problem `D`.
+///
+/// Convolvers are `Send + Sync`. The FFT plans of `rustfft` and `rustdct` are
+/// created without global state when the convolver is built and are only read
+/// during convolutions, so a convolver can be shared between threads without
+/// synchronization.
 pub trait Convolver<T, D: Dimension>: Send + Sync {
     /// Convolve the profile with the given weight function.
     fn convolve(&self, profile: Array<T, D>, weight_function: &WeightFunction<T>) -> Array<T, D>;
@@ -142,6 +149,7 @@ where
             Grid::Polar(r) => CurvilinearConvolver::new(r, &[], weight_functions, lanczos),
             Grid::Spherical(r) => CurvilinearConvolver::new(r, &[], weight_functions, lanczos),
             Grid::Cartesian1(z) => Self::new(Some(z), &[], weight_functions, lanczos),
+            Grid::Periodical1(z) => PeriodicConvolver::new(&[z], |_| (), weight_functions, lanczos),
             Grid::Cylindrical { r, z } => {
                 CurvilinearConvolver::new(r, &[z], weight_functions, lanczos)
             }
@@ -157,6 +165,34 @@ where
     }
 }
 
+impl<T, D: Dimension + RemoveAxis + 'static> ConvolverFFT<T, D>
+where
+    T: DctNum + DualNum<f64>,
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// Create the appropriate FFT convolver for the given grid that
+    /// evaluates the convolutions on a grid coarsened by `coarsening`.
+    ///
+    /// Falls back to the exact convolver if the grid can not be coarsened.
+    pub fn plan_coarse(
+        grid: &Grid,
+        weight_functions: &[WeightFunctionInfo<T>],
+        lanczos: Option<i32>,
+        coarsening: Option<usize>,
+    ) -> Arc<dyn Convolver<T, D>> {
+        match coarsening.and_then(|factor| Some((factor, grid.coarsen(factor)?))) {
+            Some((factor, (coarse_grid, periodic))) => CoarseConvolver::new(
+                Self::plan(&coarse_grid, weight_functions, lanczos),
+                factor,
+                periodic,
+            ),
+            None => Self::plan(grid, weight_functions, lanczos),
+        }
+    }
+}
+
 impl<T, D: Dimension + 'static> ConvolverFFT<T, D>
 where
     T: DctNum + DualNum<f64>,
@@ -1,12 +1,13 @@
 use feos_core::ReferenceSystem;
-use ndarray::{Array1, Array2};
-use quantity::{Angle, Length, Quantity};
+use ndarray::{Array1, Array2, s};
+use quantity::{Angle, Density, Length, Quantity};
 use std::f64::consts::{FRAC_PI_3, PI};
 
 /// Grids with up to three dimensions.
 #[derive(Clone)]
 pub enum Grid {
     Cartesian1(Axis),
+    Periodical1(Axis),
     Cartesian2(Axis, Axis),
     Periodical2(Axis, Axis, Angle),
     Cartesian3(Axis, Axis, Axis),
@@ -27,7 +28,7 @@ impl Grid {
 
     pub fn axes(&self) -> Vec<&Axis> {
         match self {
-            Self::Cartesian1(x) => vec![x],
+            Self::Cartesian1(x) | Self::Periodical1(x) => vec![x],
             Self::Cartesian2(x, y) | Self::Periodical2(x, y, _) => vec![x, y],
             Self::Cartesian3(x, y, z) | Self::Periodical3(x, y, z, _) => vec![x, y, z],
             Self::Spherical(r) | Self::Polar(r) => vec![r],
@@ -37,7 +38,7 @@ impl Grid {
 
     pub fn axes_mut(&mut self) -> Vec<&mut Axis> {
         match self {
-            Self::Cartesian1(x) => vec![x],
+            Self::Cartesian1(x) | Self::Periodical1(x) => vec![x],
             Self::Cartesian2(x, y) | Self::Periodical2(x, y, _) => vec![x, y],
             Self::Cartesian3(x, y, z) | Self::Periodical3(x, y, z, _) => vec![x, y, z],
             Self::Spherical(r) | Self::Polar(r) => vec![r],
@@ -49,6 +50,40 @@ impl Grid {
         self.axes().iter().map(|ax| &ax.grid).collect()
     }
 
+    /// Return the grid coarsened by `factor` in every dimension and the
+    /// periodicity of the axes.
+    ///
+    /// Only available for equidistant (cartesian and periodic) grids with
+    /// numbers of grid points that are divisible by `factor`.
+    pub(crate) fn coarsen(&self, factor: usize) -> Option<(Self, Vec<bool>)> {
+        Some(match self {
+            Self::Cartesian1(x) => (Self::Cartesian1(x.coarsen(factor)?), vec![false]),
+            Self::Periodical1(x) => (Self::Periodical1(x.coarsen(factor)?), vec![true]),
+            Self::Cartesian2(x, y) => (
+                Self::Cartesian2(x.coarsen(factor)?, y.coarsen(factor)?),
+                vec![false; 2],
+            ),
+            Self::Periodical2(x, y, alpha) => (
+                Self::Periodical2(x.coarsen(factor)?, y.coarsen(factor)?, *alpha),
+                vec![true; 2],
+            ),
+            Self::Cartesian3(x, y, z) => (
+                Self::Cartesian3(x.coarsen(factor)?, y.coarsen(factor)?, z.coarsen(factor)?),
+                vec![false; 3],
+            ),
+            Self::Periodical3(x, y, z, angles) => (
+                Self::Periodical3(
+                    x.coarsen(factor)?,
+                    y.coarsen(factor)?,
+                    z.coarsen(factor)?,
+                    *angles,
+                ),
+                vec![true; 3],
+            ),
+            Self::Spherical(_) | Self::Polar(_) | Self::Cylindrical { .. } => return None,
+        })
+    }
+
     pub(crate) fn integration_weights(&self) -> (Vec<&Array1<f64>>, f64) {
         (
             self.axes()
@@ -59,6 +94,19 @@ impl Grid {
         )
     }
 
+    pub(crate) fn quadrature_weights(
+        &self,
+        quadrature: Quadrature,
+    ) -> Option<(Vec<Array1<f64>>, f64)> {
+        Some((
+            self.axes()
+                .iter()
+                .map(|ax| ax.quadrature_weights(quadrature))
+                .collect::<Option<_>>()?,
+            self.functional_determinant(),
+        ))
+    }
+
     pub(crate) fn functional_determinant(&self) -> f64 {
         match &self {
             Self::Periodical2(_, _, alpha) => alpha.sin(),
@@ -71,6 +119,17 @@ impl Grid {
     }
 }
 
+/// Quadrature rules for the integration of profiles.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Quadrature {
+    /// Midpoint rule with the cells of the grid, available for all axes.
+    #[default]
+    Midpoint,
+    /// Simpson's rule between the grid points, only available for cartesian
+    /// axes with at least three grid points.
+    Simpson,
+}
+
 /// Geometries of individual axes.
 #[derive(Copy, Clone, PartialEq)]
 pub enum Geometry {
@@ -182,6 +241,59 @@ impl Axis {
         }
     }
 
+    /// Create a cartesian axis with `factor` times fewer grid points
+    /// spanning the same domain.
+    fn coarsen(&self, factor: usize) -> Option<Self> {
+        let points = self.grid.len();
+        if self.geometry != Geometry::Cartesian || factor == 0 || !points.is_multiple_of(factor) {
+            return None;
+        }
+        let edges: Array1<f64> = self.edges.iter().step_by(factor).copied().collect();
+        let cell_size = &edges.slice(s![1..]) - &edges.slice(s![..-1]);
+        let grid = &edges.slice(s![..-1]) + &(0.5 * &cell_size);
+        Some(Self {
+            geometry: self.geometry,
+            grid,
+            edges,
+            integration_weights: cell_size,
+            potential_offset: self.potential_offset,
+        })
+    }
+
+    /// Integration weights of the given quadrature rule.
+    ///
+    /// For [Quadrature::Simpson], the profile is integrated with Simpson's
+    /// rule for variable grid spacings between the grid points. If the number
+    /// of intervals is odd, the last interval and the half cells at the
+    /// boundaries of the axis are integrated with the quadratic interpolant of
+    /// the three closest grid points.
+    pub(crate) fn quadrature_weights(&self, quadrature: Quadrature) -> Option<Array1<f64>> {
+        let x = &self.grid;
+        let n = x.len();
+        match quadrature {
+            Quadrature::Midpoint => Some(self.integration_weights.clone()),
+            Quadrature::Simpson if self.geometry != Geometry::Cartesian || n < 3 => None,
+            Quadrature::Simpson => {
+                let mut weights = Array1::zeros(n);
+                let mut add = |k: usize, a: f64, b: f64| {
+                    let w = quadratic_weights([x[k], x[k + 1], x[k + 2]], a, b);
+                    for (j, w) in w.into_iter().enumerate() {
+                        weights[k + j] += w;
+                    }
+                };
+                for k in (0..n - 2).step_by(2) {
+                    add(k, x[k], x[k + 2]);
+                }
+                if n.is_multiple_of(2) {
+                    add(n - 3, x[n - 2], x[n - 1]);
+                }
+                add(0, self.edges[0], x[0]);
+                add(n - 3, x[n - 1], self.edges[n]);
+                Some(weights)
+            }
+        }
+    }
+
     /// Returns the total length of the axis.
     ///
     /// This includes the `potential_offset` and used e.g.
@@ -204,6 +316,35 @@ impl Axis {
         }) * length.powi(self.geometry.dimension())
     }
 
+    /// Linearly interpolate a density profile on this axis at the given
+    /// grid points.
+    ///
+    /// Outside of the axis, the values at the first and last grid points
+    /// are used.
+    pub(crate) fn interpolate_density(
+        &self,
+        density: &Density<Array2<f64>>,
+        grid: &Array1<f64>,
+    ) -> Density<Array2<f64>> {
+        let rho = density.to_reduced();
+        let n = self.grid.len();
+        Density::from_reduced(Array2::from_shape_fn(
+            (rho.nrows(), grid.len()),
+            |(i, k)| {
+                let x = grid[k];
+                let j = self.grid.iter().take_while(|&&g| g < x).count();
+                if j == 0 {
+                    rho[(i, 0)]
+                } else if j == n {
+                    rho[(i, n - 1)]
+                } else {
+                    let w = (x - self.grid[j - 1]) / (self.grid[j] - self.grid[j - 1]);
+                    (1.0 - w) * rho[(i, j - 1)] + w * rho[(i, j)]
+                }
+            },
+        ))
+    }
+
     /// Interpolate a function on the given axis.
     pub fn interpolate<U>(
         &self,
@@ -234,3 +375,17 @@ impl Axis {
         ))
     }
 }
+
+/// Integrals of the Lagrange polynomials through the three points `x` from
+/// `a` to `b`.
+fn quadratic_weights(x: [f64; 3], a: f64, b: f64) -> [f64; 3] {
+    let integral = |p: f64, q: f64| {
+        let f = |z: f64| z * z * z / 3.0 - 0.5 * (p + q) * z * z + p * q * z;
+        f(b) - f(a)
+    };
+    [
+        integral(x[1], x[2]) / ((x[0] - x[1]) * (x[0] - x[2])),
+        integral(x[0], x[2]) / ((x[1] - x[0]) * (x[1] - x[2])),
+        integral(x[0], x[1]) / ((x[2] - x[0]) * (x[2] - x[1])),
+    ]
+}
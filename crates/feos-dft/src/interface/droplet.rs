@@ -0,0 +1,175 @@
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::{Axis, Grid};
+use crate::profile::{DFTProfile, DFTSpecifications};
+use crate::solver::DFTSolver;
+use feos_core::{Contributions, FeosError, FeosResult, ReferenceSystem, State, StateBuilder};
+use nalgebra::DVector;
+use ndarray::Ix1;
+use quantity::{Density, Energy, Length, Moles, Pressure};
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const MAX_ITER: usize = 50;
+const TOL: f64 = 1e-5;
+const INITIAL_STEP: f64 = 1e-2;
+const MAX_STEP: f64 = 0.1;
+
+/// Density profile and properties of a critical nucleus (droplet) in a
+/// supersaturated vapor.
+#[derive(Clone)]
+pub struct Droplet<F: HelmholtzEnergyFunctional> {
+    pub profile: DFTProfile<Ix1, F>,
+    pub vapor: State<F>,
+    pub nucleation_barrier: Option<Energy>,
+    pub critical_radius: Option<Length>,
+    pub nucleus_moles: Option<Moles>,
+}
+
+impl<F: HelmholtzEnergyFunctional> Droplet<F> {
+    /// Initialize a droplet in the center of a spherical domain with a
+    /// hyperbolic tangent density profile between `liquid` and `vapor`.
+    pub fn from_tanh(
+        vapor: &State<F>,
+        liquid: &State<F>,
+        n_grid: usize,
+        l_grid: Length,
+        radius: Length,
+    ) -> Self {
+        // generate grid
+        let grid = Grid::Spherical(Axis::new_spherical(n_grid, l_grid));
+        let mut profile = DFTProfile::new(grid, vapor, None, None, None);
+
+        // calculate density profile
+        let indices = vapor.eos.component_index();
+        let r0 = radius.to_reduced();
+        profile.density = Density::from_shape_fn(profile.density.raw_dim(), |(i, r)| {
+            let rho_v = vapor.partial_density.get(indices[i]);
+            let rho_l = liquid.partial_density.get(indices[i]);
+            0.5 * (rho_l - rho_v) * (0.5 * (r0 - profile.grid.grids()[0][r])).tanh()
+                + 0.5 * (rho_l + rho_v)
+        });
+
+        // fix the size of the droplet during the iteration
+        profile.specification = Arc::new(DFTSpecifications::total_moles_from_profile(&profile));
+
+        Self {
+            profile,
+            vapor: vapor.clone(),
+            nucleation_barrier: None,
+            critical_radius: None,
+            nucleus_moles: None,
+        }
+    }
+
+    /// Solve the profile for the total number of particles that results in a
+    /// bulk phase at the state of the supersaturated vapor.
+    ///
+    /// The critical nucleus is a saddle point of the grand potential and
+    /// therefore unstable at constant chemical potential. Instead, the total
+    /// number of particles is varied in an outer secant iteration.
+    pub fn solve_inplace(&mut self, solver: Option<&DFTSolver>, debug: bool) -> FeosResult<()> {
+        let DFTSpecifications::TotalMoles { total_moles: mut n } =
+            DFTSpecifications::total_moles_from_profile(&self.profile)
+        else {
+            unreachable!()
+        };
+        let mut f = self.solve_total_moles(n, solver, debug)?;
+        let mut n_old = n;
+        let mut f_old = f;
+        n *= 1.0 + INITIAL_STEP * f.signum();
+        for _ in 0..MAX_ITER {
+            f = self.solve_total_moles(n, solver, debug)?;
+            if f.abs() < TOL {
+                self.postprocess()?;
+                return Ok(());
+            }
+            let n_new = n - f * (n - n_old) / (f - f_old);
+            n_old = n;
+            f_old = f;
+            n = n_new.clamp(n * (1.0 - MAX_STEP), n * (1.0 + MAX_STEP));
+        }
+        Err(FeosError::NotConverged("Droplet::solve".into()))
+    }
+
+    pub fn solve(mut self, solver: Option<&DFTSolver>) -> FeosResult<Self> {
+        self.solve_inplace(solver, false)?;
+        Ok(self)
+    }
+
+    /// Solve the profile for a given total number of particles and return the
+    /// logarithmic deviation of the bulk density from the vapor density.
+    fn solve_total_moles(
+        &mut self,
+        total_moles: f64,
+        solver: Option<&DFTSolver>,
+        debug: bool,
+    ) -> FeosResult<f64> {
+        self.profile.specification = Arc::new(DFTSpecifications::TotalMoles { total_moles });
+        self.profile.solve(solver, debug)?;
+        Ok((self.profile.bulk.density / self.vapor.density)
+            .into_value()
+            .ln())
+    }
+
+    fn postprocess(&mut self) -> FeosResult<()> {
+        let volume = self.profile.volume();
+
+        // excess grand potential of the critical nucleus
+        self.nucleation_barrier = Some(
+            self.profile.grand_potential()? + self.vapor.pressure(Contributions::Total) * volume,
+        );
+
+        // number of particles in the nucleus
+        let nucleus_moles = self.profile.total_moles() - self.vapor.density * volume;
+        self.nucleus_moles = Some(nucleus_moles);
+
+        // equimolar radius based on the density in the center of the droplet
+        let delta_rho = self.center_density().sum() - self.vapor.density;
+        self.critical_radius = Some((3.0 * nucleus_moles / (4.0 * PI * delta_rho)).cbrt());
+
+        Ok(())
+    }
+
+    /// Partial densities of all components in the center of the droplet.
+    fn center_density(&self) -> Density<DVector<f64>> {
+        let mut rho_center = DVector::zeros(self.vapor.eos.components());
+        let mut segments = DVector::zeros(self.vapor.eos.components());
+        for (i, &j) in self.vapor.eos.component_index().iter().enumerate() {
+            rho_center[j] += self.profile.density.get((i, 0)).to_reduced();
+            segments[j] += 1.0;
+        }
+        Density::from_reduced(rho_center.component_div(&segments))
+    }
+
+    /// Calculate the Laplace pressure, i.e., the difference between the
+    /// pressure inside the droplet and the pressure of the vapor.
+    ///
+    /// The pressure inside the droplet is the bulk pressure evaluated at the
+    /// densities in the center of the droplet.
+    pub fn laplace_pressure(&self) -> FeosResult<Pressure> {
+        let rho_center = self.center_density();
+        let inside = StateBuilder::new(&self.vapor.eos)
+            .temperature(self.vapor.temperature)
+            .partial_density(&rho_center)
+            .build()?;
+        Ok(inside.pressure(Contributions::Total) - self.vapor.pressure(Contributions::Total))
+    }
+
+    /// Calculate the relative deviation of the Laplace pressure from
+    /// $2\gamma/R$.
+    ///
+    /// The radius $R$ is the critical radius and the surface tension
+    /// $\gamma$ follows from the nucleation barrier
+    /// $\Delta\Omega=\frac{4\pi}{3}\gamma R^2$. Returns `None` if the droplet
+    /// has not been solved yet.
+    pub fn laplace_consistency_residual(&self) -> FeosResult<Option<f64>> {
+        let (Some(barrier), Some(radius)) = (self.nucleation_barrier, self.critical_radius) else {
+            return Ok(None);
+        };
+        let surface_tension = 3.0 * barrier / self.profile.dividing_surface_area(radius)?;
+        let laplace_pressure = 2.0 * surface_tension / radius;
+        Ok(Some(
+            (self.laplace_pressure()? / laplace_pressure).into_value() - 1.0,
+        ))
+    }
+}
@@ -0,0 +1,121 @@
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::{Axis, Grid};
+use crate::profile::{DFTProfile, DFTSpecifications};
+use crate::solver::DFTSolver;
+use feos_core::{Contributions, FeosError, FeosResult, ReferenceSystem, State};
+use nalgebra::DVector;
+use ndarray::Ix1;
+use quantity::{Area, Density, Length, RGAS, SurfaceTension};
+use std::sync::Arc;
+
+/// Density profile and surface tension of a liquid film with a free surface
+/// towards vacuum.
+///
+/// The liquid is located at the beginning of the (planar) domain and the
+/// vacuum at the end. The number of particles of every component is fixed
+/// during the iteration, so the film can only exchange particles with the
+/// vacuum region, which results in the vapor that coexists with the liquid.
+/// At low temperatures, this vapor is negligible and the interface is a
+/// liquid-vacuum interface.
+#[derive(Clone)]
+pub struct FreeSurface<F: HelmholtzEnergyFunctional> {
+    pub profile: DFTProfile<Ix1, F>,
+    pub liquid: State<F>,
+    pub surface_tension: Option<SurfaceTension>,
+}
+
+impl<F: HelmholtzEnergyFunctional> FreeSurface<F> {
+    /// Initialize a liquid film of thickness `thickness` with a one-sided
+    /// hyperbolic tangent that decays from the density of `liquid` to the
+    /// (small) density in the vacuum region.
+    ///
+    /// The density in the vacuum region is estimated as the density of an
+    /// ideal gas with the same chemical potential as the liquid.
+    pub fn from_tanh(liquid: &State<F>, n_grid: usize, l_grid: Length, thickness: Length) -> Self {
+        // generate grid
+        let grid = Grid::Cartesian1(Axis::new_cartesian(n_grid, l_grid, None));
+        let mut profile = DFTProfile::new(grid, liquid, None, None, None);
+
+        // estimate the density in the vacuum region
+        let mu_res = liquid.residual_chemical_potential() / (RGAS * liquid.temperature);
+        let rho_vac = Density::from_shape_fn(liquid.eos.components(), |i| {
+            liquid.partial_density.get(i) * mu_res.get(i).into_value().exp()
+        });
+
+        // calculate density profile
+        let indices = liquid.eos.component_index();
+        let z0 = thickness.to_reduced();
+        profile.density = Density::from_shape_fn(profile.density.raw_dim(), |(i, z)| {
+            let rho_l = liquid.partial_density.get(indices[i]);
+            let rho_v = rho_vac.get(indices[i]);
+            0.5 * (rho_l - rho_v) * (0.5 * (z0 - profile.grid.grids()[0][z])).tanh()
+                + 0.5 * (rho_l + rho_v)
+        });
+
+        // conserve the number of particles in the film
+        profile.specification = Arc::new(DFTSpecifications::moles_from_profile(&profile));
+
+        Self {
+            profile,
+            liquid: liquid.clone(),
+            surface_tension: None,
+        }
+    }
+
+    pub fn solve_inplace(&mut self, solver: Option<&DFTSolver>, debug: bool) -> FeosResult<()> {
+        // Solve the profile
+        self.profile.solve(solver, debug)?;
+
+        // postprocess
+        self.postprocess()
+    }
+
+    pub fn solve(mut self, solver: Option<&DFTSolver>) -> FeosResult<Self> {
+        self.solve_inplace(solver, false)?;
+        Ok(self)
+    }
+
+    fn postprocess(&mut self) -> FeosResult<()> {
+        // the bulk state of the solved profile is the liquid in the film
+        self.liquid = self.profile.bulk.clone();
+
+        // surface tension from the excess grand potential with respect to the
+        // pressure of the liquid, which vanishes in the vacuum limit
+        let omega = self.profile.grand_potential()?;
+        let pressure = self.liquid.pressure(Contributions::Total);
+        self.surface_tension =
+            Some((omega + pressure * self.profile.volume()) / Area::from_reduced(1.0));
+        Ok(())
+    }
+
+    /// Partial densities of all components at the end of the domain, i.e.,
+    /// in the vacuum (or vapor) region.
+    pub fn vacuum_density(&self) -> Density<DVector<f64>> {
+        let n = self.profile.density.shape()[1];
+        let mut rho = DVector::zeros(self.liquid.eos.components());
+        for (i, &j) in self.liquid.eos.component_index().iter().enumerate() {
+            rho[j] = self.profile.density.get((i, n - 1)).to_reduced();
+        }
+        Density::from_reduced(rho)
+    }
+
+    /// Check whether the vacuum region is sufficiently dilute.
+    ///
+    /// Returns an error, if the density at the end of the domain exceeds
+    /// `tol` times the density of the liquid for any component. In that
+    /// case, the temperature is too high for a liquid-vacuum interface or the
+    /// domain is too small.
+    pub fn check_vacuum(&self, tol: f64) -> FeosResult<()> {
+        let rho_vac = self.vacuum_density();
+        let rho_l = &self.liquid.partial_density;
+        for i in 0..rho_vac.len() {
+            let ratio = (rho_vac.get(i) / rho_l.get(i)).into_value();
+            if ratio > tol {
+                return Err(FeosError::Error(format!(
+                    "The density of component {i} in the vacuum region is {ratio:.3e} times the density of the liquid."
+                )));
+            }
+        }
+        Ok(())
+    }
+}
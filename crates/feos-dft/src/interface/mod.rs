@@ -1,19 +1,53 @@
 //! Density profiles at planar interfaces and interfacial tensions.
-use crate::functional::HelmholtzEnergyFunctional;
-use crate::geometry::{Axis, Grid};
+use crate::adsorption::{FluidParameters, PoreProfile1D};
+use crate::convolver::Convolver;
+use crate::functional::{HelmholtzEnergyFunctional, MoleculeShape};
+use crate::geometry::{Axis, Geometry, Grid};
 use crate::pdgt::PdgtFunctionalProperties;
-use crate::profile::{DFTProfile, DFTSpecifications};
+use crate::profile::{DFTProfile, DFTSpecifications, richardson_extrapolation};
 use crate::solver::DFTSolver;
-use feos_core::{Contributions, FeosError, FeosResult, PhaseEquilibrium, ReferenceSystem};
-use ndarray::{Array1, Array2, Axis as Axis_nd, Ix1, s};
-use quantity::{Area, Density, Length, Moles, SurfaceTension, Temperature};
+use feos_core::{
+    Contributions, FeosError, FeosResult, Molarweight, PhaseEquilibrium, ReferenceSystem, State,
+    StateBuilder, Verbosity, log_result,
+};
+use nalgebra::DVector;
+use ndarray::{Array, Array1, Array2, Axis as Axis_nd, Ix1, s};
+use quantity::{
+    _Density, _Length, Angle, Area, Density, Dimensionless, Length, METER, MOL, MolarEntropy,
+    Moles, PASCAL, Pressure, Quantity, RADIANS, RGAS, SECOND, SurfaceTension, Temperature,
+};
 use std::sync::Arc;
+use typenum::{Diff, P2, P3};
 
+mod droplet;
+mod free_surface;
 mod surface_tension_diagram;
-pub use surface_tension_diagram::SurfaceTensionDiagram;
+pub use droplet::Droplet;
+pub use free_surface::FreeSurface;
+pub use surface_tension_diagram::{SurfaceTensionCache, SurfaceTensionDiagram};
 
-const RELATIVE_WIDTH: f64 = 6.0;
-const MIN_WIDTH: f64 = 100.0;
+/// Default width of the calculation domain in `from_pdgt` relative to the width of the pDGT profile.
+pub const RELATIVE_WIDTH: f64 = 6.0;
+/// Default minimum width of the calculation domain in `from_pdgt` in units of the reference length (Å).
+pub const MIN_WIDTH: f64 = 100.0;
+/// Default number of grid points in [surface_tension].
+pub const DEFAULT_GRID_POINTS: usize = 1024;
+
+const MAX_ITER_TANH_FIT: usize = 100;
+const TOL_TANH_FIT: f64 = 1e-10;
+const MAX_ITER_TARGET_SURFACE_TENSION: usize = 50;
+const TOL_TARGET_SURFACE_TENSION: f64 = 1e-8;
+const TOL_PLATEAU: f64 = 1e-3;
+const TOL_COEXISTENCE: f64 = 1e-6;
+/// Standard acceleration of gravity in m/s².
+const STANDARD_GRAVITY: f64 = 9.80665;
+const TOL_REDUCTION_CONVERGENCE_ERROR: f64 = 0.1;
+/// Minimum ratio of the density differences between the boundaries of a
+/// warm-restarted and the original profile.
+const MIN_DENSITY_CONTRAST: f64 = 0.5;
+
+pub type _DensityGradient = Diff<_Density, _Length>;
+pub type DensityGradient<T> = Quantity<T, _DensityGradient>;
 
 /// Density profile and properties of a planar interface.
 #[derive(Clone)]
@@ -30,18 +64,76 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         self.profile.solve(solver, debug)?;
 
         // postprocess
-        self.surface_tension = Some(
-            (self.profile.integrate(
-                &(self.profile.grand_potential_density()?
-                    + self.vle.vapor().pressure(Contributions::Total)),
-            )) / Area::from_reduced(1.0),
-        );
-        let delta_rho = self.vle.liquid().density - self.vle.vapor().density;
+        self.postprocess()?;
+
+        let verbosity = solver.map_or(Verbosity::None, |solver| solver.verbosity);
+        if let Some(warning) = self.non_spherical_warning() {
+            log_result!(verbosity, "Warning: {warning}");
+        }
+        if let Some(warning) = self.metastability_warning() {
+            log_result!(verbosity, "Warning: {warning}");
+        }
+        Ok(())
+    }
+
+    /// Diagnostic for interfaces of non-spherical (chain) molecules.
+    ///
+    /// The functional describes chains through the density profiles of their
+    /// segments and does not resolve the orientation of the molecules. The
+    /// surface tension therefore contains no contribution from an ordering of
+    /// the chains at the interface, and profiles of heterosegmented molecules
+    /// are given per segment rather than per molecule. Returns `None` for
+    /// spherical molecules, including homosegmented molecules with $m\leq 1$.
+    pub fn non_spherical_warning(&self) -> Option<String> {
+        match self.profile.bulk.eos.molecule_shape() {
+            MoleculeShape::Spherical(_) => None,
+            MoleculeShape::NonSpherical(m) if m.iter().all(|&m| m <= 1.0) => None,
+            MoleculeShape::NonSpherical(m) => Some(format!(
+                "The interface contains chain molecules (m = {:?}) that are described by isotropic segment densities. Orientational ordering at the interface is not resolved.",
+                m.as_slice()
+            )),
+            MoleculeShape::Heterosegmented(_) => Some(String::from(
+                "The interface contains heterosegmented molecules that are described by isotropic segment densities. Orientational ordering at the interface is not resolved and the density profiles refer to segments, not molecules.",
+            )),
+        }
+    }
+
+    /// Diagnostic for interfaces between bulk phases that are not in
+    /// equilibrium, see [PlanarInterface::from_states].
+    ///
+    /// Returns `None` if the pressures and the chemical potentials of both
+    /// phases in `vle` agree.
+    pub fn metastability_warning(&self) -> Option<String> {
+        let (vapor, liquid) = (self.vle.vapor(), self.vle.liquid());
+        let p_v = vapor.pressure(Contributions::Total);
+        let p_l = liquid.pressure(Contributions::Total);
+        let rt = RGAS * vapor.temperature;
+        let mu_res = ((liquid.residual_chemical_potential() - vapor.residual_chemical_potential())
+            / rt)
+            .into_value();
+        let rho_v = vapor.partial_density.to_reduced();
+        let rho_l = liquid.partial_density.to_reduced();
+        let dp = ((p_l - p_v) / p_l.abs().max(p_v.abs())).into_value();
+        let dmu = (0..rho_v.len())
+            .map(|i| (mu_res[i] + (rho_l[i] / rho_v[i]).ln()).abs())
+            .fold(0.0, f64::max);
+        (dp.abs() > TOL_COEXISTENCE || dmu > TOL_COEXISTENCE).then(|| {
+            format!(
+                "The bulk phases of the interface are not in equilibrium (relative pressure difference {dp:.3e}, chemical potential difference {dmu:.3e} RT). The surface tension is a constrained interfacial tension of metastable states."
+            )
+        })
+    }
+
+    fn postprocess(&mut self) -> FeosResult<()> {
+        let (omega, offset) = self.surface_tension_contributions()?;
+        self.surface_tension = Some(omega + offset);
+        let rho = self.mass_weighted_total_density();
+        let rho_v = rho.get(self.vapor_index());
+        let delta_rho = rho.get(0) - rho_v;
         self.equimolar_radius = Some(
-            self.profile
-                .integrate(&(self.profile.density.sum_axis(Axis_nd(0)) - self.vle.vapor().density))
+            self.profile.integrate(&(rho - rho_v))
                 / delta_rho
-                / Area::from_reduced(1.0),
+                / Area::from_reduced(self.n_interfaces()),
         );
 
         Ok(())
@@ -51,6 +143,232 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         self.solve_inplace(solver, false)?;
         Ok(self)
     }
+
+    /// Solve the interface with the tolerance and the maximum number of
+    /// iterations of the solver overridden for this call.
+    ///
+    /// The overrides apply to the last algorithm of the solver, which decides
+    /// whether the profile is converged, see [DFTSolver::tolerance] and
+    /// [DFTSolver::max_iter].
+    pub fn solve_with_tolerance(
+        mut self,
+        solver: Option<&DFTSolver>,
+        tol: Option<f64>,
+        max_iter: Option<usize>,
+    ) -> FeosResult<Self> {
+        let solver = DFTSolver::with_overrides(solver, tol, max_iter);
+        self.solve_inplace(Some(&solver), false)?;
+        Ok(self)
+    }
+
+    /// Weighted densities of the functional contributions at the interface,
+    /// see [DFTProfile::weighted_densities_by_contribution].
+    pub fn weighted_densities(&self) -> FeosResult<Vec<(&'static str, Array2<f64>)>> {
+        self.profile.weighted_densities_by_contribution()
+    }
+
+    /// Absolute difference between the surface tensions of `self` and `other`.
+    ///
+    /// Together with [DFTProfile::max_density_difference], this can be used to
+    /// compare two solved interfaces, e.g., in regression tests.
+    pub fn surface_tension_difference(&self, other: &Self) -> FeosResult<SurfaceTension> {
+        let (Some(gamma1), Some(gamma2)) = (self.surface_tension, other.surface_tension) else {
+            return Err(FeosError::Error(String::from(
+                "Both planar interfaces have to be solved to compare their surface tensions.",
+            )));
+        };
+        Ok((gamma1 - gamma2).abs())
+    }
+
+    /// Contributions to the surface tension of the current density profile.
+    ///
+    /// Returns the integrated grand potential density and the offset from the
+    /// bulk pressure that is added to it, both per interfacial area. Their sum
+    /// is the surface tension, which helps to analyze unexpected (e.g. negative)
+    /// surface tensions. If the pressures of the bulk phases differ, see
+    /// [PlanarInterface::from_states], the pressure of the liquid is used on
+    /// the liquid side of the equimolar dividing surface.
+    pub fn surface_tension_contributions(&self) -> FeosResult<(SurfaceTension, SurfaceTension)> {
+        let area = Area::from_reduced(self.n_interfaces());
+        let omega = self.profile.grand_potential_density()?;
+        let p_v = self.vle.vapor().pressure(Contributions::Total).to_reduced();
+        let p_l = self
+            .vle
+            .liquid()
+            .pressure(Contributions::Total)
+            .to_reduced();
+        let offset = if (p_l - p_v).abs() > TOL_COEXISTENCE * p_l.abs().max(p_v.abs()) {
+            let x = self.liquid_fraction(self.equimolar_position());
+            Pressure::from_reduced(&x * p_l + (1.0 - &x) * p_v)
+        } else {
+            Pressure::from_reduced(Array::from_elem(omega.raw_dim(), p_v))
+        };
+        Ok((
+            self.profile.integrate(&omega) / area,
+            self.profile.integrate(&offset) / area,
+        ))
+    }
+
+    /// Work of cohesion $W_\mathrm{c}=2\gamma_\mathrm{lv}$, i.e., the work
+    /// required to separate a column of liquid of unit area.
+    pub fn work_of_cohesion(&self) -> FeosResult<SurfaceTension> {
+        let gamma = self.surface_tension.ok_or_else(|| {
+            FeosError::Error(String::from(
+                "The planar interface has to be solved to calculate the work of cohesion.",
+            ))
+        })?;
+        Ok(2.0 * gamma)
+    }
+
+    /// Work of adhesion $W_\mathrm{a}=\gamma_\mathrm{lv}+\gamma_\mathrm{sv}-\gamma_\mathrm{sl}$
+    /// of the liquid on a wall.
+    ///
+    /// The wall tensions are the interfacial tensions of solved profiles of
+    /// the same wall in contact with the coexisting `vapor` and `liquid` of
+    /// the interface, e.g., obtained with [Wall1D](crate::adsorption::Wall1D).
+    /// The position of the dividing surface at the wall cancels in the
+    /// difference. For partial wetting, Young's equation gives
+    /// $W_\mathrm{a}=\gamma_\mathrm{lv}(1+\cos\theta)$, so the work of
+    /// adhesion lies between zero (drying) and the work of cohesion
+    /// (complete wetting).
+    pub fn work_of_adhesion(
+        &self,
+        vapor: &PoreProfile1D<F>,
+        liquid: &PoreProfile1D<F>,
+    ) -> FeosResult<SurfaceTension> {
+        let gamma_lv = self.surface_tension.ok_or_else(|| {
+            FeosError::Error(String::from(
+                "The planar interface has to be solved to calculate the work of adhesion.",
+            ))
+        })?;
+        let gamma_sv = wall_tension(vapor, self.vle.vapor(), "vapor")?;
+        let gamma_sl = wall_tension(liquid, self.vle.liquid(), "liquid")?;
+        Ok(gamma_lv + gamma_sv - gamma_sl)
+    }
+
+    /// Spreading coefficient $S=\gamma_\mathrm{sv}-\gamma_\mathrm{sl}-\gamma_\mathrm{lv}$
+    /// of the liquid on a wall.
+    ///
+    /// The wall tensions are calculated from the solved profiles of the same
+    /// wall in contact with the coexisting `vapor` and `liquid`, see
+    /// [PlanarInterface::work_of_adhesion]. The spreading coefficient is the
+    /// difference between the works of adhesion and cohesion. For $S>0$, the
+    /// liquid spreads on the wall, see [PlanarInterface::is_spreading].
+    pub fn spreading_coefficient(
+        &self,
+        vapor: &PoreProfile1D<F>,
+        liquid: &PoreProfile1D<F>,
+    ) -> FeosResult<SurfaceTension> {
+        let gamma_lv = self.surface_tension.ok_or_else(|| {
+            FeosError::Error(String::from(
+                "The planar interface has to be solved to calculate the spreading coefficient.",
+            ))
+        })?;
+        let gamma_sv = wall_tension(vapor, self.vle.vapor(), "vapor")?;
+        let gamma_sl = wall_tension(liquid, self.vle.liquid(), "liquid")?;
+        Ok(gamma_sv - gamma_sl - gamma_lv)
+    }
+
+    /// Whether the liquid spreads on a wall, i.e., whether the spreading
+    /// coefficient calculated with [PlanarInterface::spreading_coefficient]
+    /// is positive.
+    pub fn is_spreading(
+        &self,
+        vapor: &PoreProfile1D<F>,
+        liquid: &PoreProfile1D<F>,
+    ) -> FeosResult<bool> {
+        Ok(self.spreading_coefficient(vapor, liquid)?.to_reduced() > 0.0)
+    }
+
+    /// Classify the wetting of a wall by the liquid of the interface.
+    ///
+    /// The contact angle follows from Young's equation
+    /// $\cos\theta=\frac{\gamma_\mathrm{sv}-\gamma_\mathrm{sl}}{\gamma_\mathrm{lv}}$
+    /// with the wall tensions of the solved profiles of the same wall in
+    /// contact with the coexisting `vapor` and `liquid`, see
+    /// [PlanarInterface::work_of_adhesion]. Values of $\cos\theta\geq 1$
+    /// correspond to complete wetting and values of $\cos\theta\leq -1$
+    /// to complete drying.
+    pub fn wetting_state(
+        &self,
+        vapor: &PoreProfile1D<F>,
+        liquid: &PoreProfile1D<F>,
+    ) -> FeosResult<WettingState> {
+        let gamma_lv = self.surface_tension.ok_or_else(|| {
+            FeosError::Error(String::from(
+                "The planar interface has to be solved to determine the wetting state.",
+            ))
+        })?;
+        let gamma_sv = wall_tension(vapor, self.vle.vapor(), "vapor")?;
+        let gamma_sl = wall_tension(liquid, self.vle.liquid(), "liquid")?;
+        let cos_theta = ((gamma_sv - gamma_sl) / gamma_lv).into_value();
+        Ok(if cos_theta >= 1.0 {
+            WettingState::Complete
+        } else if cos_theta <= -1.0 {
+            WettingState::Dry
+        } else {
+            WettingState::Partial(cos_theta.acos() * RADIANS)
+        })
+    }
+}
+
+/// Wetting of a wall by a liquid in coexistence with its vapor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WettingState {
+    /// Complete wetting, i.e., a macroscopic liquid film at the wall.
+    Complete,
+    /// Partial wetting with the contact angle measured through the liquid.
+    Partial(Angle),
+    /// Complete drying, i.e., a macroscopic vapor film at the wall.
+    Dry,
+}
+
+/// Interfacial properties of a single component of a planar interface.
+#[derive(Clone)]
+pub struct ComponentInterfacialProperties {
+    /// Index of the component.
+    pub component: usize,
+    /// Indices of the density profiles (segments) of the component.
+    pub segments: Vec<usize>,
+    /// Relative adsorption $\Gamma_i^{(j)}$ of the component $i$ with respect
+    /// to every component $j$.
+    pub relative_adsorption: Moles<DVector<f64>>,
+    /// Interfacial enrichment of the component.
+    pub interfacial_enrichment: f64,
+}
+
+/// Interfacial tension of a solved planar wall in contact with `phase`.
+fn wall_tension<F: HelmholtzEnergyFunctional>(
+    wall: &PoreProfile1D<F>,
+    phase: &State<F>,
+    name: &str,
+) -> FeosResult<SurfaceTension> {
+    if wall.profile.grid.axes()[0].geometry != Geometry::Cartesian {
+        return Err(FeosError::Error(String::from(
+            "Wall tensions are only available for planar walls.",
+        )));
+    }
+    let omega = wall.interfacial_tension.ok_or_else(|| {
+        FeosError::Error(format!(
+            "The profile at the wall in contact with the {name} has to be solved."
+        ))
+    })?;
+    let bulk = &wall.profile.bulk;
+    let deviation = ((&bulk.partial_density - &phase.partial_density)
+        .to_reduced()
+        .norm()
+        / phase.partial_density.to_reduced().norm())
+    .max(
+        ((bulk.temperature - phase.temperature) / phase.temperature)
+            .into_value()
+            .abs(),
+    );
+    if deviation > TOL_COEXISTENCE {
+        return Err(FeosError::Error(format!(
+            "The bulk phase of the wall profile is not the coexisting {name} of the interface."
+        )));
+    }
+    Ok(omega / Area::from_reduced(1.0))
 }
 
 impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
@@ -66,6 +384,56 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         }
     }
 
+    /// Initialize a planar interface with a precomputed convolver.
+    ///
+    /// Planning the convolver is skipped, which saves time if many interfaces
+    /// are calculated with the same functional, temperature and grid. The
+    /// caller is responsible for the consistency of the convolver, e.g., by
+    /// reusing `profile.convolver` of an interface with the same temperature,
+    /// `n_grid` and `l_grid`, see [DFTProfile::new_with_convolver].
+    pub fn new_with_convolver(
+        vle: &PhaseEquilibrium<F, 2>,
+        n_grid: usize,
+        l_grid: Length,
+        convolver: Arc<dyn Convolver<f64, Ix1>>,
+    ) -> Self {
+        // generate grid
+        let grid = Grid::Cartesian1(Axis::new_cartesian(n_grid, l_grid, None));
+
+        Self {
+            profile: DFTProfile::new_with_convolver(grid, convolver, vle.vapor(), None, None, None),
+            vle: vle.clone(),
+            surface_tension: None,
+            equimolar_radius: None,
+        }
+    }
+
+    /// Initialize a planar interface on a periodic domain.
+    ///
+    /// The domain contains two interfaces, e.g., to mimic a slab of liquid
+    /// in molecular simulations with periodic boundary conditions. The bulk
+    /// liquid is located at the edges and the bulk vapor in the center of the
+    /// domain.
+    pub fn new_periodic(vle: &PhaseEquilibrium<F, 2>, n_grid: usize, l_grid: Length) -> Self {
+        // generate grid
+        let grid = Grid::Periodical1(Axis::new_cartesian(n_grid, l_grid, None));
+
+        Self {
+            profile: DFTProfile::new(grid, vle.vapor(), None, None, None),
+            vle: vle.clone(),
+            surface_tension: None,
+            equimolar_radius: None,
+        }
+    }
+
+    /// Evaluate the convolutions on a grid that is coarsened by `factor`.
+    ///
+    /// This trades accuracy for speed, see [DFTProfile::coarsen_convolution].
+    pub fn coarsen_convolution(mut self, factor: usize) -> FeosResult<Self> {
+        self.profile.coarsen_convolution(factor)?;
+        Ok(self)
+    }
+
     pub fn from_tanh(
         vle: &PhaseEquilibrium<F, 2>,
         n_grid: usize,
@@ -73,23 +441,119 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         critical_temperature: Temperature,
         fix_equimolar_surface: bool,
     ) -> Self {
-        let mut profile = Self::new(vle, n_grid, l_grid);
+        Self::new(vle, n_grid, l_grid).initialize_tanh(critical_temperature, fix_equimolar_surface)
+    }
+
+    /// Initialize a planar interface between two bulk states that are not
+    /// necessarily in equilibrium, e.g., a superheated liquid and a vapor,
+    /// with a hyperbolic tangent.
+    ///
+    /// Both states have to be at the same temperature and the density of the
+    /// vapor has to be lower than the density of the liquid. If the states are
+    /// not in equilibrium, the planar interface is not stationary and the
+    /// surface tension is only a constrained interfacial tension that depends
+    /// on the specification and the size of the domain, see
+    /// [PlanarInterface::metastability_warning]. In this case, the equimolar
+    /// dividing surface should be fixed with `fix_equimolar_surface`.
+    pub fn from_states(
+        vapor: &State<F>,
+        liquid: &State<F>,
+        n_grid: usize,
+        l_grid: Length,
+        critical_temperature: Temperature,
+        fix_equimolar_surface: bool,
+    ) -> FeosResult<Self> {
+        if vapor.temperature != liquid.temperature {
+            return Err(FeosError::Error(String::from(
+                "The bulk states of the interface have to be at the same temperature.",
+            )));
+        }
+        if vapor.density >= liquid.density {
+            return Err(FeosError::Error(String::from(
+                "The density of the vapor has to be lower than the density of the liquid.",
+            )));
+        }
+        let vle = PhaseEquilibrium([vapor.clone(), liquid.clone()]);
+        Ok(Self::from_tanh(
+            &vle,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface,
+        ))
+    }
+
+    /// Initialize a planar interface with a hyperbolic tangent and a
+    /// precomputed convolver, see [PlanarInterface::new_with_convolver].
+    pub fn from_tanh_with_convolver(
+        vle: &PhaseEquilibrium<F, 2>,
+        n_grid: usize,
+        l_grid: Length,
+        convolver: Arc<dyn Convolver<f64, Ix1>>,
+        critical_temperature: Temperature,
+        fix_equimolar_surface: bool,
+    ) -> Self {
+        Self::new_with_convolver(vle, n_grid, l_grid, convolver)
+            .initialize_tanh(critical_temperature, fix_equimolar_surface)
+    }
 
+    fn initialize_tanh(
+        mut self,
+        critical_temperature: Temperature,
+        fix_equimolar_surface: bool,
+    ) -> Self {
         // calculate segment indices
-        let indices = &profile.profile.bulk.eos.component_index();
+        let indices = &self.profile.bulk.eos.component_index();
 
         // calculate density profile
-        let z0 = 0.5 * l_grid.to_reduced();
+        let z0 = 0.5 * self.profile.grid.axes()[0].length();
         let (z0, sign) = (z0.abs(), -z0.signum());
+        let reduced_temperature =
+            (self.vle.vapor().temperature / critical_temperature).into_value();
+        self.profile.density = Density::from_shape_fn(self.profile.density.raw_dim(), |(i, z)| {
+            let rho_v = self.vle.vapor().partial_density.get(indices[i]);
+            let rho_l = self.vle.liquid().partial_density.get(indices[i]);
+            0.5 * (rho_l - rho_v)
+                * (sign * (self.profile.grid.grids()[0][z] - z0) / 3.0
+                    * (2.4728 - 2.3625 * reduced_temperature))
+                    .tanh()
+                + 0.5 * (rho_l + rho_v)
+        });
+
+        // specify specification
+        if fix_equimolar_surface {
+            self.profile.specification =
+                Arc::new(DFTSpecifications::total_moles_from_profile(&self.profile));
+        }
+
+        self
+    }
+
+    /// Initialize a liquid slab with two interfaces on a periodic domain
+    /// with hyperbolic tangents.
+    pub fn from_tanh_periodic(
+        vle: &PhaseEquilibrium<F, 2>,
+        n_grid: usize,
+        l_grid: Length,
+        critical_temperature: Temperature,
+        fix_equimolar_surface: bool,
+    ) -> Self {
+        let mut profile = Self::new_periodic(vle, n_grid, l_grid);
+
+        // calculate segment indices
+        let indices = &profile.profile.bulk.eos.component_index();
+
+        // calculate density profile
+        let l = l_grid.to_reduced();
         let reduced_temperature = (vle.vapor().temperature / critical_temperature).into_value();
+        let k = (2.4728 - 2.3625 * reduced_temperature) / 3.0;
         profile.profile.density =
             Density::from_shape_fn(profile.profile.density.raw_dim(), |(i, z)| {
                 let rho_v = profile.vle.vapor().partial_density.get(indices[i]);
                 let rho_l = profile.vle.liquid().partial_density.get(indices[i]);
+                let z = profile.profile.grid.grids()[0][z];
                 0.5 * (rho_l - rho_v)
-                    * (sign * (profile.profile.grid.grids()[0][z] - z0) / 3.0
-                        * (2.4728 - 2.3625 * reduced_temperature))
-                        .tanh()
+                    * ((k * (0.25 * l - z)).tanh() + (k * (z - 0.75 * l)).tanh() + 1.0)
                     + 0.5 * (rho_l + rho_v)
             });
 
@@ -107,6 +571,27 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         vle: &PhaseEquilibrium<F, 2>,
         n_grid: usize,
         fix_equimolar_surface: bool,
+    ) -> FeosResult<Self> {
+        Self::from_pdgt_with_width(
+            vle,
+            n_grid,
+            fix_equimolar_surface,
+            RELATIVE_WIDTH,
+            Length::from_reduced(MIN_WIDTH),
+        )
+    }
+
+    /// Initialize the interface from a pDGT calculation with a custom width of the calculation domain.
+    ///
+    /// The width of the domain is the maximum of `min_width` and `relative_width` times the
+    /// width of the pDGT profile. Fluids with long-ranged interactions may require larger
+    /// values than the defaults [RELATIVE_WIDTH] and [MIN_WIDTH] to resolve the bulk phases.
+    pub fn from_pdgt_with_width(
+        vle: &PhaseEquilibrium<F, 2>,
+        n_grid: usize,
+        fix_equimolar_surface: bool,
+        relative_width: f64,
+        min_width: Length,
     ) -> FeosResult<Self> {
         let dft = &vle.vapor().eos;
 
@@ -120,16 +605,10 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         let mut w_pdgt = Length::from_reduced(0.0);
         let (rho_pdgt, gamma_pdgt) =
             dft.solve_pdgt(vle, 20, 0, Some((&mut z_pdgt, &mut w_pdgt)))?;
-        if !gamma_pdgt.to_reduced().is_normal() {
-            return Err(FeosError::InvalidState(
-                String::from("DFTProfile::from_pdgt"),
-                String::from("gamma_pdgt"),
-                gamma_pdgt.to_reduced(),
-            ));
-        }
+        check_pdgt_surface_tension(gamma_pdgt)?;
 
         // create PlanarInterface
-        let l_grid = Length::from_reduced(MIN_WIDTH).max(w_pdgt * RELATIVE_WIDTH);
+        let l_grid = min_width.max(w_pdgt * relative_width);
         let mut profile = Self::new(vle, n_grid, l_grid);
 
         // interpolate density profile from pDGT to DFT
@@ -155,38 +634,384 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
 }
 
 impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
-    pub fn shift_equimolar_inplace(&mut self) {
-        let s = self.profile.density.shape();
-        let m = &self.profile.bulk.eos.m();
-        let mut rho_l = Density::from_reduced(0.0);
-        let mut rho_v = Density::from_reduced(0.0);
-        let mut rho = Density::zeros(s[1]);
-        for i in 0..s[0] {
-            rho_l += self.profile.density.get((i, 0)) * m[i];
-            rho_v += self.profile.density.get((i, s[1] - 1)) * m[i];
-            rho += &(&self.profile.density.index_axis(Axis_nd(0), i) * m[i]);
+    /// Surface tension of the phase equilibrium from perturbed density gradient theory (pDGT).
+    ///
+    /// In contrast to [PlanarInterface::from_pdgt], the result is not checked. A negative
+    /// value can indicate a wetting transition, a NaN value indicates that the pDGT
+    /// calculation diverged.
+    pub fn pdgt_surface_tension(vle: &PhaseEquilibrium<F, 2>) -> FeosResult<SurfaceTension> {
+        let dft = &vle.vapor().eos;
+        if dft.component_index().len() != 1 {
+            return Err(FeosError::Error(String::from(
+                "pDGT surface tensions are only available for pure, non-segment functionals.",
+            )));
         }
+        Ok(dft.solve_pdgt(vle, 20, 0, None)?.1)
+    }
 
-        let x = (rho - rho_v) / (rho_l - rho_v);
-        let ze = self.profile.grid.axes()[0].edges[0] + self.profile.integrate(&x).to_reduced();
-        self.profile.grid.axes_mut()[0].grid -= ze;
+    /// Surface tension of the phase equilibrium of the interface from perturbed
+    /// density gradient theory (pDGT).
+    ///
+    /// The result does not depend on the density profile and can be compared to
+    /// the DFT result in [PlanarInterface::surface_tension] to assess the
+    /// accuracy of the much cheaper gradient theory.
+    pub fn surface_tension_pdgt(&self) -> FeosResult<SurfaceTension> {
+        Self::pdgt_surface_tension(&self.vle)
     }
+}
 
-    pub fn shift_equimolar(mut self) -> Self {
-        self.shift_equimolar_inplace();
-        self
+/// Vapor-liquid surface tension of a pure component at the given temperature.
+///
+/// Convenience function for the complete workflow: the phase equilibrium is
+/// calculated, the interface is initialized from pDGT on a domain of
+/// automatically chosen width ([RELATIVE_WIDTH], [MIN_WIDTH]) with
+/// [DEFAULT_GRID_POINTS] grid points and solved. Use [PlanarInterface]
+/// directly for more control over the calculation.
+pub fn surface_tension<F: HelmholtzEnergyFunctional>(
+    functional: &F,
+    temperature: Temperature,
+    solver: Option<&DFTSolver>,
+) -> FeosResult<SurfaceTension> {
+    if functional.component_index().len() != 1 {
+        return Err(FeosError::Error(String::from(
+            "The surface tension can only be calculated directly for pure, non-segment functionals.",
+        )));
+    }
+    let vle = PhaseEquilibrium::pure(functional, temperature, None, Default::default())?;
+    let interface = PlanarInterface::from_pdgt(&vle, DEFAULT_GRID_POINTS, false)?.solve(solver)?;
+    Ok(interface.surface_tension.unwrap())
+}
+
+/// Temperature at which the vapor-liquid surface tension of a pure component
+/// equals `target`.
+///
+/// The surface tension decreases monotonically with the temperature and
+/// vanishes at the critical point. Starting from the temperatures in
+/// `bracket`, the temperature is determined by bisection, where the phase
+/// equilibrium and the interface are solved at every temperature as in
+/// [surface_tension]. Returns an error if the target is not positive or if it
+/// lies outside the range of surface tensions at the temperatures in
+/// `bracket`, both of which have to be below the critical temperature.
+pub fn temperature_for_surface_tension<F: HelmholtzEnergyFunctional>(
+    functional: &F,
+    target: SurfaceTension,
+    bracket: (Temperature, Temperature),
+    solver: Option<&DFTSolver>,
+) -> FeosResult<Temperature> {
+    let gamma_target = target.to_reduced();
+    if gamma_target <= 0.0 {
+        return Err(FeosError::Error(String::from(
+            "The target surface tension has to be positive, the surface tension vanishes at the critical point.",
+        )));
+    }
+    let residual = |t: f64| -> FeosResult<f64> {
+        let gamma = surface_tension(functional, Temperature::from_reduced(t), solver)?;
+        Ok(gamma.to_reduced() - gamma_target)
+    };
+    let (mut t_low, mut t_high) = (bracket.0.to_reduced(), bracket.1.to_reduced());
+    if t_low > t_high {
+        std::mem::swap(&mut t_low, &mut t_high);
+    }
+    let (f_low, f_high) = (residual(t_low)?, residual(t_high)?);
+    if f_low < 0.0 || f_high > 0.0 {
+        return Err(FeosError::Error(format!(
+            "The target surface tension {target} is outside of the range between {} and {} within the temperature bracket.",
+            SurfaceTension::from_reduced(f_high + gamma_target),
+            SurfaceTension::from_reduced(f_low + gamma_target),
+        )));
+    }
+
+    for _ in 0..MAX_ITER_TARGET_SURFACE_TENSION {
+        let t = 0.5 * (t_low + t_high);
+        let f = residual(t)?;
+        if f.abs() < TOL_TARGET_SURFACE_TENSION * gamma_target
+            || t_high - t_low < TOL_TARGET_SURFACE_TENSION * t
+        {
+            return Ok(Temperature::from_reduced(t));
+        }
+        if f > 0.0 {
+            t_low = t;
+        } else {
+            t_high = t;
+        }
+    }
+    Err(FeosError::NotConverged(String::from(
+        "temperature_for_surface_tension",
+    )))
+}
+
+/// Check the pDGT surface tension and distinguish diverged calculations from
+/// negative surface tensions that can occur in the vicinity of wetting transitions.
+fn check_pdgt_surface_tension(gamma_pdgt: SurfaceTension) -> FeosResult<()> {
+    let gamma = gamma_pdgt.to_reduced();
+    if gamma.is_nan() || gamma.is_infinite() {
+        Err(FeosError::IterationFailed(format!(
+            "PlanarInterface::from_pdgt (pDGT diverged with gamma_pdgt = {gamma_pdgt})"
+        )))
+    } else if gamma < 0.0 {
+        Err(FeosError::InvalidState(
+            String::from("PlanarInterface::from_pdgt"),
+            String::from("gamma_pdgt (negative surface tension, possible wetting transition)"),
+            gamma,
+        ))
+    } else if !gamma.is_normal() {
+        Err(FeosError::InvalidState(
+            String::from("PlanarInterface::from_pdgt"),
+            String::from("gamma_pdgt (vanishing surface tension, trivial interface)"),
+            gamma,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
+    /// Number of interfaces in the calculation domain.
+    fn n_interfaces(&self) -> f64 {
+        match self.profile.grid {
+            Grid::Periodical1(_) => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Index of the grid point that represents the bulk vapor phase.
+    fn vapor_index(&self) -> usize {
+        let n_grid = self.profile.density.shape()[1];
+        match self.profile.grid {
+            Grid::Periodical1(_) => n_grid / 2,
+            _ => n_grid - 1,
+        }
+    }
+
+    /// Partial densities of every component at the liquid boundary and at the
+    /// vapor boundary (the center of periodic profiles) of the profile.
+    pub fn boundary_densities(&self) -> (Density<DVector<f64>>, Density<DVector<f64>>) {
+        let v = self.vapor_index();
+        let density = self.profile.density.to_reduced();
+        let components = self.profile.bulk.eos.components();
+        let mut rho_l = DVector::zeros(components);
+        let mut rho_v = DVector::zeros(components);
+        for (i, &c) in self.profile.bulk.eos.component_index().iter().enumerate() {
+            rho_l[c] = density[(i, 0)];
+            rho_v[c] = density[(i, v)];
+        }
+        (Density::from_reduced(rho_l), Density::from_reduced(rho_v))
+    }
+
+    /// Check whether the densities at the boundaries of the profile deviate
+    /// from the partial densities of the bulk phases in `vle` by less than the
+    /// relative tolerance `tol` for every component.
+    ///
+    /// If false, the calculation domain is too narrow for the profile to reach
+    /// the bulk phases and properties that are derived from the boundary
+    /// densities, like the relative adsorption or the interfacial thickness,
+    /// are not reliable.
+    pub fn boundary_is_bulk(&self, tol: f64) -> bool {
+        let (rho_l, rho_v) = self.boundary_densities();
+        [
+            (rho_l, &self.vle.liquid().partial_density),
+            (rho_v, &self.vle.vapor().partial_density),
+        ]
+        .into_iter()
+        .all(|(rho, rho_bulk)| {
+            rho.to_reduced()
+                .iter()
+                .zip(rho_bulk.to_reduced().iter())
+                .all(|(rho, rho_bulk)| ((rho - rho_bulk) / rho_bulk).abs() < tol)
+        })
+    }
+
+    /// Replace the bulk phases in `vle` by the states at the boundaries of
+    /// the density profile.
+    ///
+    /// The states are evaluated at the temperature of the profile and the
+    /// partial densities at the liquid boundary and at the vapor boundary (the
+    /// center of periodic profiles). In general, they are not exactly in phase
+    /// equilibrium. If the profile is already solved, the surface tension and
+    /// the equimolar radius are recalculated with the new bulk phases.
+    ///
+    /// Use this if the profile does not correspond to the original VLE anymore,
+    /// e.g., after manual modifications with `set_density` or if the
+    /// calculation domain is too narrow for the profile to reach the bulk
+    /// densities. For profiles that are solved for the chemical potential of
+    /// the original VLE on a sufficiently wide domain, the original VLE is
+    /// more accurate and should be kept.
+    pub fn update_vle_from_profile_inplace(&mut self) -> FeosResult<()> {
+        let eos = &self.profile.bulk.eos;
+        let (rho_l, rho_v) = self.boundary_densities();
+        let state = |rho| {
+            StateBuilder::new(eos)
+                .temperature(self.profile.temperature)
+                .partial_density(rho)
+                .build()
+        };
+        self.vle = PhaseEquilibrium([state(&rho_v)?, state(&rho_l)?]);
+        if self.surface_tension.is_some() {
+            self.postprocess()?;
+        }
+        Ok(())
+    }
+
+    pub fn update_vle_from_profile(mut self) -> FeosResult<Self> {
+        self.update_vle_from_profile_inplace()?;
+        Ok(self)
+    }
+
+    /// Total segment density $\sum_im_i\rho_i(z)$ of the profile.
+    ///
+    /// All properties that are based on the total density of the profile
+    /// (equimolar dividing surface, interfacial thickness, tanh fit) use this
+    /// density, so that chain molecules are treated consistently. The bulk
+    /// densities are the boundary values of the profile.
+    fn mass_weighted_total_density(&self) -> Density<Array1<f64>> {
+        let m = Array1::from(self.profile.bulk.eos.m().to_vec());
+        let density = self.profile.density.to_reduced();
+        Density::from_reduced(m.dot(&density))
+    }
+
+    /// Position of the (first) equimolar dividing surface in reduced units.
+    ///
+    /// The liquid side of the profile is the boundary with the higher density,
+    /// so that profiles with the vapor phase at the beginning of the domain
+    /// (or at the edges of a periodic domain) are handled as well.
+    fn equimolar_position(&self) -> f64 {
+        let rho = self.mass_weighted_total_density();
+        let rho_0 = rho.get(0);
+        let rho_v = rho.get(self.vapor_index());
+        let (rho_l, rho_v) = if rho_0 >= rho_v {
+            (rho_0, rho_v)
+        } else {
+            (rho_v, rho_0)
+        };
+        let mut x = ((rho - rho_v) / (rho_l - rho_v)).into_value();
+        if rho_0 < rho_l {
+            x.mapv_inplace(|x| 1.0 - x);
+        }
+        self.profile.grid.axes()[0].edges[0]
+            + self
+                .profile
+                .integrate(&Dimensionless::from_reduced(x))
+                .to_reduced()
+                / self.n_interfaces()
+    }
+
+    /// Check that both boundaries of the profile are at bulk plateaus with
+    /// different densities, which is required to locate the equimolar
+    /// dividing surface.
+    fn check_bulk_plateaus(&self) -> FeosResult<()> {
+        let rho = self.mass_weighted_total_density().to_reduced();
+        let v = self.vapor_index();
+        let delta_rho = (rho[0] - rho[v]).abs();
+        if delta_rho.is_nan() || delta_rho <= TOL_PLATEAU * rho[0].max(rho[v]) {
+            return Err(FeosError::Error(String::from(
+                "The densities at the boundaries of the profile do not differ.",
+            )));
+        }
+        let max_slope = (rho[1] - rho[0]).abs().max((rho[v - 1] - rho[v]).abs());
+        if max_slope > TOL_PLATEAU * delta_rho {
+            return Err(FeosError::Error(String::from(
+                "The boundaries of the profile are not at bulk plateaus.",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Position of the equimolar dividing surface in the coordinates of the
+    /// grid.
+    ///
+    /// In contrast to [PlanarInterface::shift_equimolar], the grid is not
+    /// modified. Together with the methods that take the position of the
+    /// dividing surface as parameter, e.g.,
+    /// [PlanarInterface::adsorption_at_dividing_surface] and
+    /// [PlanarInterface::interfacial_moles_at], interfacial properties can be
+    /// evaluated without side effects. Returns an error if the boundaries of
+    /// the profile are not at bulk plateaus with different densities.
+    pub fn equimolar_dividing_surface(&self) -> FeosResult<Length> {
+        self.check_bulk_plateaus()?;
+        Ok(Length::from_reduced(self.equimolar_position()))
+    }
+
+    /// Shift the grid such that the equimolar dividing surface is located at
+    /// zero.
+    ///
+    /// Returns an error if the boundaries of the profile are not at bulk
+    /// plateaus with different densities.
+    pub fn shift_equimolar_inplace(&mut self) -> FeosResult<()> {
+        let ze = self.equimolar_dividing_surface()?.to_reduced();
+        let axis = &mut self.profile.grid.axes_mut()[0];
+        axis.grid -= ze;
+        axis.edges -= ze;
+        Ok(())
+    }
+
+    pub fn shift_equimolar(mut self) -> FeosResult<Self> {
+        self.shift_equimolar_inplace()?;
+        Ok(self)
+    }
+
+    /// Number of moles of each component within a band of width `2 half_width`
+    /// centered on the equimolar dividing surface.
+    ///
+    /// For periodic domains, the bands around both interfaces are included
+    /// and grid cells in overlapping bands are only counted once.
+    /// Grid cells that are only partially covered by the band contribute
+    /// proportionally to their overlap with the band.
+    pub fn interfacial_moles(&self, half_width: Length) -> Moles<DVector<f64>> {
+        self.interfacial_moles_at(Length::from_reduced(self.equimolar_position()), half_width)
+    }
+
+    /// Number of moles of each component within a band of width `2 half_width`
+    /// centered on an explicit dividing surface at `dividing_surface`.
+    ///
+    /// The position is given in the coordinates of the grid, see
+    /// [PlanarInterface::interfacial_moles] for details.
+    pub fn interfacial_moles_at(
+        &self,
+        dividing_surface: Length,
+        half_width: Length,
+    ) -> Moles<DVector<f64>> {
+        let axis = self.profile.grid.axes()[0];
+        let edges = &axis.edges;
+        let h = half_width.to_reduced();
+        let ze = dividing_surface.to_reduced();
+        let mut centers = vec![ze];
+        if let Grid::Periodical1(_) = self.profile.grid {
+            centers.push(edges[0] + edges[edges.len() - 1] - ze);
+        }
+        let weights = Array1::from_shape_fn(axis.grid.len(), |k| {
+            centers
+                .iter()
+                .map(|&z| (edges[k + 1].min(z + h) - edges[k].max(z - h)).max(0.0))
+                .sum::<f64>()
+                .min(edges[k + 1] - edges[k])
+                / (edges[k + 1] - edges[k])
+        });
+        let density = Density::from_reduced(self.profile.density.to_reduced() * weights);
+        self.profile.integrate_segments(&density)
     }
 
     /// Relative adsorption of component `i' with respect to `j': \Gamma_i^(j)
+    ///
+    /// The relative adsorption is calculated from the partial densities of
+    /// the individual components (segments for segment-based functionals)
+    /// and is therefore not weighted by the number of segments.
     pub fn relative_adsorption(&self) -> Moles<Array2<f64>> {
-        let s = self.profile.density.shape();
+        self.relative_adsorption_of(&self.profile.density)
+    }
+
+    /// Relative adsorptions of arbitrary density profiles (segments or
+    /// components) of the interface.
+    fn relative_adsorption_of(&self, density: &Density<Array2<f64>>) -> Moles<Array2<f64>> {
+        let s = density.shape();
+        let v = self.vapor_index();
+        let n_interfaces = self.n_interfaces();
         let mut rho_l = Density::zeros(s[0]);
         let mut rho_v = Density::zeros(s[0]);
 
         // Calculate the partial densities in the liquid and in the vapor phase
         for i in 0..s[0] {
-            rho_l.set(i, self.profile.density.get((i, 0)));
-            rho_v.set(i, self.profile.density.get((i, s[1] - 1)));
+            rho_l.set(i, density.get((i, 0)));
+            rho_v.set(i, density.get((i, v)));
         }
 
         // Calculate \Gamma_i^(j)
@@ -196,21 +1021,67 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
             } else {
                 self.profile.integrate(
                     &(-(rho_l.get(i) - rho_v.get(i))
-                        * ((&self.profile.density.index_axis(Axis_nd(0), j) - rho_l.get(j))
+                        * ((&density.index_axis(Axis_nd(0), j) - rho_l.get(j))
                             / (rho_l.get(j) - rho_v.get(j))
-                            - (&self.profile.density.index_axis(Axis_nd(0), i) - rho_l.get(i))
+                            - (&density.index_axis(Axis_nd(0), i) - rho_l.get(i))
                                 / (rho_l.get(i) - rho_v.get(i)))),
-                )
+                ) / n_interfaces
             }
         })
     }
 
+    /// Adsorption of every component with respect to an explicit dividing
+    /// surface at `dividing_surface`: \Gamma_i(z_s)
+    ///
+    /// The position is given in the coordinates of the grid. In the reference
+    /// system, the bulk liquid fills the domain up to the dividing surface and
+    /// the bulk vapor the rest of the domain. A positive value indicates that
+    /// the interface contains more molecules of the component than the
+    /// reference system. In contrast to [PlanarInterface::relative_adsorption],
+    /// the result depends on the position of the dividing surface; the relative
+    /// adsorption follows as
+    /// \Gamma_i^(j) = \Gamma_i(z_s) - \Gamma_j(z_s) (rho_i^L - rho_i^V) / (rho_j^L - rho_j^V).
+    /// For periodic domains, the second dividing surface is placed symmetrically
+    /// and the result is the average over both interfaces.
+    pub fn adsorption_at_dividing_surface(&self, dividing_surface: Length) -> Moles<Array1<f64>> {
+        let x = self.liquid_fraction(dividing_surface.to_reduced());
+        let density = self.profile.density.to_reduced();
+        let v = self.vapor_index();
+        Moles::from_reduced(Array1::from_shape_fn(density.nrows(), |i| {
+            let (rho_l, rho_v) = (density[(i, 0)], density[(i, v)]);
+            let excess = &density.row(i) - &(&x * rho_l + (1.0 - &x) * rho_v);
+            self.profile
+                .integrate(&Density::from_reduced(excess))
+                .to_reduced()
+                / self.n_interfaces()
+        }))
+    }
+
+    /// Fraction of every grid cell that is filled with the bulk liquid in the
+    /// reference system with a dividing surface at `z_s`.
+    fn liquid_fraction(&self, z_s: f64) -> Array1<f64> {
+        let edges = &self.profile.grid.axes()[0].edges;
+        let n = edges.len() - 1;
+        let fraction =
+            |k: usize, z_s: f64| ((z_s - edges[k]) / (edges[k + 1] - edges[k])).clamp(0.0, 1.0);
+        Array1::from_shape_fn(n, |k| match self.profile.grid {
+            Grid::Periodical1(_) => fraction(k, z_s) + 1.0 - fraction(k, edges[0] + edges[n] - z_s),
+            _ => fraction(k, z_s),
+        })
+    }
+
     /// Interfacial enrichment of component `i': E_i
     pub fn interfacial_enrichment(&self) -> Array1<f64> {
-        let s = self.profile.density.shape();
-        let density = self.profile.density.to_reduced();
+        self.interfacial_enrichment_of(&self.profile.density)
+    }
+
+    /// Interfacial enrichment of arbitrary density profiles (segments or
+    /// components) of the interface.
+    fn interfacial_enrichment_of(&self, density: &Density<Array2<f64>>) -> Array1<f64> {
+        let s = density.shape();
+        let density = density.to_reduced();
         let rho_l = density.index_axis(Axis_nd(1), 0);
-        let rho_v = density.index_axis(Axis_nd(1), s[1] - 1);
+        let rho_v = density.index_axis(Axis_nd(1), self.vapor_index());
 
         Array1::from_shape_fn(s[0], |i| {
             *(density
@@ -222,10 +1093,96 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         })
     }
 
-    /// Interface thickness (90-10 number density difference)
-    pub fn interfacial_thickness(&self) -> FeosResult<Length> {
+    /// Density profiles of the components, i.e., the average of the density
+    /// profiles of all segments of a component.
+    ///
+    /// For functionals that are not segment-based, the density profiles of the
+    /// components are identical to the density profiles of the interface.
+    pub fn component_density(&self) -> Density<Array2<f64>> {
+        let eos = &self.profile.bulk.eos;
+        let density = self.profile.density.to_reduced();
+        let mut rho = Array2::zeros((eos.components(), density.ncols()));
+        let mut segments = vec![0.0; eos.components()];
+        for (s, &c) in eos.component_index().iter().enumerate() {
+            let mut rho_c = rho.index_axis_mut(Axis_nd(0), c);
+            rho_c += &density.index_axis(Axis_nd(0), s);
+            segments[c] += 1.0;
+        }
+        for (mut rho_c, n) in rho.outer_iter_mut().zip(segments) {
+            rho_c /= n;
+        }
+        Density::from_reduced(rho)
+    }
+
+    /// Interfacial properties of every component of the interface.
+    ///
+    /// In contrast to [PlanarInterface::relative_adsorption] and
+    /// [PlanarInterface::interfacial_enrichment], which are indexed by the
+    /// density profiles (segments for segment-based functionals), the
+    /// properties are calculated from [PlanarInterface::component_density]
+    /// and labeled with the index of the component and its segments, which
+    /// avoids mis-indexing in mixtures with many components.
+    pub fn component_properties(&self) -> Vec<ComponentInterfacialProperties> {
+        let density = self.component_density();
+        let relative_adsorption = self.relative_adsorption_of(&density);
+        let enrichment = self.interfacial_enrichment_of(&density);
+        let component_index = self.profile.bulk.eos.component_index();
+        (0..density.shape()[0])
+            .map(|i| ComponentInterfacialProperties {
+                component: i,
+                segments: (0..component_index.len())
+                    .filter(|&s| component_index[s] == i)
+                    .collect(),
+                relative_adsorption: Moles::from_reduced(DVector::from_iterator(
+                    density.shape()[0],
+                    relative_adsorption.to_reduced().row(i).iter().copied(),
+                )),
+                interfacial_enrichment: enrichment[i],
+            })
+            .collect()
+    }
+
+    /// Interfacial enrichment (or depletion) of component `i' and the position
+    /// of the corresponding extremum in the density profile.
+    ///
+    /// For `depletion = false`, the enrichment is $\max(\rho_i)/\max(\rho_i^L,\rho_i^V)\geq 1$
+    /// at the position of the maximum. For `depletion = true`, the depletion is
+    /// $\min(\rho_i)/\min(\rho_i^L,\rho_i^V)\leq 1$ at the position of the minimum.
+    pub fn interfacial_enrichment_extremum(
+        &self,
+        depletion: bool,
+    ) -> (Array1<f64>, Length<Array1<f64>>) {
         let s = self.profile.density.shape();
-        let rho = self.profile.density.sum_axis(Axis_nd(0)).to_reduced();
+        let density = self.profile.density.to_reduced();
+        let z = self.profile.grid.grids()[0];
+        let rho_l = density.index_axis(Axis_nd(1), 0);
+        let rho_v = density.index_axis(Axis_nd(1), self.vapor_index());
+
+        let mut enrichment = Array1::zeros(s[0]);
+        let mut position = Array1::zeros(s[0]);
+        for (i, rho) in density.outer_iter().enumerate() {
+            let rho = rho.iter().enumerate();
+            // panics only if iterator is empty
+            let (k, &rho_ext) = if depletion {
+                rho.min_by(|(_, a), (_, b)| a.total_cmp(b)).unwrap()
+            } else {
+                rho.max_by(|(_, a), (_, b)| a.total_cmp(b)).unwrap()
+            };
+            let rho_bulk = if depletion {
+                rho_l[i].min(rho_v[i])
+            } else {
+                rho_l[i].max(rho_v[i])
+            };
+            enrichment[i] = rho_ext / rho_bulk;
+            position[i] = z[k];
+        }
+        (enrichment, Length::from_reduced(position))
+    }
+
+    /// Interface thickness (90-10 segment density difference)
+    pub fn interfacial_thickness(&self) -> FeosResult<Length> {
+        let v = self.vapor_index();
+        let rho = self.mass_weighted_total_density().into_reduced();
         let z = self.profile.grid.grids()[0];
         let dz = z[1] - z[0];
 
@@ -248,8 +1205,8 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         }
 
         // Get the densities in the liquid and in the vapor phase
-        let rho_v = rho[0].min(rho[s[1] - 1]);
-        let rho_l = rho[0].max(rho[s[1] - 1]);
+        let rho_v = rho[0].min(rho[v]);
+        let rho_l = rho[0].max(rho[v]);
 
         if (rho_l - rho_v).abs() < 1.0e-10 {
             return Ok(Length::from_reduced(0.0));
@@ -261,7 +1218,7 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
 
         // Get indizes right of intersection between density profile and
         // constant density boundaries
-        let index_upper_plus = if rho[0] >= rho[s[1] - 1] {
+        let index_upper_plus = if rho[0] >= rho[v] {
             rho.iter()
                 .enumerate()
                 .find(|&(_, &x)| (x - rho_upper).is_sign_negative())
@@ -274,7 +1231,7 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
                 .expect("Could not find rho_upper value!")
                 .0
         };
-        let index_lower_plus = if rho[0] >= rho[s[1] - 1] {
+        let index_lower_plus = if rho[0] >= rho[v] {
             rho.iter()
                 .enumerate()
                 .find(|&(_, &x)| (x - rho_lower).is_sign_negative())
@@ -304,14 +1261,341 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         Ok(Length::from_reduced(z_lower - z_upper))
     }
 
+    /// Density profile interpolated linearly at arbitrary positions `z`,
+    /// e.g., the bins of a histogram from molecular simulations.
+    ///
+    /// The positions do not have to be sorted. Between the boundary of the
+    /// calculation domain and the outermost grid points, the densities at
+    /// these grid points are used. Positions outside of the calculation
+    /// domain are flagged with NaN densities.
+    pub fn resample(&self, z: &Length<Array1<f64>>) -> FeosResult<Density<Array2<f64>>> {
+        let z = z.to_reduced();
+        if z.iter().any(|z| !z.is_finite()) {
+            return Err(FeosError::Error(String::from(
+                "The positions for the resampling have to be finite.",
+            )));
+        }
+        let axis = self.profile.grid.axes()[0];
+        let (grid, edges) = (&axis.grid, &axis.edges);
+        let n = grid.len();
+        let density = self.profile.density.to_reduced();
+
+        // interp requires sorted positions within the grid
+        let mut order: Vec<_> = (0..z.len()).collect();
+        order.sort_by(|&i, &j| z[i].total_cmp(&z[j]));
+        let z_sorted = Array1::from_iter(order.iter().map(|&i| z[i].clamp(grid[0], grid[n - 1])));
+        let rho_sorted = interp(
+            grid,
+            &density,
+            &z_sorted,
+            &density.column(0).to_owned(),
+            &density.column(n - 1).to_owned(),
+            false,
+        );
+
+        let mut rho = Array2::from_elem((density.nrows(), z.len()), f64::NAN);
+        for (k, &i) in order.iter().enumerate() {
+            if z[i] >= edges[0] && z[i] <= edges[n] {
+                rho.column_mut(i).assign(&rho_sorted.column(k));
+            }
+        }
+        Ok(Density::from_reduced(rho))
+    }
+
+    /// Derivative of the density profile of every component (segment for
+    /// segment-based functionals) with respect to the position on the grid.
+    ///
+    /// The derivatives are calculated with central differences. At the
+    /// boundaries of non-periodic domains, second-order one-sided differences
+    /// are used. The extremum of the gradient locates the interface.
+    pub fn density_gradient(&self) -> FeosResult<DensityGradient<Array2<f64>>> {
+        let z = &self.profile.grid.grids()[0];
+        let n = z.len();
+        if n < 3 {
+            return Err(FeosError::Error(String::from(
+                "The density gradient requires at least 3 grid points.",
+            )));
+        }
+        let dz = z[1] - z[0];
+        let rho = self.profile.density.to_reduced();
+        let periodic = matches!(self.profile.grid, Grid::Periodical1(_));
+        let gradient = Array2::from_shape_fn(rho.raw_dim(), |(i, j)| match j {
+            0 if periodic => (rho[(i, 1)] - rho[(i, n - 1)]) / (2.0 * dz),
+            0 => (-3.0 * rho[(i, 0)] + 4.0 * rho[(i, 1)] - rho[(i, 2)]) / (2.0 * dz),
+            j if j == n - 1 && periodic => (rho[(i, 0)] - rho[(i, n - 2)]) / (2.0 * dz),
+            j if j == n - 1 => {
+                (3.0 * rho[(i, j)] - 4.0 * rho[(i, j - 1)] + rho[(i, j - 2)]) / (2.0 * dz)
+            }
+            j => (rho[(i, j + 1)] - rho[(i, j - 1)]) / (2.0 * dz),
+        });
+        Ok(Density::from_reduced(gradient) / Length::from_reduced(1.0))
+    }
+
+    /// Local residual (excess) molar entropy $s^\mathrm{res}(z)/\rho(z)$
+    /// across the interface, e.g., for entropy scaling of transport
+    /// properties at interfaces.
+    ///
+    /// The residual entropy density is the temperature derivative of the
+    /// local residual Helmholtz energy density at fixed density profile,
+    /// see [DFTProfile::residual_entropy_density]. At the bulk plateaus, the
+    /// profile approaches the residual molar entropies of the coexisting
+    /// phases. Grid points with vanishing density are NaN.
+    pub fn excess_entropy_profile(&self) -> FeosResult<MolarEntropy<Array1<f64>>> {
+        let s_res = self.profile.residual_entropy_density()?;
+        let rho = self.profile.density.sum_axis(Axis_nd(0));
+        Ok(s_res / rho)
+    }
+
+    /// Labeled table of the density profile for plotting and export.
+    ///
+    /// The columns contain the grid positions `z`, the density of every
+    /// component (segment for segment-based functionals) `rho{i}`, the total
+    /// density `rho` and the grand potential density `omega`. If `reduced`
+    /// is true, all values are in reduced units (Å, 1/Å³, kB K/Å³), otherwise
+    /// in m, mol/m³ and Pa.
+    pub fn profile_table(&self, reduced: bool) -> FeosResult<(Vec<String>, Array2<f64>)> {
+        let density = &self.profile.density;
+        let z = Length::from_reduced(self.profile.grid.grids()[0].to_owned());
+        let rho = density.sum_axis(Axis_nd(0));
+        let omega = self.profile.grand_potential_density()?;
+        let (z, density, rho, omega) = if reduced {
+            (
+                z.into_reduced(),
+                density.to_reduced(),
+                rho.into_reduced(),
+                omega.into_reduced(),
+            )
+        } else {
+            let molar_density = MOL / METER.powi::<P3>();
+            (
+                z.convert_to(METER),
+                density.convert_to(molar_density),
+                rho.convert_to(molar_density),
+                omega.convert_to(PASCAL),
+            )
+        };
+
+        let n_segments = density.shape()[0];
+        let mut labels = vec![String::from("z")];
+        labels.extend((0..n_segments).map(|i| format!("rho{i}")));
+        labels.extend([String::from("rho"), String::from("omega")]);
+        let mut table = Array2::zeros((z.len(), n_segments + 3));
+        table.column_mut(0).assign(&z);
+        table
+            .slice_mut(s![.., 1..n_segments + 1])
+            .assign(&density.t());
+        table.column_mut(n_segments + 1).assign(&rho);
+        table.column_mut(n_segments + 2).assign(&omega);
+        Ok((labels, table))
+    }
+
+    /// Estimate the surface tension in the limit of infinite grid resolution.
+    ///
+    /// The interface is solved on the current grid and on grids with two and
+    /// four times as many grid points. Each refined profile is initialized
+    /// with the interpolated solution on the coarser grid. Returns the
+    /// Richardson-extrapolated surface tension and the estimated error of the
+    /// surface tension on the finest grid.
+    pub fn richardson_extrapolation(
+        &self,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<(SurfaceTension, SurfaceTension)> {
+        let (_, gamma, error) = self.solve_refined(solver)?;
+        Ok((gamma, error))
+    }
+
+    /// Solve the interface on the current grid and on grids with two and four
+    /// times as many grid points. Returns the interface on the finest grid and
+    /// the Richardson-extrapolated surface tension with its estimated error.
+    fn solve_refined(
+        &self,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<(Self, SurfaceTension, SurfaceTension)> {
+        let mut interface = self.clone();
+        let mut surface_tensions = [0.0; 3];
+        for (k, gamma) in surface_tensions.iter_mut().enumerate() {
+            if k > 0 {
+                interface = interface.refine()?;
+            }
+            interface.solve_inplace(solver, false)?;
+            *gamma = interface.surface_tension.unwrap().to_reduced();
+        }
+        let (gamma, error) = richardson_extrapolation(surface_tensions);
+        Ok((
+            interface,
+            SurfaceTension::from_reduced(gamma),
+            SurfaceTension::from_reduced(error),
+        ))
+    }
+
+    /// Estimate the error of the surface tension due to the incomplete
+    /// convergence of the density profile.
+    ///
+    /// Starting from the current profile, the interface is solved again with
+    /// the tolerance of the last algorithm of `solver` reduced to a tenth of
+    /// the final residual in the solver log. The change of the surface
+    /// tension is used as estimate of the error.
+    pub fn convergence_error(&self, solver: Option<&DFTSolver>) -> FeosResult<SurfaceTension> {
+        let (Some(gamma), Some(log)) = (self.surface_tension, &self.profile.solver_log) else {
+            return Err(FeosError::Error(String::from(
+                "The planar interface has to be solved to estimate the convergence error.",
+            )));
+        };
+        let residual = log.residual().last().copied().unwrap_or_default();
+        let solver = solver
+            .cloned()
+            .unwrap_or_default()
+            .tolerance(TOL_REDUCTION_CONVERGENCE_ERROR * residual);
+        let refined = self.clone().solve(Some(&solver))?;
+        Ok((refined.surface_tension.unwrap() - gamma).abs())
+    }
+
+    /// Surface tension with an estimate of its numerical uncertainty.
+    ///
+    /// The discretization error is estimated with
+    /// [PlanarInterface::richardson_extrapolation] and the error due to the
+    /// incomplete convergence with [PlanarInterface::convergence_error] on the
+    /// finest grid, both with the same `solver`. The two contributions are
+    /// treated as independent and combined in quadrature. Returns the
+    /// extrapolated surface tension and its uncertainty.
+    pub fn surface_tension_with_uncertainty(
+        &self,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<(SurfaceTension, SurfaceTension)> {
+        let (interface, gamma, grid_error) = self.solve_refined(solver)?;
+        let convergence_error = interface.convergence_error(solver)?.to_reduced();
+        let error = grid_error.to_reduced().hypot(convergence_error);
+        Ok((gamma, SurfaceTension::from_reduced(error)))
+    }
+
+    /// Interface on a grid with twice as many grid points that is
+    /// initialized with the interpolated density profile.
+    fn refine(&self) -> FeosResult<Self> {
+        let axis = self.profile.grid.axes()[0];
+        let n_grid = 2 * axis.grid.len();
+        let l_grid = Length::from_reduced(axis.length());
+        let mut interface = match self.profile.grid {
+            Grid::Periodical1(_) => Self::new_periodic(&self.vle, n_grid, l_grid),
+            _ => Self::new(&self.vle, n_grid, l_grid),
+        };
+        // account for a shifted grid
+        let shift = axis.grid[0] - 0.5 * (axis.edges[0] + axis.edges[1]);
+        interface.profile.grid.axes_mut()[0].grid += shift;
+        interface.profile.specification = self.profile.specification.clone();
+        let density =
+            axis.interpolate_density(&self.profile.density, interface.profile.grid.grids()[0]);
+        interface.set_density(&density, false)
+    }
+
+    /// Fit a hyperbolic tangent to the total segment density profile.
+    ///
+    /// The profile $\rho(z)=\frac{1}{2}(\rho^L+\rho^V)+\frac{1}{2}(\rho^L-\rho^V)\tanh\left(\pm\frac{z-z_0}{w}\right)$
+    /// is fitted with the bulk densities taken from the profile. The sign is
+    /// chosen such that the width $w$ is positive. Returns the width $w$ and
+    /// the position $z_0$ of the interface.
+    pub fn fit_tanh_width(&self) -> FeosResult<(Length, Length)> {
+        let v = self.vapor_index();
+        let rho = self.mass_weighted_total_density().into_reduced();
+        let rho = rho.slice(s![..=v]);
+        let z = self.profile.grid.grids()[0].slice(s![..=v]);
+        let (rho_l, rho_v) = (rho[0], rho[v]);
+        let mean = 0.5 * (rho_l + rho_v);
+        let amplitude = 0.5 * (rho_v - rho_l);
+
+        // initial values from the equimolar surface and the curvature of the profile
+        let x = rho.mapv(|r| (r - rho_v) / (rho_l - rho_v));
+        let dz = &self.profile.grid.axes()[0].edges.slice(s![1..=v + 1])
+            - &self.profile.grid.axes()[0].edges.slice(s![..=v]);
+        let mut z0 = self.profile.grid.axes()[0].edges[0] + (&x * &dz).sum();
+        let mut w = 2.0 * (&x * &x.mapv(|x| 1.0 - x) * &dz).sum();
+
+        // Levenberg-Marquardt iteration
+        let sum_of_squares = |z0: f64, w: f64| {
+            rho.iter()
+                .zip(z.iter())
+                .map(|(r, z)| (r - mean - amplitude * ((z - z0) / w).tanh()).powi(2))
+                .sum::<f64>()
+        };
+        let mut lambda = 1e-3;
+        let mut s0 = sum_of_squares(z0, w);
+        for _ in 0..MAX_ITER_TANH_FIT {
+            let (mut jtj, mut jtr) = ([[0.0; 2]; 2], [0.0; 2]);
+            for (r, z) in rho.iter().zip(z.iter()) {
+                let t = ((z - z0) / w).tanh();
+                let res = r - mean - amplitude * t;
+                let dt = amplitude * (1.0 - t * t) / w;
+                let jac = [-dt, -dt * (z - z0) / w];
+                for i in 0..2 {
+                    jtr[i] += jac[i] * res;
+                    for j in 0..2 {
+                        jtj[i][j] += jac[i] * jac[j];
+                    }
+                }
+            }
+            let a = [
+                [jtj[0][0] * (1.0 + lambda), jtj[0][1]],
+                [jtj[1][0], jtj[1][1] * (1.0 + lambda)],
+            ];
+            let det = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+            let dz0 = (a[1][1] * jtr[0] - a[0][1] * jtr[1]) / det;
+            let dw = (a[0][0] * jtr[1] - a[1][0] * jtr[0]) / det;
+            if dz0.abs() < TOL_TANH_FIT * w.abs() && dw.abs() < TOL_TANH_FIT * w.abs() {
+                return Ok((Length::from_reduced(w.abs()), Length::from_reduced(z0)));
+            }
+            let s1 = sum_of_squares(z0 - dz0, w - dw);
+            if s1 < s0 {
+                z0 -= dz0;
+                w -= dw;
+                s0 = s1;
+                lambda *= 0.1;
+            } else {
+                lambda *= 10.0;
+            }
+        }
+        Err(FeosError::NotConverged("fit_tanh_width".into()))
+    }
+
+    /// Density profile mirrored at the vapor boundary of the domain.
+    ///
+    /// The result contains twice as many grid points and can be used to
+    /// initialize a liquid slab on a periodic domain with twice the width,
+    /// e.g., with [PlanarInterface::new_periodic] and
+    /// [PlanarInterface::set_density_inplace]. Because the grid points are
+    /// located at the centers of the grid cells, no grid point lies on the
+    /// mirror plane and no density is duplicated.
+    pub fn mirrored_density(&self) -> FeosResult<Density<Array2<f64>>> {
+        if !matches!(self.profile.grid, Grid::Cartesian1(_)) {
+            return Err(FeosError::Error(String::from(
+                "Only profiles on non-periodic Cartesian grids can be mirrored.",
+            )));
+        }
+        let density = self.profile.density.to_reduced();
+        let n = density.ncols();
+        Ok(Density::from_reduced(Array2::from_shape_fn(
+            (density.nrows(), 2 * n),
+            |(i, j)| density[(i, j.min(2 * n - 1 - j))],
+        )))
+    }
+
+    fn check_density_shape(&self, init: &Density<Array2<f64>>) -> FeosResult<()> {
+        let expected = self.profile.density.shape();
+        if expected != init.shape() {
+            return Err(FeosError::Error(format!(
+                "The density profile has to be of shape {:?} (segments x grid points) with units of density, got shape {:?}.",
+                expected,
+                init.shape()
+            )));
+        }
+        Ok(())
+    }
+
     fn set_density_scale(&mut self, init: &Density<Array2<f64>>) {
-        assert_eq!(self.profile.density.shape(), init.shape());
-        let n_grid = self.profile.density.shape()[1];
-        let drho_init = &init.index_axis(Axis_nd(1), 0) - &init.index_axis(Axis_nd(1), n_grid - 1);
-        let rho_init_0 = init.index_axis(Axis_nd(1), n_grid - 1);
+        let v = self.vapor_index();
+        let drho_init = &init.index_axis(Axis_nd(1), 0) - &init.index_axis(Axis_nd(1), v);
+        let rho_init_0 = init.index_axis(Axis_nd(1), v);
         let drho = &self.profile.density.index_axis(Axis_nd(1), 0)
-            - &self.profile.density.index_axis(Axis_nd(1), n_grid - 1);
-        let rho_0 = self.profile.density.index_axis(Axis_nd(1), n_grid - 1);
+            - &self.profile.density.index_axis(Axis_nd(1), v);
+        let rho_0 = self.profile.density.index_axis(Axis_nd(1), v);
 
         self.profile.density = Density::from_shape_fn(self.profile.density.raw_dim(), |(i, j)| {
             ((init.get((i, j)) - rho_init_0.get(i)) / drho_init.get(i)).into_value() * drho.get(i)
@@ -319,18 +1603,238 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         });
     }
 
-    pub fn set_density_inplace(&mut self, init: &Density<Array2<f64>>, scale: bool) {
+    pub fn set_density_inplace(
+        &mut self,
+        init: &Density<Array2<f64>>,
+        scale: bool,
+    ) -> FeosResult<()> {
+        self.check_density_shape(init)?;
         if scale {
             self.set_density_scale(init)
         } else {
-            assert_eq!(self.profile.density.shape(), init.shape());
             self.profile.density = init.clone();
         }
+        Ok(())
     }
 
-    pub fn set_density(mut self, init: &Density<Array2<f64>>, scale: bool) -> Self {
-        self.set_density_inplace(init, scale);
-        self
+    pub fn set_density(mut self, init: &Density<Array2<f64>>, scale: bool) -> FeosResult<Self> {
+        self.set_density_inplace(init, scale)?;
+        Ok(self)
+    }
+
+    /// Solve the interface for a different functional, e.g., with slightly
+    /// perturbed parameters, starting from the current density profile.
+    ///
+    /// The coexisting phases are recalculated with `dft` at the same
+    /// temperature (and for mixtures at the same liquid composition) and the
+    /// convolver is set up with the weight functions of the new functional.
+    /// The current density profile is scaled from the old to the new bulk
+    /// densities and used as initial guess. The grid, the external potential
+    /// and the specification are kept. Returns an error if the solution
+    /// collapses to a homogeneous phase.
+    pub fn resolve_with(&self, dft: &F, solver: Option<&DFTSolver>) -> FeosResult<Self> {
+        let (vapor, liquid) = (self.vle.vapor(), self.vle.liquid());
+        let vle = if dft.components() == 1 {
+            PhaseEquilibrium::pure(dft, vapor.temperature, Some(&self.vle), Default::default())?
+        } else {
+            PhaseEquilibrium::bubble_point(
+                dft,
+                vapor.temperature,
+                &liquid.molefracs,
+                Some(vapor.pressure(Contributions::Total)),
+                Some(&vapor.molefracs),
+                Default::default(),
+            )?
+        };
+
+        // scale the density profile to the new bulk densities
+        let indices = dft.component_index();
+        let density = Density::from_shape_fn(self.profile.density.raw_dim(), |(i, z)| {
+            let rho_v = vapor.partial_density.get(indices[i]);
+            let rho_l = liquid.partial_density.get(indices[i]);
+            let rho_v_new = vle.vapor().partial_density.get(indices[i]);
+            let rho_l_new = vle.liquid().partial_density.get(indices[i]);
+            ((self.profile.density.get((i, z)) - rho_v) / (rho_l - rho_v)).into_value()
+                * (rho_l_new - rho_v_new)
+                + rho_v_new
+        });
+
+        let mut profile = DFTProfile::new(
+            self.profile.grid.clone(),
+            vle.vapor(),
+            Some(self.profile.external_potential.clone()),
+            Some(&density),
+            self.profile.lanczos,
+        );
+        profile.specification = self.profile.specification.clone();
+        let interface = Self {
+            profile,
+            vle,
+            surface_tension: None,
+            equimolar_radius: None,
+        }
+        .solve(solver)?;
+
+        // compare the density difference between the boundaries of the profiles
+        let v = self.vapor_index();
+        let rho = self.mass_weighted_total_density();
+        let rho_new = interface.mass_weighted_total_density();
+        let contrast = ((rho_new.get(0) - rho_new.get(v)) / (rho.get(0) - rho.get(v))).into_value();
+        if contrast < MIN_DENSITY_CONTRAST {
+            return Err(FeosError::Error(String::from(
+                "The density profile collapsed to a homogeneous phase. Use a smaller perturbation of the functional.",
+            )));
+        }
+        Ok(interface)
+    }
+
+    /// Sensitivities $\frac{\partial\gamma}{\partial p_k}$ of the surface
+    /// tension to parameters $p_k$ of the functional, e.g., as gradient for a
+    /// parameter fit to interfacial data.
+    ///
+    /// Every element of `perturbations` returns the functional with the
+    /// respective parameter shifted by the given value. The derivatives are
+    /// approximated by central differences with the shifts $\pm$`step`, where
+    /// both perturbed interfaces are solved with
+    /// [PlanarInterface::resolve_with] starting from the current (converged)
+    /// density profile. The result is given per unit of the parameters as
+    /// they are shifted by `perturbations`.
+    pub fn surface_tension_sensitivity<P: Fn(f64) -> FeosResult<F>>(
+        &self,
+        perturbations: &[P],
+        step: f64,
+        solver: Option<&DFTSolver>,
+    ) -> FeosResult<SurfaceTension<Array1<f64>>> {
+        let gamma = |delta: f64, perturbation: &P| -> FeosResult<f64> {
+            let interface = self.resolve_with(&perturbation(delta)?, solver)?;
+            Ok(interface.surface_tension.unwrap().to_reduced())
+        };
+        let sensitivity = perturbations
+            .iter()
+            .map(|p| Ok((gamma(step, p)? - gamma(-step, p)?) / (2.0 * step)))
+            .collect::<FeosResult<Array1<f64>>>()?;
+        Ok(SurfaceTension::from_reduced(sensitivity))
+    }
+
+    /// Set an arbitrary external potential acting on each segment in units
+    /// of $k_\mathrm{B}T$, e.g., to model the coupling to an electric or
+    /// magnetic field.
+    ///
+    /// The potential has to be of the same shape as the density profile
+    /// (segments x grid points). It should vanish at the boundaries of the
+    /// domain, so that the bulk phases of the interface remain the coexisting
+    /// phases in `vle`. Previous results of the solved interface are reset.
+    pub fn set_external_potential_inplace(
+        &mut self,
+        external_potential: Array2<f64>,
+    ) -> FeosResult<()> {
+        let expected = self.profile.density.shape();
+        if expected != external_potential.shape() {
+            return Err(FeosError::Error(format!(
+                "The external potential has to be of shape {:?} (segments x grid points), got shape {:?}.",
+                expected,
+                external_potential.shape()
+            )));
+        }
+        self.profile.external_potential = external_potential;
+        self.surface_tension = None;
+        self.equimolar_radius = None;
+        Ok(())
+    }
+
+    pub fn set_external_potential(mut self, external_potential: Array2<f64>) -> FeosResult<Self> {
+        self.set_external_potential_inplace(external_potential)?;
+        Ok(self)
+    }
+
+    /// Fix the chemical potentials of the given components and the total
+    /// number of moles of all other components.
+    ///
+    /// This is the natural specification for gases dissolved in a liquid at
+    /// fixed partial pressures, e.g., CO$_2$ in water: the chemical potentials
+    /// of the gases are fixed by the coexisting phases in `vle`, while the
+    /// amount of solvent, and thereby the position of the interface, is taken
+    /// from the current density profile. Call this after initializing the
+    /// density profile. Previous results of the solved interface are reset.
+    pub fn fix_chemical_potentials_inplace(&mut self, components: &[usize]) -> FeosResult<()> {
+        let n = self.profile.bulk.eos.components();
+        if let Some(&i) = components.iter().find(|&&i| i >= n) {
+            return Err(FeosError::Error(format!(
+                "Component index {i} is out of range for a system with {n} components."
+            )));
+        }
+        let open = self
+            .profile
+            .bulk
+            .eos
+            .component_index()
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| components.contains(c))
+            .map(|(s, _)| s)
+            .collect::<Vec<_>>();
+        self.profile.specification = if open.len() == self.profile.density.shape()[0] {
+            Arc::new(DFTSpecifications::ChemicalPotential)
+        } else {
+            Arc::new(DFTSpecifications::semigrand_from_profile(
+                &self.profile,
+                open,
+            ))
+        };
+        self.surface_tension = None;
+        self.equimolar_radius = None;
+        Ok(())
+    }
+
+    pub fn fix_chemical_potentials(mut self, components: &[usize]) -> FeosResult<Self> {
+        self.fix_chemical_potentials_inplace(components)?;
+        Ok(self)
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + Molarweight> PlanarInterface<F> {
+    /// Bond number $\mathrm{Bo}=\frac{\Delta\rho^{(m)}gL^2}{\gamma}$ of the
+    /// interface for a characteristic length $L$ and standard gravity.
+    ///
+    /// The Bond number compares gravitational and capillary forces on the
+    /// length scale $L$, e.g., the size of a droplet or the diameter of a
+    /// capillary. For $\mathrm{Bo}\ll 1$ the shape of the interface is
+    /// governed by the surface tension and gravity can be neglected.
+    pub fn bond_number(&self, length: Length) -> FeosResult<f64> {
+        let Some(gamma) = self.surface_tension else {
+            return Err(FeosError::Error(String::from(
+                "The planar interface has to be solved to calculate the Bond number.",
+            )));
+        };
+        let delta_rho = (self.vle.liquid().mass_density() - self.vle.vapor().mass_density()).abs();
+        let g = STANDARD_GRAVITY * METER / SECOND.powi::<P2>();
+        Ok((delta_rho * g * length * length / gamma).into_value())
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> PlanarInterface<F> {
+    /// Surface tension $\gamma^*=\frac{\gamma\sigma^2}{\varepsilon}$ reduced
+    /// with the characteristic energy $\varepsilon$ and size $\sigma$ of the
+    /// fluid.
+    ///
+    /// The parameters are taken from [FluidParameters], so that surface
+    /// tensions of different fluids can be compared on a corresponding-states
+    /// basis. Only available for fluids that consist of a single segment, e.g.,
+    /// pure components in PC-SAFT.
+    pub fn surface_tension_reduced_cs(&self) -> FeosResult<f64> {
+        let Some(gamma) = self.surface_tension else {
+            return Err(FeosError::Error(String::from(
+                "The planar interface has to be solved to calculate the reduced surface tension.",
+            )));
+        };
+        let eos = &self.profile.bulk.eos;
+        let (sigma, epsilon_k) = (eos.sigma_ff(), eos.epsilon_k_ff());
+        if sigma.len() != 1 {
+            return Err(FeosError::Error(String::from(
+                "The reduced surface tension is only available for fluids with a single segment.",
+            )));
+        }
+        Ok(gamma.to_reduced() * sigma[0].powi(2) / epsilon_k[0])
     }
 }
 
@@ -1,17 +1,72 @@
 use super::PlanarInterface;
 use crate::functional::HelmholtzEnergyFunctional;
 use crate::solver::DFTSolver;
-use feos_core::{PhaseEquilibrium, ReferenceSystem, StateVec};
+use feos_core::{FeosError, FeosResult, PhaseEquilibrium, ReferenceSystem, StateVec};
 use ndarray::{Array1, Array2};
-use quantity::{Length, Moles, SurfaceTension, Temperature};
+use quantity::{Area, Density, Length, Moles, Pressure, RGAS, SurfaceTension, Temperature};
+use std::ops::ControlFlow;
 
 const DEFAULT_GRID_POINTS: usize = 2048;
 
+/// Minimum number of points for the fit of the critical scaling of the surface
+/// tension, i.e., one more than the number of parameters.
+const MIN_POINTS_CRITICAL_SCALING: usize = 4;
+const MAX_ITER_CRITICAL_SCALING: usize = 100;
+const TOL_CRITICAL_SCALING: f64 = 1e-3;
+
 /// Container structure for the efficient calculation of surface tension diagrams.
 pub struct SurfaceTensionDiagram<F: HelmholtzEnergyFunctional> {
     pub profiles: Vec<PlanarInterface<F>>,
 }
 
+/// Converged density profiles of planar interfaces indexed by their reduced
+/// temperature.
+///
+/// The cache provides initial guesses for the calculation of a
+/// [SurfaceTensionDiagram], e.g., if the same diagram is recalculated with
+/// slightly perturbed parameters in a parameter fit. For every phase
+/// equilibrium, the cached profile with the closest temperature is scaled to
+/// the new bulk densities.
+#[derive(Clone, Default)]
+pub struct SurfaceTensionCache {
+    profiles: Vec<(f64, Density<Array2<f64>>)>,
+}
+
+impl SurfaceTensionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a density profile to the cache, replacing any profile at the same
+    /// temperature.
+    pub fn insert(&mut self, temperature: Temperature, density: Density<Array2<f64>>) {
+        let t = temperature.to_reduced();
+        match self.profiles.binary_search_by(|(t0, _)| t0.total_cmp(&t)) {
+            Ok(i) => self.profiles[i].1 = density,
+            Err(i) => self.profiles.insert(i, (t, density)),
+        }
+    }
+
+    /// The cached density profile with the given shape that is closest to
+    /// `temperature`.
+    pub fn get(&self, temperature: Temperature, shape: &[usize]) -> Option<&Density<Array2<f64>>> {
+        let t = temperature.to_reduced();
+        self.profiles
+            .iter()
+            .filter(|(_, density)| density.shape() == shape)
+            .min_by(|(t1, _), (t2, _)| (t1 - t).abs().total_cmp(&(t2 - t).abs()))
+            .map(|(_, density)| density)
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+}
+
 // #[expect(clippy::ptr_arg)]
 impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
     pub fn new(
@@ -22,10 +77,75 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
         critical_temperature: Option<Temperature>,
         fix_equimolar_surface: Option<bool>,
         solver: Option<&DFTSolver>,
+    ) -> Self {
+        Self::new_with_cache(
+            dia,
+            &SurfaceTensionCache::new(),
+            init_densities,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface,
+            solver,
+        )
+    }
+
+    /// Calculate a surface tension diagram using the density profiles in
+    /// `cache` as initial guesses.
+    ///
+    /// On a cache miss, i.e., if the cache does not contain a profile with
+    /// the right number of grid points, the profile is initialized as in
+    /// [SurfaceTensionDiagram::new].
+    #[expect(clippy::too_many_arguments)]
+    pub fn new_with_cache(
+        dia: &[PhaseEquilibrium<F, 2>],
+        cache: &SurfaceTensionCache,
+        init_densities: Option<bool>,
+        n_grid: Option<usize>,
+        l_grid: Option<Length>,
+        critical_temperature: Option<Temperature>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<&DFTSolver>,
+    ) -> Self {
+        Self::new_with_progress(
+            dia,
+            cache,
+            init_densities,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface,
+            solver,
+            |_, _, _| ControlFlow::Continue(()),
+        )
+    }
+
+    /// Calculate a surface tension diagram and report the progress after
+    /// every phase equilibrium.
+    ///
+    /// The callback `progress` is called with the index of the phase
+    /// equilibrium in `dia`, its temperature and the surface tension, which is
+    /// `None` if the interface could not be solved. If the callback returns
+    /// [ControlFlow::Break], the calculation is stopped and the diagram
+    /// contains only the interfaces solved so far. Otherwise, the diagram is
+    /// identical to [SurfaceTensionDiagram::new_with_cache].
+    #[expect(clippy::too_many_arguments)]
+    pub fn new_with_progress(
+        dia: &[PhaseEquilibrium<F, 2>],
+        cache: &SurfaceTensionCache,
+        init_densities: Option<bool>,
+        n_grid: Option<usize>,
+        l_grid: Option<Length>,
+        critical_temperature: Option<Temperature>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<&DFTSolver>,
+        mut progress: impl FnMut(usize, Temperature, Option<SurfaceTension>) -> ControlFlow<()>,
     ) -> Self {
         let n_grid = n_grid.unwrap_or(DEFAULT_GRID_POINTS);
         let mut profiles: Vec<PlanarInterface<F>> = Vec::with_capacity(dia.len());
-        for vle in dia.iter() {
+        for (i, vle) in dia.iter().enumerate() {
+            let segments = vle.vapor().eos.component_index().len();
+            let cached = cache.get(vle.vapor().temperature, &[segments, n_grid]);
             // check for a critical point
             let profile = if PhaseEquilibrium::is_trivial_solution(vle.vapor(), vle.liquid()) {
                 Ok(PlanarInterface::from_tanh(
@@ -35,9 +155,19 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
                     Temperature::from_reduced(500.0),
                     fix_equimolar_surface.unwrap_or(false),
                 ))
+            } else if let Some(density) = cached {
+                // initialize with the closest profile from the cache
+                PlanarInterface::from_tanh(
+                    vle,
+                    n_grid,
+                    l_grid.unwrap_or(Length::from_reduced(100.0)),
+                    critical_temperature.unwrap_or(Temperature::from_reduced(500.0)),
+                    fix_equimolar_surface.unwrap_or(false),
+                )
+                .set_density(density, true)
             } else {
                 // initialize with pDGT for single segments and tanh for mixtures and segment DFT
-                if vle.vapor().eos.component_index().len() == 1 {
+                if segments == 1 {
                     PlanarInterface::from_pdgt(vle, n_grid, false)
                 } else {
                     Ok(PlanarInterface::from_tanh(
@@ -48,24 +178,65 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
                         fix_equimolar_surface.unwrap_or(false),
                     ))
                 }
-                .map(|mut profile| {
+                .and_then(|mut profile| {
                     if let Some(init) = profiles.last()
                         && init.profile.density.shape() == profile.profile.density.shape()
                         && let Some(scale) = init_densities
                     {
-                        profile.set_density_inplace(&init.profile.density, scale)
+                        profile.set_density_inplace(&init.profile.density, scale)?;
                     }
-                    profile
+                    Ok(profile)
                 })
             }
             .and_then(|profile| profile.solve(solver));
+            let surface_tension = profile.as_ref().ok().and_then(|p| p.surface_tension);
             if let Ok(profile) = profile {
                 profiles.push(profile);
             }
+            if progress(i, vle.vapor().temperature, surface_tension).is_break() {
+                break;
+            }
         }
         Self { profiles }
     }
 
+    /// Calculate surface tensions of a pure fluid along an isobar.
+    ///
+    /// The phase equilibria are calculated for the given pressures in the
+    /// given order. The calculation stops at the first pressure for which no
+    /// phase equilibrium is found, i.e., once the critical pressure is exceeded,
+    /// and only the points up to that limit are returned.
+    #[expect(clippy::too_many_arguments)]
+    pub fn isobar(
+        func: &F,
+        pressures: &Pressure<Array1<f64>>,
+        init_densities: Option<bool>,
+        n_grid: Option<usize>,
+        l_grid: Option<Length>,
+        critical_temperature: Option<Temperature>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<&DFTSolver>,
+    ) -> Self {
+        let mut dia: Vec<PhaseEquilibrium<F, 2>> = Vec::with_capacity(pressures.len());
+        for p in pressures.into_iter() {
+            match PhaseEquilibrium::pure(func, p, dia.last(), Default::default()) {
+                Ok(vle) if !PhaseEquilibrium::is_trivial_solution(vle.vapor(), vle.liquid()) => {
+                    dia.push(vle)
+                }
+                _ => break,
+            }
+        }
+        Self::new(
+            &dia,
+            init_densities,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface,
+            solver,
+        )
+    }
+
     pub fn vapor(&self) -> StateVec<'_, F> {
         self.profiles.iter().map(|p| p.vle.vapor()).collect()
     }
@@ -80,6 +251,20 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
         })
     }
 
+    /// Cache of the converged density profiles that can be used to
+    /// initialize subsequent calculations, see
+    /// [SurfaceTensionDiagram::new_with_cache].
+    pub fn cache(&self) -> SurfaceTensionCache {
+        let mut cache = SurfaceTensionCache::new();
+        for profile in &self.profiles {
+            cache.insert(
+                profile.vle.vapor().temperature,
+                profile.profile.density.clone(),
+            );
+        }
+        cache
+    }
+
     pub fn relative_adsorption(&self) -> Vec<Moles<Array2<f64>>> {
         self.profiles
             .iter()
@@ -87,6 +272,384 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
             .collect()
     }
 
+    /// Contributions of the individual components to the change of the
+    /// surface tension between consecutive points of an isothermal diagram
+    /// according to the Gibbs adsorption isotherm
+    /// $\mathrm{d}\gamma=-\sum_i\Gamma_i^{(j)}\mathrm{d}\mu_i$.
+    ///
+    /// The relative adsorptions $\Gamma_i^{(j)}$ with respect to the
+    /// `reference` component $j$ are averaged between consecutive points and
+    /// the chemical potentials are differenced. The result has the shape
+    /// `(profiles - 1, components)` and its rows sum up approximately to the
+    /// differences of the surface tensions.
+    pub fn gibbs_adsorption_contributions(
+        &self,
+        reference: usize,
+    ) -> FeosResult<SurfaceTension<Array2<f64>>> {
+        let Some(first) = self.profiles.first() else {
+            return Ok(SurfaceTension::zeros((0, 0)));
+        };
+        let eos = &first.vle.vapor().eos;
+        let n = eos.components();
+        if eos.component_index().len() != n {
+            return Err(FeosError::Error(
+                "The Gibbs adsorption isotherm is only available for one segment per component."
+                    .into(),
+            ));
+        }
+        let temperature = first.vle.vapor().temperature;
+        if self
+            .profiles
+            .iter()
+            .any(|p| p.vle.vapor().temperature != temperature)
+        {
+            return Err(FeosError::Error(
+                "The Gibbs adsorption isotherm requires an isothermal diagram.".into(),
+            ));
+        }
+
+        let rt = RGAS * temperature;
+        let adsorption = self.relative_adsorption();
+        let mut contributions = SurfaceTension::zeros((self.profiles.len() - 1, n));
+        for (k, p) in self.profiles.windows(2).enumerate() {
+            let (v0, v1) = (p[0].vle.vapor(), p[1].vle.vapor());
+            let (mu0, mu1) = (
+                v0.residual_chemical_potential(),
+                v1.residual_chemical_potential(),
+            );
+            for i in 0..n {
+                let dmu = mu1.get(i) - mu0.get(i)
+                    + rt * (v1.partial_density.get(i) / v0.partial_density.get(i))
+                        .into_value()
+                        .ln();
+                let gamma = 0.5
+                    * (adsorption[k].get((i, reference)) + adsorption[k + 1].get((i, reference)))
+                    / Area::from_reduced(1.0);
+                contributions.set((k, i), -gamma * dmu);
+            }
+        }
+        Ok(contributions)
+    }
+
+    /// Residual $\Delta\gamma+\sum_i\Gamma_i^{(j)}\Delta\mu_i$ of the Gibbs
+    /// adsorption isotherm between consecutive points of an isothermal
+    /// diagram.
+    ///
+    /// The residual is the difference between the change of the surface
+    /// tension and the sum of the
+    /// [SurfaceTensionDiagram::gibbs_adsorption_contributions]. Because the
+    /// relative adsorptions are averaged between consecutive points, the
+    /// residual of consistent profiles decreases quadratically with the step
+    /// size along the path. Residuals that are large compared to the changes
+    /// of the surface tension indicate under-resolved or unconverged profiles.
+    /// Returns an error if any interface of the diagram is not solved.
+    pub fn gibbs_adsorption_residual(
+        &self,
+        reference: usize,
+    ) -> FeosResult<SurfaceTension<Array1<f64>>> {
+        let gamma = self
+            .profiles
+            .iter()
+            .map(|p| p.surface_tension)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                FeosError::Error("All interfaces of the diagram have to be solved.".into())
+            })?;
+        let contributions = self.gibbs_adsorption_contributions(reference)?;
+        Ok(SurfaceTension::from_shape_fn(
+            self.profiles.len().saturating_sub(1),
+            |k| {
+                let dgamma = gamma[k + 1] - gamma[k];
+                (0..contributions.shape()[1]).fold(dgamma, |res, i| res - contributions.get((k, i)))
+            },
+        ))
+    }
+
+    /// Gibbs elasticity $E=\frac{\mathrm{d}\gamma}{\mathrm{d}\ln A}=-\frac{\mathrm{d}\gamma}{\mathrm{d}\ln\Gamma_i^{(j)}}$
+    /// at every point of the diagram.
+    ///
+    /// The surface concentration is the relative adsorption $\Gamma_i^{(j)}$
+    /// of `component` $i$ with respect to the `reference` component $j$, e.g.,
+    /// of a surfactant with respect to the solvent. At a constant amount of
+    /// adsorbed molecules, the surface concentration is inversely proportional
+    /// to the interfacial area $A$. The derivative is approximated by central
+    /// differences (second order on non-uniform grids) at the inner points and
+    /// by one-sided differences at the first and the last point of the diagram.
+    /// The surface concentrations have to be positive and strictly monotonic
+    /// along the diagram.
+    pub fn gibbs_elasticity(
+        &self,
+        component: usize,
+        reference: usize,
+    ) -> FeosResult<SurfaceTension<Array1<f64>>> {
+        if self.profiles.len() < 2 {
+            return Err(FeosError::Error(
+                "At least two points are required to calculate the Gibbs elasticity.".into(),
+            ));
+        }
+        let n = self.profiles[0].vle.liquid().eos.components();
+        if component >= n || reference >= n {
+            return Err(FeosError::Error(format!(
+                "Component indices {component} and {reference} have to be smaller than {n}."
+            )));
+        }
+        if component == reference {
+            return Err(FeosError::Error(
+                "The relative adsorption of the reference component vanishes.".into(),
+            ));
+        }
+        let adsorption = self.relative_adsorption();
+        if adsorption
+            .iter()
+            .any(|a| a.get((component, reference)).to_reduced() <= 0.0)
+        {
+            return Err(FeosError::Error(
+                "The surface concentrations have to be positive.".into(),
+            ));
+        }
+        let x: Vec<_> = adsorption
+            .iter()
+            .map(|a| a.get((component, reference)).to_reduced().ln())
+            .collect();
+        let dx: Vec<_> = x.windows(2).map(|x| x[1] - x[0]).collect();
+        if !(dx.iter().all(|&dx| dx > 0.0) || dx.iter().all(|&dx| dx < 0.0)) {
+            return Err(FeosError::Error(
+                "The surface concentrations have to change strictly monotonically along the diagram."
+                    .into(),
+            ));
+        }
+        let gamma: Vec<_> = self
+            .profiles
+            .iter()
+            .map(|p| p.surface_tension.unwrap().to_reduced())
+            .collect();
+
+        let k_max = gamma.len() - 1;
+        Ok(SurfaceTension::from_reduced(Array1::from_shape_fn(
+            gamma.len(),
+            |k| {
+                -if k == 0 {
+                    (gamma[1] - gamma[0]) / dx[0]
+                } else if k == k_max {
+                    (gamma[k] - gamma[k - 1]) / dx[k - 1]
+                } else {
+                    let (h1, h2) = (dx[k - 1], dx[k]);
+                    (h1 * h1 * gamma[k + 1] - h2 * h2 * gamma[k - 1]
+                        + (h2 * h2 - h1 * h1) * gamma[k])
+                        / (h1 * h2 * (h1 + h2))
+                }
+            },
+        )))
+    }
+
+    /// Estimate the concentration at which the surface tension saturates with
+    /// increasing liquid concentration of `component`, e.g., the critical
+    /// micelle concentration (CMC) of surfactants.
+    ///
+    /// The diagram has to be ordered by increasing concentrations, e.g., by
+    /// constructing it from bubble points at a constant temperature. The slopes
+    /// $\mathrm{d}\gamma/\mathrm{d}\ln c$ are calculated between consecutive
+    /// points. The break is the first point after the steepest segment from
+    /// which on the magnitude of the slope drops below `tol` times the magnitude
+    /// of the steepest slope. Returns `None` if no such break is found, i.e.,
+    /// if the surface tension does not level off within the diagram.
+    pub fn saturation_concentration(
+        &self,
+        component: usize,
+        tol: f64,
+    ) -> FeosResult<Option<Density>> {
+        if self.profiles.len() < 3 {
+            return Err(FeosError::Error(
+                "At least three points are required to detect a break in the surface tension."
+                    .into(),
+            ));
+        }
+        let n = self.profiles[0].vle.liquid().eos.components();
+        if component >= n {
+            return Err(FeosError::Error(format!(
+                "Component index {component} is out of range for {n} components."
+            )));
+        }
+        let c: Vec<_> = self
+            .profiles
+            .iter()
+            .map(|p| p.vle.liquid().partial_density.get(component))
+            .collect();
+        if c.windows(2).any(|c| c[1] <= c[0]) {
+            return Err(FeosError::Error(
+                "The concentrations have to increase strictly along the diagram.".into(),
+            ));
+        }
+
+        let slopes: Vec<f64> = self
+            .profiles
+            .windows(2)
+            .zip(c.windows(2))
+            .map(|(p, c)| {
+                let dgamma = p[1].surface_tension.unwrap() - p[0].surface_tension.unwrap();
+                dgamma.to_reduced() / (c[1] / c[0]).into_value().ln()
+            })
+            .collect();
+        let (steepest, max_slope) = slopes
+            .iter()
+            .map(|s| s.abs())
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        Ok(slopes
+            .iter()
+            .enumerate()
+            .skip(steepest + 1)
+            .find(|(_, s)| s.abs() < tol * max_slope)
+            .map(|(k, _)| c[k]))
+    }
+
+    /// Fit the surface tensions close to the critical point to the power law
+    /// $\gamma=\gamma_0\left(1-\frac{T}{T_\mathrm{c}}\right)^\mu$.
+    ///
+    /// Only points with $0<1-\frac{T}{T_\mathrm{c}^\mathrm{est}}\leq$ `window`
+    /// are used, where $T_\mathrm{c}^\mathrm{est}$ is the estimate
+    /// `critical_temperature`, e.g., the critical temperature of the bulk
+    /// equation of state. For a given $T_\mathrm{c}$, the amplitude and the
+    /// exponent follow from a linear least-squares fit of $\ln\gamma$ over
+    /// $\ln\left(1-\frac{T}{T_\mathrm{c}}\right)$. The critical temperature is
+    /// varied between the highest temperature within the window and
+    /// $(1+\text{window})T_\mathrm{c}^\mathrm{est}$ to minimize the residual.
+    /// As a mean-field theory, DFT approaches the exponent $\mu=\frac{3}{2}$
+    /// in the limit $T\to T_\mathrm{c}$. Further away from the critical
+    /// point, corrections to the power law lower the fitted exponent, so the
+    /// window should only cover a few percent of the critical temperature.
+    ///
+    /// Returns the exponent and the fitted critical temperature. Returns an
+    /// error if fewer than four points lie within the window or if the fitted
+    /// critical temperature lies at the boundary of the search interval.
+    pub fn critical_scaling(
+        &self,
+        critical_temperature: Temperature,
+        window: f64,
+    ) -> FeosResult<(f64, Temperature)> {
+        let tc_est = critical_temperature.to_reduced();
+        let (t, ln_gamma): (Vec<_>, Vec<_>) = self
+            .profiles
+            .iter()
+            .filter_map(|p| {
+                let t = p.vle.vapor().temperature.to_reduced();
+                let gamma = p.surface_tension?.to_reduced();
+                let tau = 1.0 - t / tc_est;
+                (tau > 0.0 && tau <= window && gamma > 0.0).then_some((t, gamma.ln()))
+            })
+            .unzip();
+        if t.len() < MIN_POINTS_CRITICAL_SCALING {
+            return Err(FeosError::Error(format!(
+                "Only {} points are within the window of the critical point, at least {MIN_POINTS_CRITICAL_SCALING} are required.",
+                t.len()
+            )));
+        }
+
+        // linear least-squares fit for a given critical temperature
+        let n = t.len() as f64;
+        let fit = |tc: f64| {
+            let x: Vec<_> = t.iter().map(|&t| (1.0 - t / tc).ln()).collect();
+            let x_mean = x.iter().sum::<f64>() / n;
+            let y_mean = ln_gamma.iter().sum::<f64>() / n;
+            let sxx: f64 = x.iter().map(|x| (x - x_mean).powi(2)).sum();
+            let sxy: f64 = x
+                .iter()
+                .zip(&ln_gamma)
+                .map(|(x, y)| (x - x_mean) * (y - y_mean))
+                .sum();
+            let mu = sxy / sxx;
+            let residual: f64 = x
+                .iter()
+                .zip(&ln_gamma)
+                .map(|(x, y)| (y - y_mean - mu * (x - x_mean)).powi(2))
+                .sum();
+            (residual, mu)
+        };
+
+        // golden-section search for the logarithm of the distance between the
+        // critical temperature and the highest temperature
+        let t_max = t.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let tc = |s: f64| t_max + s.exp();
+        let (s_min, s_max) = ((1e-8 * t_max).ln(), ((1.0 + window) * tc_est - t_max).ln());
+        let ratio = 0.5 * (5f64.sqrt() - 1.0);
+        let (mut a, mut b) = (s_min, s_max);
+        for _ in 0..MAX_ITER_CRITICAL_SCALING {
+            let s1 = b - ratio * (b - a);
+            let s2 = a + ratio * (b - a);
+            if fit(tc(s1)).0 < fit(tc(s2)).0 {
+                b = s2;
+            } else {
+                a = s1;
+            }
+        }
+        let s = 0.5 * (a + b);
+        if (s - s_min).abs() < TOL_CRITICAL_SCALING || (s_max - s).abs() < TOL_CRITICAL_SCALING {
+            return Err(FeosError::Error(
+                "The fitted critical temperature lies at the boundary of the search interval."
+                    .into(),
+            ));
+        }
+        Ok((fit(tc(s)).1, Temperature::from_reduced(tc(s))))
+    }
+
+    /// Temperature at which the surface tension has a maximum, e.g., for
+    /// mixtures with an anomalous temperature dependence of the surface
+    /// tension.
+    ///
+    /// The points are sorted by temperature and the slopes
+    /// $\frac{\mathrm{d}\gamma}{\mathrm{d}T}$ are calculated between
+    /// consecutive points. A maximum is located where the slope changes from
+    /// positive to negative by linearly interpolating the slopes between the
+    /// midpoints of the adjacent intervals, which is exact for parabolas. Only
+    /// sign changes between interior intervals are considered, so that a
+    /// surface tension that is largest at the lowest or highest temperature of
+    /// a monotonic diagram is not reported as a maximum. Returns the
+    /// temperature of the first maximum or `None` if the surface tension has
+    /// no maximum within the diagram.
+    pub fn temperature_of_maximum_surface_tension(&self) -> FeosResult<Option<Temperature>> {
+        let mut points = self
+            .profiles
+            .iter()
+            .map(|p| {
+                let t = p.vle.vapor().temperature.to_reduced();
+                p.surface_tension
+                    .map(|gamma| (t, gamma.to_reduced()))
+                    .ok_or_else(|| FeosError::Error("The interfaces are not solved.".into()))
+            })
+            .collect::<FeosResult<Vec<_>>>()?;
+        if points.len() < 3 {
+            return Err(FeosError::Error(
+                "At least three points are required to locate a maximum of the surface tension."
+                    .into(),
+            ));
+        }
+        points.sort_by(|(t1, _), (t2, _)| t1.total_cmp(t2));
+        if points.windows(2).any(|p| p[1].0 <= p[0].0) {
+            return Err(FeosError::Error(
+                "The temperatures of the diagram have to be distinct.".into(),
+            ));
+        }
+
+        // slopes at the midpoints of the intervals
+        let slopes: Vec<_> = points
+            .windows(2)
+            .map(|p| {
+                (
+                    0.5 * (p[0].0 + p[1].0),
+                    (p[1].1 - p[0].1) / (p[1].0 - p[0].0),
+                )
+            })
+            .collect();
+        Ok(slopes
+            .windows(2)
+            .find(|s| s[0].1 > 0.0 && s[1].1 <= 0.0)
+            .map(|s| {
+                let ((t0, s0), (t1, s1)) = (s[0], s[1]);
+                Temperature::from_reduced(t0 + s0 / (s0 - s1) * (t1 - t0))
+            }))
+    }
+
     pub fn interfacial_enrichment(&self) -> Vec<Array1<f64>> {
         self.profiles
             .iter()
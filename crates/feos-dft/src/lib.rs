@@ -17,7 +17,7 @@ mod weight_functions;
 pub use convolver::{Convolver, ConvolverFFT};
 pub use functional::{HelmholtzEnergyFunctional, HelmholtzEnergyFunctionalDyn, MoleculeShape};
 pub use functional_contribution::FunctionalContribution;
-pub use geometry::{Axis, Geometry, Grid};
+pub use geometry::{Axis, Geometry, Grid, Quadrature};
 pub use pdgt::PdgtFunctionalProperties;
 pub use profile::{DFTProfile, DFTSpecification, DFTSpecifications};
 pub use solver::{DFTSolver, DFTSolverLog};
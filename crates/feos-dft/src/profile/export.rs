@@ -0,0 +1,170 @@
+use super::DFTProfile;
+use crate::geometry::{Geometry, Grid};
+use feos_core::{FeosResult, ReferenceSystem};
+use ndarray::{Array, Array2, Dimension, Ix1, Ix2, Ix3};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+type Columns = Vec<(String, Vec<f64>)>;
+
+impl<D: Dimension, F> DFTProfile<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+{
+    /// Flattened density profiles and external potentials of all segments.
+    ///
+    /// In Fortran order, the first index varies fastest, as required for
+    /// VTK files.
+    fn export_fields(&self, fortran_order: bool) -> Columns {
+        let flatten = |a: ndarray::ArrayView<f64, D>| -> Vec<f64> {
+            if fortran_order {
+                a.t().iter().copied().collect()
+            } else {
+                a.iter().copied().collect()
+            }
+        };
+        let density = self.density.to_reduced();
+        let mut fields = Vec::new();
+        for (name, data) in [
+            ("density", &density),
+            ("external_potential", &self.external_potential),
+        ] {
+            for (i, d) in data.outer_iter().enumerate() {
+                fields.push((format!("{name}_{i}"), flatten(d)));
+            }
+        }
+        fields
+    }
+}
+
+impl<F> DFTProfile<Ix1, F> {
+    /// Write the density profiles and external potentials to a CSV file.
+    ///
+    /// Every row contains the position in Å (`z` for cartesian, `r` for
+    /// curved geometries), the densities of all segments (`density_i`) in
+    /// molecules per Å³ and the reduced external potentials of all segments
+    /// (`external_potential_i`) in units of $k_\mathrm{B}T$.
+    pub fn to_csv<P: AsRef<Path>>(&self, path: P) -> FeosResult<()> {
+        let name = match self.grid.axes()[0].geometry {
+            Geometry::Cartesian => "z",
+            Geometry::Cylindrical | Geometry::Spherical => "r",
+        };
+        let mut columns = vec![(name.into(), self.grid.grids()[0].to_vec())];
+        columns.extend(self.export_fields(false));
+        write_csv(path, &columns)
+    }
+}
+
+impl<F> DFTProfile<Ix2, F> {
+    /// Cartesian coordinates in Å and their names at every grid point.
+    fn export_coordinates(&self) -> ([&str; 2], [Array2<f64>; 2]) {
+        match &self.grid {
+            Grid::Cylindrical { r, z } => {
+                let shape = [r.grid.len(), z.grid.len()];
+                let r_grid = Array::from_shape_fn(shape, |(i, _)| r.grid[i]);
+                let z_grid = Array::from_shape_fn(shape, |(_, j)| z.grid[j]);
+                (["r", "z"], [r_grid, z_grid])
+            }
+            _ => {
+                let [x, y] = self.meshgrid();
+                (["x", "y"], [x.to_reduced(), y.to_reduced()])
+            }
+        }
+    }
+
+    /// Write the density profiles and external potentials to a CSV file.
+    ///
+    /// Every row contains the cartesian coordinates in Å (`x` and `y`, or `r`
+    /// and `z` for cylindrical grids), the densities of all segments
+    /// (`density_i`) in molecules per Å³ and the reduced external potentials
+    /// of all segments (`external_potential_i`) in units of $k_\mathrm{B}T$.
+    pub fn to_csv<P: AsRef<Path>>(&self, path: P) -> FeosResult<()> {
+        let (names, coordinates) = self.export_coordinates();
+        let mut columns: Columns = names
+            .into_iter()
+            .zip(coordinates)
+            .map(|(n, c)| (n.into(), c.iter().copied().collect()))
+            .collect();
+        columns.extend(self.export_fields(false));
+        write_csv(path, &columns)
+    }
+
+    /// Write the density profiles and external potentials to a legacy VTK
+    /// file (structured grid) that can be opened, e.g., in ParaView.
+    ///
+    /// The grid is written in the plane $z=0$ with the coordinates in Å. The
+    /// point data contains the densities of all segments (`density_i`) in
+    /// molecules per Å³ and the reduced external potentials of all segments
+    /// (`external_potential_i`) in units of $k_\mathrm{B}T$.
+    pub fn to_vtk<P: AsRef<Path>>(&self, path: P) -> FeosResult<()> {
+        let (_, [x, y]) = self.export_coordinates();
+        let shape = [x.shape()[0], x.shape()[1], 1];
+        let z = vec![0.0; x.len()];
+        let points = [
+            x.t().iter().copied().collect(),
+            y.t().iter().copied().collect(),
+            z,
+        ];
+        write_vtk(path, shape, &points, &self.export_fields(true))
+    }
+}
+
+impl<F> DFTProfile<Ix3, F> {
+    /// Write the density profiles and external potentials to a legacy VTK
+    /// file (structured grid) that can be opened, e.g., in ParaView.
+    ///
+    /// The coordinates are given in Å, non-orthogonal unit cells of periodic
+    /// grids are resolved. The point data contains the densities of all
+    /// segments (`density_i`) in molecules per Å³ and the reduced external
+    /// potentials of all segments (`external_potential_i`) in units of
+    /// $k_\mathrm{B}T$.
+    pub fn to_vtk<P: AsRef<Path>>(&self, path: P) -> FeosResult<()> {
+        let [x, y, z] = self.meshgrid();
+        let shape = [x.shape()[0], x.shape()[1], x.shape()[2]];
+        let points = [x, y, z].map(|c| c.to_reduced().t().iter().copied().collect());
+        write_vtk(path, shape, &points, &self.export_fields(true))
+    }
+}
+
+fn write_csv<P: AsRef<Path>>(path: P, columns: &Columns) -> FeosResult<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    let header: Vec<_> = columns.iter().map(|(name, _)| name.as_str()).collect();
+    writeln!(file, "{}", header.join(","))?;
+    for i in 0..columns[0].1.len() {
+        let row: Vec<_> = columns.iter().map(|(_, c)| c[i].to_string()).collect();
+        writeln!(file, "{}", row.join(","))?;
+    }
+    file.flush()?;
+    Ok(())
+}
+
+fn write_vtk<P: AsRef<Path>>(
+    path: P,
+    shape: [usize; 3],
+    points: &[Vec<f64>; 3],
+    fields: &Columns,
+) -> FeosResult<()> {
+    let n = shape.iter().product::<usize>();
+    let mut file = BufWriter::new(File::create(path)?);
+    writeln!(file, "# vtk DataFile Version 3.0")?;
+    writeln!(file, "feos density profile")?;
+    writeln!(file, "ASCII")?;
+    writeln!(file, "DATASET STRUCTURED_GRID")?;
+    writeln!(file, "DIMENSIONS {} {} {}", shape[0], shape[1], shape[2])?;
+    writeln!(file, "POINTS {n} double")?;
+    let [x, y, z] = points;
+    for ((x, y), z) in x.iter().zip(y).zip(z) {
+        writeln!(file, "{x} {y} {z}")?;
+    }
+    writeln!(file, "POINT_DATA {n}")?;
+    for (name, values) in fields {
+        writeln!(file, "SCALARS {name} double 1")?;
+        writeln!(file, "LOOKUP_TABLE default")?;
+        for v in values {
+            writeln!(file, "{v}")?;
+        }
+    }
+    file.flush()?;
+    Ok(())
+}
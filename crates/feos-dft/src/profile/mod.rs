@@ -1,7 +1,9 @@
 use crate::convolver::{BulkConvolver, Convolver, ConvolverFFT};
 use crate::functional::HelmholtzEnergyFunctional;
-use crate::geometry::Grid;
+use crate::functional_contribution::FunctionalContribution;
+use crate::geometry::{Geometry, Grid, Quadrature};
 use crate::solver::{DFTSolver, DFTSolverLog};
+use crate::weight_functions::WeightFunctionInfo;
 use feos_core::{FeosError, FeosResult, ReferenceSystem, State};
 use nalgebra::{DVector, Dyn, U1};
 use ndarray::{
@@ -9,11 +11,14 @@ use ndarray::{
     RemoveAxis,
 };
 use num_dual::DualNum;
-use quantity::{_Volume, DEGREES, Density, Length, Moles, Quantity, Temperature, Volume};
+use quantity::{_Volume, Area, DEGREES, Density, Length, Moles, Quantity, Temperature, Volume};
+use rustdct::DctNum;
+use std::f64::consts::{FRAC_PI_3, PI};
 use std::ops::{Add, MulAssign};
 use std::sync::Arc;
 use typenum::Sum;
 
+mod export;
 mod properties;
 
 pub(crate) const MAX_POTENTIAL: f64 = 50.0;
@@ -32,6 +37,14 @@ pub trait DFTSpecification<D: Dimension, F>: Send + Sync {
         bulk_density: &Array1<f64>,
         z: &Array1<f64>,
     ) -> FeosResult<Array1<f64>>;
+
+    /// Relative deviation of the number of particles (of each segment) from
+    /// the value fixed by the specification.
+    ///
+    /// Returns `None`, if the number of particles is not fixed.
+    fn mass_conservation_residual(&self, _moles: &Array1<f64>) -> Option<f64> {
+        None
+    }
 }
 
 /// Common specifications for the grand potentials in a DFT calculation.
@@ -46,6 +59,15 @@ pub enum DFTSpecifications {
     Moles { moles: Array1<f64> },
     /// DFT with specified total number of moles.
     TotalMoles { total_moles: f64 },
+    /// DFT with specified chemical potentials of some components and specified
+    /// total number of moles of all other components.
+    ///
+    /// `open` contains the indices of the density profiles (segments for
+    /// heterosegmented functionals) whose chemical potentials are fixed by the
+    /// bulk densities, e.g., gases dissolved at a fixed partial pressure. The
+    /// bulk densities of the remaining profiles are iterated to obtain the
+    /// specified total number of moles.
+    Semigrand { open: Vec<usize>, total_moles: f64 },
 }
 
 impl DFTSpecifications {
@@ -79,6 +101,29 @@ impl DFTSpecifications {
         let moles = profile.integrate_reduced_comp(&rho).sum();
         Self::TotalMoles { total_moles: moles }
     }
+
+    /// Calculate the total number of moles of all profiles not contained in
+    /// `open` from the profile.
+    ///
+    /// Call this after initializing the density profile to fix the chemical
+    /// potentials of the profiles in `open` and the total number of particles
+    /// of all others.
+    pub fn semigrand_from_profile<D: Dimension, F: HelmholtzEnergyFunctional>(
+        profile: &DFTProfile<D, F>,
+        open: Vec<usize>,
+    ) -> Self
+    where
+        D::Larger: Dimension<Smaller = D>,
+    {
+        let rho = profile.density.to_reduced();
+        let moles = profile.integrate_reduced_comp(&rho);
+        let total_moles = moles
+            .indexed_iter()
+            .filter(|(i, _)| !open.contains(i))
+            .map(|(_, n)| n)
+            .sum();
+        Self::Semigrand { open, total_moles }
+    }
 }
 
 impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTSpecification<D, F> for DFTSpecifications {
@@ -94,12 +139,52 @@ impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTSpecification<D, F> for DFTS
             Self::TotalMoles { total_moles } => {
                 bulk_density * *total_moles / (bulk_density * z).sum()
             }
+            Self::Semigrand { open, total_moles } => {
+                let closed: f64 = (0..z.len())
+                    .filter(|i| !open.contains(i))
+                    .map(|i| bulk_density[i] * z[i])
+                    .sum();
+                Array1::from_shape_fn(bulk_density.len(), |i| {
+                    if open.contains(&i) {
+                        bulk_density[i]
+                    } else {
+                        bulk_density[i] * *total_moles / closed
+                    }
+                })
+            }
         })
     }
+
+    fn mass_conservation_residual(&self, moles: &Array1<f64>) -> Option<f64> {
+        match self {
+            Self::ChemicalPotential => None,
+            Self::Moles { moles: target } => Some(
+                ((moles - target) / target)
+                    .iter()
+                    .fold(0.0, |acc: f64, x| acc.max(x.abs())),
+            ),
+            Self::TotalMoles { total_moles } => {
+                Some(((moles.sum() - total_moles) / total_moles).abs())
+            }
+            Self::Semigrand { open, total_moles } => {
+                let closed: f64 = moles
+                    .indexed_iter()
+                    .filter(|(i, _)| !open.contains(i))
+                    .map(|(_, n)| n)
+                    .sum();
+                Some(((closed - total_moles) / total_moles).abs())
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
 /// A one-, two-, or three-dimensional density profile.
+///
+/// Density profiles (and the interfaces and pores built on them) are
+/// `Send + Sync` whenever the functional `F` is, so independent profiles can
+/// be solved in parallel, e.g., with `rayon`. Solving requires mutable access,
+/// which prevents concurrent modifications of a single profile.
 pub struct DFTProfile<D: Dimension, F> {
     pub grid: Grid,
     pub convolver: Arc<dyn Convolver<f64, D>>,
@@ -110,6 +195,9 @@ pub struct DFTProfile<D: Dimension, F> {
     pub bulk: State<F>,
     pub solver_log: Option<DFTSolverLog>,
     pub lanczos: Option<i32>,
+    pub convolver_coarsening: Option<usize>,
+    /// Components with a fixed density profile, see [DFTProfile::freeze_components].
+    pub frozen_components: Vec<usize>,
 }
 
 impl<F> DFTProfile<Ix1, F> {
@@ -216,6 +304,28 @@ where
         let weight_functions = bulk.eos.weight_functions(t);
         let convolver = ConvolverFFT::plan(&grid, &weight_functions, lanczos);
 
+        Self::new_with_convolver(grid, convolver, bulk, external_potential, density, lanczos)
+    }
+
+    /// Create a new density profile with a precomputed convolver.
+    ///
+    /// Planning the convolver is skipped, which saves time if many profiles
+    /// are calculated on the same grid and at the same temperature. The
+    /// convolver is not validated: the caller is responsible that it was
+    /// planned for `grid` with the weight functions of the functional at the
+    /// temperature of `bulk`, e.g., by reusing the convolver of another
+    /// profile. `lanczos` is only used if the convolver is planned again
+    /// later, e.g., in [DFTProfile::coarsen_convolution].
+    pub fn new_with_convolver(
+        grid: Grid,
+        convolver: Arc<dyn Convolver<f64, D>>,
+        bulk: &State<F>,
+        external_potential: Option<Array<f64, D::Larger>>,
+        density: Option<&Density<Array<f64, D::Larger>>>,
+        lanczos: Option<i32>,
+    ) -> Self {
+        let t = bulk.temperature.to_reduced();
+
         // initialize external potential
         let external_potential = external_potential.unwrap_or_else(|| {
             let mut n_grid = vec![bulk.eos.component_index().len()];
@@ -252,7 +362,115 @@ where
             bulk: bulk.clone(),
             solver_log: None,
             lanczos,
+            convolver_coarsening: None,
+            frozen_components: Vec::new(),
+        }
+    }
+
+    /// Evaluate the convolutions on a grid that is coarsened by `factor` in
+    /// every dimension.
+    ///
+    /// The profiles are averaged over blocks of `factor` grid points before
+    /// the convolutions and the results are linearly interpolated back to the
+    /// full grid. This reduces the cost of the convolutions at the expense of
+    /// accuracy: structures in the profile that are smaller than `factor` grid
+    /// spacings are smeared out, and the result is no longer the exact solution
+    /// on the full grid. Use it deliberately, e.g., for large parameter sweeps,
+    /// and compare to the exact result for a representative state.
+    ///
+    /// Only available for cartesian and periodic grids with numbers of grid
+    /// points that are divisible by `factor`.
+    pub fn coarsen_convolution(&mut self, factor: usize) -> FeosResult<()> {
+        if self.grid.coarsen(factor).is_none() {
+            return Err(FeosError::Error(format!(
+                "The grid can not be coarsened by a factor of {factor}."
+            )));
         }
+        self.convolver_coarsening = Some(factor);
+        let weight_functions = self
+            .bulk
+            .eos
+            .weight_functions(self.temperature.to_reduced());
+        self.convolver = self.plan_convolver(&weight_functions);
+        Ok(())
+    }
+
+    /// Fix the density profiles of the given components.
+    ///
+    /// The density of the frozen components is set to `density`, which
+    /// contains the profiles of all segments of the frozen components in the
+    /// order of the segments in the profile. During the solution, the
+    /// Euler-Lagrange equation is only solved for the remaining components,
+    /// which equilibrate in the presence of the fixed background density,
+    /// e.g., for quenched solvents or rigid scaffolds. The bulk densities of
+    /// the frozen components are kept constant, so they still contribute to
+    /// the chemical potentials of the remaining components.
+    ///
+    /// Components that were frozen previously remain frozen.
+    pub fn freeze_components(
+        &mut self,
+        components: &[usize],
+        density: &Density<Array<f64, D::Larger>>,
+    ) -> FeosResult<()> {
+        let n_components = self.bulk.eos.components();
+        if let Some(&i) = components.iter().find(|&&i| i >= n_components) {
+            return Err(FeosError::Error(format!(
+                "Component {i} does not exist in a system with {n_components} components."
+            )));
+        }
+        let segments: Vec<_> = self
+            .bulk
+            .eos
+            .component_index()
+            .iter()
+            .enumerate()
+            .filter(|&(_, c)| components.contains(c))
+            .map(|(s, _)| s)
+            .collect();
+        let mut expected = self.density.shape().to_vec();
+        expected[0] = segments.len();
+        if expected != density.shape() {
+            return Err(FeosError::Error(format!(
+                "The density of the frozen components has to be of shape {:?} (segments x grid points), got shape {:?}.",
+                expected,
+                density.shape()
+            )));
+        }
+        if density
+            .to_reduced()
+            .iter()
+            .any(|&r| !r.is_finite() || r < 0.0)
+        {
+            return Err(FeosError::Error(String::from(
+                "The density of the frozen components has to be finite and non-negative.",
+            )));
+        }
+        let mut rho = self.density.to_reduced();
+        let rho_frozen = density.to_reduced();
+        for (k, &s) in segments.iter().enumerate() {
+            rho.index_axis_mut(Axis_nd(0), s)
+                .assign(&rho_frozen.index_axis(Axis_nd(0), k));
+        }
+        self.density = Density::from_reduced(rho);
+        for &i in components {
+            if !self.frozen_components.contains(&i) {
+                self.frozen_components.push(i);
+            }
+        }
+        Ok(())
+    }
+
+    /// Plan a convolver for the grid and the convolution settings of the profile.
+    pub(crate) fn plan_convolver<N: DctNum + DualNum<f64>>(
+        &self,
+        weight_functions: &[WeightFunctionInfo<N>],
+    ) -> Arc<dyn Convolver<N, D>> {
+        ConvolverFFT::plan_coarse(
+            &self.grid,
+            weight_functions,
+            self.lanczos,
+            self.convolver_coarsening,
+        )
     }
 }
 
@@ -260,6 +478,32 @@ impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
 where
     D::Larger: Dimension<Smaller = D>,
 {
+    /// Indices of the segments of all frozen components.
+    pub(crate) fn frozen_segments(&self) -> Vec<usize> {
+        self.bulk
+            .eos
+            .component_index()
+            .iter()
+            .enumerate()
+            .filter(|&(_, c)| self.frozen_components.contains(c))
+            .map(|(s, _)| s)
+            .collect()
+    }
+
+    /// Reset the densities of the frozen segments to the frozen profiles.
+    pub(crate) fn reset_frozen_density(&self, density: &mut Array<f64, D::Larger>) {
+        let frozen_segments = self.frozen_segments();
+        if frozen_segments.is_empty() {
+            return;
+        }
+        let frozen_density = self.density.to_reduced();
+        for s in frozen_segments {
+            density
+                .index_axis_mut(Axis_nd(0), s)
+                .assign(&frozen_density.index_axis(Axis_nd(0), s));
+        }
+    }
+
     fn integrate_reduced<N: DualNum<f64> + Copy>(&self, mut profile: Array<N, D>) -> N {
         let (integration_weights, functional_determinant) = self.grid.integration_weights();
 
@@ -292,6 +536,17 @@ where
         integral_comp
     }
 
+    /// Relative deviation of the number of particles in the profile from the
+    /// number of particles fixed by the specification.
+    ///
+    /// For a converged profile, the residual should vanish. Larger values indicate
+    /// that particles are lost at the boundaries of a too small domain.
+    /// Returns `None`, if the specification does not fix the number of particles.
+    pub fn mass_conservation_residual(&self) -> Option<f64> {
+        let moles = self.integrate_reduced_comp(&self.density.to_reduced());
+        self.specification.mass_conservation_residual(&moles)
+    }
+
     /// Return the volume of the profile.
     ///
     /// In periodic directions, the length is assumed to be 1 Å.
@@ -300,6 +555,55 @@ where
         Volume::from_reduced(volume * self.grid.functional_determinant())
     }
 
+    /// Return the area of a dividing surface at `radius`.
+    ///
+    /// For spherical and cylindrical profiles, `radius` is the distance of the
+    /// dividing surface from the center; for planar profiles, the area does not
+    /// depend on the position of the dividing surface. In periodic directions
+    /// and along the axis of cylinders, the length is assumed to be 1 Å.
+    pub fn dividing_surface_area(&self, radius: Length) -> FeosResult<Area> {
+        let (area, _) = self.dividing_surface_geometry(radius)?;
+        Ok(Area::from_reduced(area))
+    }
+
+    /// Return the volume enclosed by a dividing surface at `radius`.
+    ///
+    /// For planar profiles, the volume between the boundary of the domain at
+    /// the beginning of the axis and the dividing surface is returned.
+    /// In periodic directions and along the axis of cylinders, the length is
+    /// assumed to be 1 Å.
+    pub fn enclosed_volume(&self, radius: Length) -> FeosResult<Volume> {
+        let (_, volume) = self.dividing_surface_geometry(radius)?;
+        Ok(Volume::from_reduced(volume))
+    }
+
+    /// Area of and volume enclosed by a dividing surface in reduced units.
+    fn dividing_surface_geometry(&self, radius: Length) -> FeosResult<(f64, f64)> {
+        let r = radius.to_reduced();
+        let (axis, length) = match &self.grid {
+            Grid::Cartesian1(x) | Grid::Periodical1(x) | Grid::Spherical(x) | Grid::Polar(x) => {
+                (x, 1.0)
+            }
+            Grid::Cylindrical { r, z } => (r, z.volume()),
+            _ => {
+                return Err(FeosError::Error(String::from(
+                    "Dividing surfaces are only defined for profiles with a single inhomogeneous direction.",
+                )));
+            }
+        };
+        let r0 = axis.edges[0];
+        if r < r0 || r > axis.edges[axis.grid.len()] {
+            return Err(FeosError::Error(format!(
+                "The dividing surface at {radius} is outside of the domain."
+            )));
+        }
+        Ok(match axis.geometry {
+            Geometry::Cartesian => (length, (r - r0) * length),
+            Geometry::Cylindrical => (2.0 * PI * r * length, PI * r * r * length),
+            Geometry::Spherical => (4.0 * PI * r * r, 4.0 * FRAC_PI_3 * r.powi(3)),
+        })
+    }
+
     /// Integrate a given profile over the iteration domain.
     pub fn integrate<S: Data<Elem = f64>, U>(
         &self,
@@ -318,6 +622,44 @@ where
         Volume::from_reduced(functional_determinant) * value.sum()
     }
 
+    /// Integrate a given profile over the iteration domain with the given
+    /// quadrature rule.
+    ///
+    /// [DFTProfile::integrate] uses the midpoint rule, which is of second
+    /// order in the grid spacing. [Quadrature::Simpson] is of fourth order
+    /// and exact for quadratic profiles, which reduces the error of smooth
+    /// profiles with non-vanishing gradients at the boundaries of the domain.
+    /// For profiles that approach bulk plateaus at the boundaries (e.g., the
+    /// grand potential density of planar interfaces), the error of the
+    /// midpoint rule decays faster than any power of the grid spacing and the
+    /// midpoint rule is consistent with the discretized functional, so it
+    /// remains the better choice for surface tensions.
+    ///
+    /// Returns an error if the quadrature rule is not available for the axes
+    /// of the grid.
+    pub fn integrate_quadrature<S: Data<Elem = f64>, U>(
+        &self,
+        profile: &Quantity<ArrayBase<S, D>, U>,
+        quadrature: Quadrature,
+    ) -> FeosResult<Quantity<f64, Sum<_Volume, U>>>
+    where
+        _Volume: Add<U>,
+    {
+        let (integration_weights, functional_determinant) =
+            self.grid.quadrature_weights(quadrature).ok_or_else(|| {
+                FeosError::Error(format!(
+                    "The quadrature rule {quadrature:?} is not available for this grid."
+                ))
+            })?;
+        let mut value = profile.to_owned();
+        for (i, w) in integration_weights.iter().enumerate() {
+            for mut l in value.lanes_mut(Axis_nd(i)) {
+                l.mul_assign(w);
+            }
+        }
+        Ok(Volume::from_reduced(functional_determinant) * value.sum())
+    }
+
     /// Integrate each component individually.
     pub fn integrate_comp<S: Data<Elem = f64>, U>(
         &self,
@@ -347,6 +689,12 @@ where
         integral_comp
     }
 
+    /// Return the density profile in reduced units together with the
+    /// reference density, such that `density = density_array * reference`.
+    pub fn density_array(&self) -> (Array<f64, D::Larger>, Density) {
+        (self.density.to_reduced(), Density::from_reduced(1.0))
+    }
+
     /// Return the number of moles of each component in the system.
     pub fn moles(&self) -> Moles<DVector<f64>> {
         self.integrate_segments(&self.density)
@@ -356,6 +704,80 @@ where
     pub fn total_moles(&self) -> Moles {
         self.moles().sum()
     }
+
+    /// Return the local mole fractions of all components on the grid.
+    ///
+    /// The density of a component is the average over the densities of its
+    /// segments. Where the total density vanishes (e.g., inside a wall), the
+    /// mole fractions are set to zero.
+    pub fn composition_profile(&self) -> Array<f64, D::Larger> {
+        let n = self.bulk.eos.components();
+        let density = self.density.to_reduced();
+        let mut shape = density.raw_dim();
+        shape[0] = n;
+        let mut x = Array::zeros(shape);
+        let mut segments = vec![0.0; n];
+        for (i, &j) in self.bulk.eos.component_index().iter().enumerate() {
+            let mut x_j = x.index_axis_mut(Axis_nd(0), j);
+            x_j += &density.index_axis(Axis_nd(0), i);
+            segments[j] += 1.0;
+        }
+        for (mut x_j, s) in x.outer_iter_mut().zip(segments) {
+            x_j /= s;
+        }
+        let total = x.sum_axis(Axis_nd(0));
+        for mut x_j in x.outer_iter_mut() {
+            x_j.zip_mut_with(&total, |x, &t| *x = if t > 0.0 { *x / t } else { 0.0 });
+        }
+        x
+    }
+
+    /// Maximum absolute difference between the density profiles of `self`
+    /// and `other` at any grid point.
+    ///
+    /// Both profiles have to be defined on the same grid. Useful to check
+    /// that results are reproducible, e.g., in regression tests.
+    pub fn max_density_difference(&self, other: &Self) -> FeosResult<Density> {
+        self.check_same_grid(other)?;
+        let (rho1, rho2) = (self.density.to_reduced(), other.density.to_reduced());
+        Ok(Density::from_reduced(
+            rho1.iter()
+                .zip(rho2.iter())
+                .fold(0.0, |d: f64, (r1, r2)| d.max((r1 - r2).abs())),
+        ))
+    }
+
+    /// Maximum difference between the density profiles of `self` and `other`
+    /// at any grid point relative to the larger of the two densities.
+    ///
+    /// Points at which both densities vanish are ignored.
+    pub fn max_relative_density_difference(&self, other: &Self) -> FeosResult<f64> {
+        self.check_same_grid(other)?;
+        let (rho1, rho2) = (self.density.to_reduced(), other.density.to_reduced());
+        Ok(rho1
+            .iter()
+            .zip(rho2.iter())
+            .filter(|(r1, r2)| r1.abs().max(r2.abs()) > 0.0)
+            .fold(0.0, |d: f64, (r1, r2)| {
+                d.max((r1 - r2).abs() / r1.abs().max(r2.abs()))
+            }))
+    }
+
+    fn check_same_grid(&self, other: &Self) -> FeosResult<()> {
+        let same_grid = self.density.shape() == other.density.shape()
+            && self
+                .grid
+                .grids()
+                .iter()
+                .zip(other.grid.grids())
+                .all(|(g1, g2)| *g1 == g2);
+        if !same_grid {
+            return Err(FeosError::Error(
+                "The density profiles have to be defined on the same grid.".into(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl<D: Dimension, F> DFTProfile<D, F>
@@ -364,12 +786,36 @@ where
     <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
     F: HelmholtzEnergyFunctional,
 {
+    /// Weighted densities of the current density profile.
+    ///
+    /// The weighted densities are calculated with the convolver of the
+    /// profile and returned in the order of the functional contributions.
+    /// For every contribution, the first axis of the array corresponds to the
+    /// individual weighted densities as defined by its weight functions.
     pub fn weighted_densities(&self) -> FeosResult<Vec<Array<f64, D::Larger>>> {
         Ok(self
             .convolver
             .weighted_densities(&self.density.to_reduced()))
     }
 
+    /// Weighted densities of the current density profile labeled with the
+    /// names of the corresponding functional contributions.
+    ///
+    /// Useful to inspect the intermediate results of individual functional
+    /// contributions, e.g., while implementing a new functional.
+    #[expect(clippy::type_complexity)]
+    pub fn weighted_densities_by_contribution(
+        &self,
+    ) -> FeosResult<Vec<(&'static str, Array<f64, D::Larger>)>> {
+        Ok(self
+            .bulk
+            .eos
+            .contributions()
+            .map(|c| c.name())
+            .zip(self.weighted_densities()?)
+            .collect())
+    }
+
     #[expect(clippy::type_complexity)]
     pub fn residual(&self, log: bool) -> FeosResult<(Array<f64, D::Larger>, Array1<f64>, f64)> {
         // Read from profile
@@ -437,6 +883,12 @@ where
             .bond_integrals(temperature, &exp_dfdrho, self.convolver.as_ref());
         let mut rho_projected = &exp_dfdrho * bonds;
 
+        // calculate the bulk densities from the specification
+        let z = self.integrate_reduced_comp(&rho_projected);
+        let bulk_density_spec =
+            self.specification
+                .calculate_bulk_density(self, bulk_density, &z)?;
+
         // multiply bulk density
         rho_projected
             .outer_iter_mut()
@@ -445,12 +897,19 @@ where
                 x *= rho_b;
             });
 
+        // keep the densities of frozen components fixed
+        let frozen_segments = self.frozen_segments();
+        self.reset_frozen_density(&mut rho_projected);
+
         // calculate residual
         let mut res = if log {
             rho_projected.mapv(f64::ln) - density.mapv(f64::ln)
         } else {
             &rho_projected - density
         };
+        for &s in &frozen_segments {
+            res.index_axis_mut(Axis_nd(0), s).fill(0.0);
+        }
 
         // set residual to 0 where external potentials are overwhelming
         res.iter_mut()
@@ -459,11 +918,14 @@ where
             .for_each(|(r, _)| *r = 0.0);
 
         // additional residuals for the calculation of the bulk densities
-        let z = self.integrate_reduced_comp(&rho_projected);
-        let res_bulk = bulk_density
-            - self
-                .specification
-                .calculate_bulk_density(self, bulk_density, &z)?;
+        let mut res_bulk = if log {
+            bulk_density_spec.mapv(f64::ln) - bulk_density.mapv(f64::ln)
+        } else {
+            bulk_density_spec - bulk_density
+        };
+        for &s in &frozen_segments {
+            res_bulk[s] = 0.0;
+        }
 
         // calculate the norm of the residual
         let res_norm = ((density - &rho_projected).mapv(|x| x * x).sum()
@@ -478,6 +940,17 @@ where
         }
     }
 
+    /// Human-readable description of the solver settings used in the last
+    /// solve, including solves that failed.
+    ///
+    /// Returns `None` if the profile was not solved yet.
+    pub fn solver_description(&self) -> Option<String> {
+        self.solver_log
+            .as_ref()
+            .and_then(|log| log.settings())
+            .map(|solver| solver.to_string())
+    }
+
     pub fn solve(&mut self, solver: Option<&DFTSolver>, debug: bool) -> FeosResult<()> {
         // unwrap solver
         let solver = solver.cloned().unwrap_or_default();
@@ -507,3 +980,20 @@ where
         Ok(())
     }
 }
+
+/// Richardson extrapolation of a property calculated on grids that are
+/// successively refined by a factor of 2.
+///
+/// The order of convergence is estimated from the three values and a second
+/// order convergence is assumed if the estimate fails. Returns the
+/// extrapolated value and the estimated error of the value on the finest grid.
+pub(crate) fn richardson_extrapolation([v0, v1, v2]: [f64; 3]) -> (f64, f64) {
+    let (d1, d2) = (v1 - v0, v2 - v1);
+    let order = if d1 * d2 > 0.0 && d2.abs() < d1.abs() {
+        (d1 / d2).log2()
+    } else {
+        2.0
+    };
+    let extrapolated = v2 + d2 / (2f64.powf(order) - 1.0);
+    (extrapolated, (extrapolated - v2).abs())
+}
@@ -2,7 +2,7 @@
 use super::DFTProfile;
 use crate::convolver::{BulkConvolver, Convolver};
 use crate::functional_contribution::FunctionalContribution;
-use crate::{ConvolverFFT, DFTSolverLog, HelmholtzEnergyFunctional, WeightFunctionInfo};
+use crate::{DFTSolverLog, HelmholtzEnergyFunctional, WeightFunctionInfo};
 use feos_core::{Contributions, FeosResult, ReferenceSystem, Total, Verbosity};
 use nalgebra::{DMatrix, DVector};
 use ndarray::{Array, Array1, Axis, Dimension, RemoveAxis};
@@ -119,7 +119,7 @@ where
             .into_iter()
             .map(|c| c.weight_functions(temperature_dual))
             .collect();
-        let convolver = ConvolverFFT::plan(&self.grid, &weight_functions, self.lanczos);
+        let convolver = self.plan_convolver(&weight_functions);
 
         let density = self.density.to_reduced();
 
@@ -207,7 +207,7 @@ where
             .into_iter()
             .map(|c| c.weight_functions(temperature_dual))
             .collect();
-        let convolver = ConvolverFFT::plan(&self.grid, &weight_functions, self.lanczos);
+        let convolver = self.plan_convolver(&weight_functions);
 
         let density = self.density.to_reduced();
 
@@ -257,7 +257,7 @@ where
             .into_iter()
             .map(|c| c.weight_functions(temperature_dual))
             .collect();
-        let convolver = ConvolverFFT::plan(&self.grid, &weight_functions, self.lanczos);
+        let convolver = self.plan_convolver(&weight_functions);
 
         let density = self.density.to_reduced();
 
@@ -391,8 +391,7 @@ where
             .into_iter()
             .map(|c| c.weight_functions(t_dual))
             .collect();
-        let convolver: Arc<dyn Convolver<_, D>> =
-            ConvolverFFT::plan(&self.grid, &weight_functions, self.lanczos);
+        let convolver: Arc<dyn Convolver<_, D>> = self.plan_convolver(&weight_functions);
         let (_, mut dfdrho) =
             self.bulk
                 .eos
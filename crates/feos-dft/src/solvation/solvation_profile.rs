@@ -130,7 +130,7 @@ fn external_potential_3d<F: HelmholtzEnergyFunctional + FluidParameters>(
     let cutoff_radius2 = cutoff_radius.powi(2);
 
     // calculate external potential
-    let sigma_ff = functional.sigma_ff();
+    let sigma_ff = functional.sigma_ff_t(reduced_temperature);
     let epsilon_k_ff = functional.epsilon_k_ff();
 
     Zip::indexed(&mut external_potential).par_for_each(|(i, ix, iy, iz), u| {
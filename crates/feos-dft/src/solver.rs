@@ -4,8 +4,8 @@ use crate::{
 };
 use feos_core::{FeosError, FeosResult, ReferenceSystem, Verbosity, log_iter, log_result};
 use nalgebra::{DMatrix, DVector, dvector};
-use ndarray::RemoveAxis;
 use ndarray::prelude::*;
+use ndarray::{IntoDimension, RemoveAxis};
 use petgraph::Directed;
 use petgraph::graph::Graph;
 use petgraph::visit::EdgeRef;
@@ -41,6 +41,9 @@ const DEFAULT_PARAMS_NEWTON: Newton = Newton {
     max_iter_gmres: 200,
     tol: 1e-11,
 };
+/// Negative densities smaller in magnitude than this fraction of the maximum
+/// density are attributed to round-off errors of the update.
+const TOL_NEGATIVE_DENSITY: f64 = 1e-8;
 
 #[derive(Clone, Copy, Debug)]
 struct PicardIteration {
@@ -150,6 +153,122 @@ impl DFTSolver {
         self.algorithms.push(DFTAlgorithm::Newton(params));
         self
     }
+
+    /// Override the damping coefficient of all Picard iterations and Anderson
+    /// mixing steps of the solver.
+    ///
+    /// The damping coefficient (under-relaxation factor) has to be in the
+    /// range $0<\alpha\leq 1$. The default for Anderson mixing is 0.15, Picard
+    /// iterations use a line search by default. If the solver diverges, e.g.,
+    /// close to critical points or for strongly associating fluids, values
+    /// between 0.01 and 0.1 usually stabilize the iteration at the cost of
+    /// more iterations. Newton steps are not affected.
+    pub fn damping_coefficient(mut self, damping_coefficient: f64) -> Self {
+        for algorithm in &mut self.algorithms {
+            match algorithm {
+                DFTAlgorithm::PicardIteration(picard) => {
+                    picard.damping_coefficient = Some(damping_coefficient)
+                }
+                DFTAlgorithm::AndersonMixing(anderson) => {
+                    anderson.damping_coefficient = damping_coefficient
+                }
+                DFTAlgorithm::Newton(_) => (),
+            }
+        }
+        self
+    }
+
+    /// Replace all Anderson mixing steps of the solver by Picard iterations.
+    ///
+    /// The Picard iterations keep the tolerance and the log setting of the
+    /// replaced steps and use a line search to determine the step size.
+    /// Picard iterations converge slowly but are robust and suit stiff
+    /// problems for which Anderson mixing diverges.
+    pub fn use_picard_iteration(mut self) -> Self {
+        for algorithm in &mut self.algorithms {
+            if let DFTAlgorithm::AndersonMixing(anderson) = *algorithm {
+                *algorithm = DFTAlgorithm::PicardIteration(PicardIteration {
+                    log: anderson.log,
+                    max_iter: anderson.max_iter.max(DEFAULT_PARAMS_PICARD.max_iter),
+                    tol: anderson.tol,
+                    damping_coefficient: None,
+                });
+            }
+        }
+        self
+    }
+
+    /// Replace all Picard iterations of the solver by Anderson mixing steps.
+    ///
+    /// The Anderson mixing steps keep the settings of the replaced steps. If
+    /// the Picard iteration uses a line search, the default damping
+    /// coefficient of Anderson mixing is used. Anderson mixing converges
+    /// considerably faster than Picard iterations and is the method of choice
+    /// for most systems.
+    pub fn use_anderson_mixing(mut self) -> Self {
+        for algorithm in &mut self.algorithms {
+            if let DFTAlgorithm::PicardIteration(picard) = *algorithm {
+                *algorithm = DFTAlgorithm::AndersonMixing(AndersonMixing {
+                    log: picard.log,
+                    max_iter: picard.max_iter,
+                    tol: picard.tol,
+                    damping_coefficient: picard
+                        .damping_coefficient
+                        .unwrap_or(DEFAULT_PARAMS_ANDERSON.damping_coefficient),
+                    mmax: DEFAULT_PARAMS_ANDERSON.mmax,
+                });
+            }
+        }
+        self
+    }
+
+    /// Override the tolerance of the last algorithm of the solver.
+    ///
+    /// Only the last algorithm decides whether the profile is converged, the
+    /// preceding algorithms merely provide initial values. Loose tolerances
+    /// are useful for exploratory calculations, tight tolerances for accurate
+    /// results.
+    pub fn tolerance(mut self, tol: f64) -> Self {
+        if let Some(algorithm) = self.algorithms.last_mut() {
+            match algorithm {
+                DFTAlgorithm::PicardIteration(picard) => picard.tol = tol,
+                DFTAlgorithm::AndersonMixing(anderson) => anderson.tol = tol,
+                DFTAlgorithm::Newton(newton) => newton.tol = tol,
+            }
+        }
+        self
+    }
+
+    /// Override the maximum number of iterations of the last algorithm of
+    /// the solver.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        if let Some(algorithm) = self.algorithms.last_mut() {
+            match algorithm {
+                DFTAlgorithm::PicardIteration(picard) => picard.max_iter = max_iter,
+                DFTAlgorithm::AndersonMixing(anderson) => anderson.max_iter = max_iter,
+                DFTAlgorithm::Newton(newton) => newton.max_iter = max_iter,
+            }
+        }
+        self
+    }
+
+    /// A copy of the given solver (or of the default solver) with the
+    /// tolerance and the maximum number of iterations of the last algorithm
+    /// overridden, if provided.
+    pub(crate) fn with_overrides(
+        solver: Option<&Self>,
+        tol: Option<f64>,
+        max_iter: Option<usize>,
+    ) -> Self {
+        let mut solver = solver.cloned().unwrap_or_default();
+        if let Some(tol) = tol {
+            solver = solver.tolerance(tol);
+        }
+        if let Some(max_iter) = max_iter {
+            solver = solver.max_iter(max_iter);
+        }
+        solver
+    }
 }
 
 /// A log that stores the residuals and execution time of DFT solvers.
@@ -160,6 +279,8 @@ pub struct DFTSolverLog {
     residual: Vec<f64>,
     time: Vec<Duration>,
     solver: Vec<&'static str>,
+    settings: Option<DFTSolver>,
+    negative_densities: usize,
 }
 
 impl DFTSolverLog {
@@ -174,6 +295,8 @@ impl DFTSolverLog {
             residual: Vec::new(),
             time: Vec::new(),
             solver: Vec::new(),
+            settings: None,
+            negative_densities: 0,
         }
     }
 
@@ -195,6 +318,17 @@ impl DFTSolverLog {
         );
     }
 
+    fn add_negative_densities(&mut self, solver: &'static str, iteration: usize, count: usize) {
+        self.negative_densities += count;
+        log_iter!(
+            self.verbosity,
+            "{:22} | {:>4} | replaced {} negative densities by their absolute values",
+            solver,
+            iteration,
+            count,
+        );
+    }
+
     pub fn residual(&self) -> ArrayView1<'_, f64> {
         (&self.residual).into()
     }
@@ -206,6 +340,20 @@ impl DFTSolverLog {
     pub fn solver(&self) -> &[&'static str] {
         &self.solver
     }
+
+    /// The settings of the solver that produced the log, i.e., the
+    /// algorithms with their tolerances, maximum numbers of iterations and
+    /// damping coefficients.
+    pub fn settings(&self) -> Option<&DFTSolver> {
+        self.settings.as_ref()
+    }
+
+    /// The number of densities that were negative beyond round-off errors
+    /// after an update of Anderson mixing or Newton in linear space and
+    /// were replaced by their absolute values.
+    pub fn negative_densities(&self) -> usize {
+        self.negative_densities
+    }
 }
 
 impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
@@ -223,8 +371,9 @@ where
         let mut converged = false;
         let mut iterations = 0;
         let mut log = DFTSolverLog::new(solver.verbosity);
+        log.settings = Some(solver.clone());
         for algorithm in &solver.algorithms {
-            let (conv, iter) = match algorithm {
+            let result = match algorithm {
                 DFTAlgorithm::PicardIteration(picard) => {
                     self.solve_picard(*picard, rho, rho_bulk, &mut log)
                 }
@@ -232,10 +381,25 @@ where
                     self.solve_anderson(*anderson, rho, rho_bulk, &mut log)
                 }
                 DFTAlgorithm::Newton(newton) => self.solve_newton(*newton, rho, rho_bulk, &mut log),
-            }?;
+            };
+            // keep the log of failed iterations for debugging
+            let (conv, iter) = match result {
+                Ok(result) => result,
+                Err(err) => {
+                    self.solver_log = Some(log);
+                    return Err(err);
+                }
+            };
             converged = conv;
             iterations += iter;
         }
+        if log.negative_densities > 0 {
+            log_result!(
+                solver.verbosity,
+                "Warning: {} negative densities were replaced by their absolute values",
+                log.negative_densities
+            );
+        }
         self.solver_log = Some(log);
         if converged {
             log_result!(solver.verbosity, "DFT solved in {} iterations", iterations);
@@ -289,6 +453,7 @@ where
                 *rho += &(&res * damping_coefficient);
                 *rho_bulk += &(&res_bulk * damping_coefficient);
             }
+            Self::check_density(solver, k, rho, rho_bulk)?;
         }
         Ok((false, picard.max_iter))
     }
@@ -313,6 +478,9 @@ where
             } else {
                 rho + alpha * delta_rho
             };
+            if rho_new.iter().any(|&r| r < 0.0) {
+                continue;
+            }
             let Ok((_, _, res2, _, _)) =
                 self.euler_lagrange_equation(&rho_new, rho_bulk, logarithm)
             else {
@@ -357,6 +525,62 @@ where
         Ok(alpha)
     }
 
+    /// Check that all densities are finite and not negative after an update.
+    ///
+    /// Returns an error that identifies the segment and the grid point of the
+    /// first invalid density, so that failed iterations can be diagnosed
+    /// instead of surfacing as NaN in the results. Small negative densities
+    /// that result from round-off errors of the update are accepted.
+    fn check_density(
+        solver: &str,
+        iteration: usize,
+        rho: &Array<f64, D::Larger>,
+        rho_bulk: &Array1<f64>,
+    ) -> FeosResult<()> {
+        let rho_max = rho.iter().chain(rho_bulk).fold(0.0, |a: f64, &r| a.max(r));
+        let is_invalid = |r: &f64| !(r.is_finite() && *r >= -TOL_NEGATIVE_DENSITY * rho_max);
+        if let Some((index, rho)) = rho.indexed_iter().find(|(_, r)| is_invalid(r)) {
+            let index = index.into_dimension();
+            let index = index.slice();
+            return Err(FeosError::Error(format!(
+                "{solver} encountered the density {rho} of segment {} at grid point {:?} in iteration {iteration}.",
+                index[0],
+                &index[1..]
+            )));
+        }
+        if let Some((s, rho)) = rho_bulk.iter().enumerate().find(|(_, r)| is_invalid(r)) {
+            return Err(FeosError::Error(format!(
+                "{solver} encountered the bulk density {rho} of segment {s} in iteration {iteration}."
+            )));
+        }
+        Ok(())
+    }
+
+    /// Replace the densities by their absolute values after an update in
+    /// linear space.
+    ///
+    /// Densities that are negative beyond round-off errors are counted in the
+    /// log, so that the correction does not go unnoticed.
+    fn flip_negative_density(
+        solver: &'static str,
+        iteration: usize,
+        rho: &mut Array<f64, D::Larger>,
+        rho_bulk: &mut Array1<f64>,
+        log: &mut DFTSolverLog,
+    ) {
+        let rho_max = rho.iter().chain(&*rho_bulk).fold(0.0, |a: f64, &r| a.max(r));
+        let count = rho
+            .iter()
+            .chain(&*rho_bulk)
+            .filter(|&&r| r < -TOL_NEGATIVE_DENSITY * rho_max)
+            .count();
+        if count > 0 {
+            log.add_negative_densities(solver, iteration, count);
+        }
+        rho.mapv_inplace(f64::abs);
+        rho_bulk.mapv_inplace(f64::abs);
+    }
+
     fn solve_anderson(
         &self,
         anderson: AndersonMixing,
@@ -430,9 +654,10 @@ where
                 rho.mapv_inplace(f64::exp);
                 rho_bulk.mapv_inplace(f64::exp);
             } else {
-                rho.mapv_inplace(f64::abs);
-                rho_bulk.mapv_inplace(f64::abs);
+                Self::flip_negative_density(solver, k, rho, rho_bulk, log);
             }
+            self.reset_frozen_density(rho);
+            Self::check_density(solver, k, rho, rho_bulk)?;
         }
         Ok((false, anderson.max_iter))
     }
@@ -445,6 +670,7 @@ where
         log: &mut DFTSolverLog,
     ) -> FeosResult<(bool, usize)> {
         let solver = if newton.log { "Newton (log)" } else { "Newton" };
+        let frozen_segments = self.frozen_segments();
         for k in 0..newton.max_iter {
             // calculate initial residual
             let (res, _, res_norm, exp_dfdrho, rho_p) =
@@ -469,14 +695,19 @@ where
                     .for_each(|(mut q, &m)| q /= m);
                 let delta_i = self.delta_bond_integrals(&exp_dfdrho, &delta_functional_derivative);
                 let rho = if newton.log { &*rho } else { &rho_p };
-                delta_rho + (delta_functional_derivative - delta_i) * rho
+                let mut delta_res = (delta_functional_derivative - delta_i) * rho;
+                for &s in &frozen_segments {
+                    delta_res.index_axis_mut(Axis(0), s).fill(0.0);
+                }
+                delta_rho + delta_res
             };
 
             // update solution
             let lhs = if newton.log { &*rho * res } else { res };
             *rho += &Self::gmres(rhs, &lhs, newton.max_iter_gmres, newton.tol * 1e-2, log)?;
-            rho.mapv_inplace(f64::abs);
-            rho_bulk.mapv_inplace(f64::abs);
+            Self::flip_negative_density(solver, k, rho, rho_bulk, log);
+            self.reset_frozen_density(rho);
+            Self::check_density(solver, k, rho, rho_bulk)?;
         }
 
         Ok((false, newton.max_iter))
@@ -3,7 +3,7 @@
 use criterion::{Criterion, criterion_group, criterion_main};
 use feos::core::parameter::IdentifierOption;
 use feos::core::{PhaseEquilibrium, State, StateBuilder};
-use feos::dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+use feos::dft::adsorption::{ExternalPotential, InitialGuess, Pore1D, PoreSpecification};
 use feos::dft::{DFTSolver, Geometry};
 use feos::gc_pcsaft::{GcPcSaftFunctional, GcPcSaftParameters};
 use feos::hard_sphere::{FMTFunctional, FMTVersion};
@@ -25,7 +25,11 @@ fn fmt(c: &mut Criterion) {
     );
     let bulk = State::new_pure(&func, KELVIN, 0.75 / NAV / ANGSTROM.powi::<P3>()).unwrap();
     group.bench_function("liquid", |b| {
-        b.iter(|| pore.initialize(&bulk, None, None).unwrap().solve(None))
+        b.iter(|| {
+            pore.initialize(&bulk, InitialGuess::Bulk, None)
+                .unwrap()
+                .solve(None)
+        })
     });
 }
 
@@ -53,11 +57,19 @@ fn pcsaft(c: &mut Criterion) {
     let vle = PhaseEquilibrium::pure(&func, 300.0 * KELVIN, None, Default::default()).unwrap();
     let bulk = vle.liquid();
     group.bench_function("butane_liquid", |b| {
-        b.iter(|| pore.initialize(bulk, None, None).unwrap().solve(None))
+        b.iter(|| {
+            pore.initialize(bulk, InitialGuess::Bulk, None)
+                .unwrap()
+                .solve(None)
+        })
     });
     let bulk = State::new_pure(&func, 300.0 * KELVIN, vle.vapor().density * 0.2).unwrap();
     group.bench_function("butane_vapor", |b| {
-        b.iter(|| pore.initialize(&bulk, None, None).unwrap().solve(None))
+        b.iter(|| {
+            pore.initialize(&bulk, InitialGuess::Bulk, None)
+                .unwrap()
+                .solve(None)
+        })
     });
 
     let parameters = PcSaftParameters::from_json(
@@ -79,7 +91,11 @@ fn pcsaft(c: &mut Criterion) {
     .unwrap();
     let bulk = vle.liquid();
     group.bench_function("butane_pentane_liquid", |b| {
-        b.iter(|| pore.initialize(bulk, None, None).unwrap().solve(None))
+        b.iter(|| {
+            pore.initialize(bulk, InitialGuess::Bulk, None)
+                .unwrap()
+                .solve(None)
+        })
     });
     let bulk = StateBuilder::new(&func)
         .temperature(300.0 * KELVIN)
@@ -87,7 +103,11 @@ fn pcsaft(c: &mut Criterion) {
         .build()
         .unwrap();
     group.bench_function("butane_pentane_vapor", |b| {
-        b.iter(|| pore.initialize(&bulk, None, None).unwrap().solve(None))
+        b.iter(|| {
+            pore.initialize(&bulk, InitialGuess::Bulk, None)
+                .unwrap()
+                .solve(None)
+        })
     });
 }
 
@@ -122,7 +142,7 @@ fn gc_pcsaft(c: &mut Criterion) {
         .anderson_mixing(None, None, None, None, None);
     group.bench_function("butane_liquid", |b| {
         b.iter(|| {
-            pore.initialize(bulk, None, None)
+            pore.initialize(bulk, InitialGuess::Bulk, None)
                 .unwrap()
                 .solve(Some(&solver))
         })
@@ -76,6 +76,10 @@ impl FluidParameters for SaftVRQMie {
     fn sigma_ff(&self) -> DVector<f64> {
         self.params.sigma.clone()
     }
+
+    fn sigma_ff_t(&self, temperature: f64) -> DVector<f64> {
+        self.params.sigma_eff(temperature)
+    }
 }
 
 impl PairPotential for SaftVRQMie {
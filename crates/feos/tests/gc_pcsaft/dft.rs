@@ -4,7 +4,7 @@ use approx::assert_relative_eq;
 use feos::gc_pcsaft::{GcPcSaft, GcPcSaftFunctional, GcPcSaftParameters};
 use feos_core::parameter::{ChemicalRecord, Identifier, IdentifierOption, SegmentRecord};
 use feos_core::{PhaseEquilibrium, State, StateBuilder, Verbosity};
-use feos_dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+use feos_dft::adsorption::{ExternalPotential, InitialGuess, Pore1D, PoreSpecification};
 use feos_dft::interface::PlanarInterface;
 use feos_dft::{DFTSolver, Geometry};
 use nalgebra::dvector;
@@ -189,6 +189,39 @@ fn test_dft() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_dft_component_properties() -> Result<(), Box<dyn Error>> {
+    let parameters = GcPcSaftParameters::from_json_segments_hetero(
+        &["propane"],
+        "../../parameters/pcsaft/gc_substances.json",
+        "../../parameters/pcsaft/sauer2014_hetero.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = GcPcSaftFunctional::new(parameters);
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+    let profile = PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, tc, false).solve(None)?;
+
+    // all segments of propane are assigned to the single component
+    let n_segments = profile.profile.density.shape()[0];
+    assert!(n_segments > 1);
+    let properties = profile.component_properties();
+    assert_eq!(properties.len(), 1);
+    assert_eq!(properties[0].component, 0);
+    assert_eq!(properties[0].segments, (0..n_segments).collect::<Vec<_>>());
+    let enrichment = properties[0].interfacial_enrichment;
+    assert!((1.0..1.01).contains(&enrichment));
+
+    let density = profile.component_density();
+    assert_relative_eq!(
+        density.get((0, 0)),
+        vle.liquid().density,
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn test_dft_assoc() -> Result<(), Box<dyn Error>> {
@@ -232,7 +265,7 @@ fn test_dft_assoc() -> Result<(), Box<dyn Error>> {
         None,
         None,
     )
-    .initialize(&bulk, None, None)
+    .initialize(&bulk, InitialGuess::Bulk, None)
     .unwrap()
     .solve(Some(&solver))?;
     Ok(())
@@ -5,14 +5,28 @@ use feos::hard_sphere::FMTVersion;
 use feos::ideal_gas::{Joback, JobackParameters};
 use feos::pcsaft::{PcSaft, PcSaftFunctional, PcSaftParameters};
 use feos_core::parameter::IdentifierOption;
-use feos_core::{Contributions, EquationOfState, FeosResult, PhaseEquilibrium, State, Verbosity};
-use feos_dft::interface::PlanarInterface;
-use feos_dft::{DFTSolver, PdgtFunctionalProperties};
+use feos_core::{
+    Contributions, DensityInitialization, EquationOfState, FeosResult, PhaseEquilibrium,
+    ReferenceSystem, State, Verbosity,
+};
+use feos_dft::adsorption::{
+    Adsorption1D, CorrugatedWall2D, ExternalPotential, FluidParameters, InitialGuess, Pore1D,
+    PoreProfile1D, PoreSpecification, Wall1D, pore_size_distribution,
+};
+use feos_dft::interface::{
+    self, Droplet, FreeSurface, PlanarInterface, SurfaceTensionDiagram, WettingState,
+};
+use feos_dft::{
+    DFTSolver, DFTSpecifications, Geometry, HelmholtzEnergyFunctional, PdgtFunctionalProperties,
+    Quadrature,
+};
 use nalgebra::dvector;
-use ndarray::Axis;
+use ndarray::{Array1, Array2, Array3, Axis, arr1, s};
 use quantity::*;
 use std::error::Error;
-use typenum::P3;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+use typenum::{P2, P3};
 
 fn parameters(comp: &str) -> FeosResult<PcSaftParameters> {
     PcSaftParameters::from_json(
@@ -23,6 +37,18 @@ fn parameters(comp: &str) -> FeosResult<PcSaftParameters> {
     )
 }
 
+fn lj93_wall() -> ExternalPotential {
+    ExternalPotential::LJ93 {
+        epsilon_k_ss: 10.0,
+        sigma_ss: 3.0,
+        rho_s: 0.08,
+    }
+}
+
+fn lj93_pore(pore_size: Length, n_grid: Option<usize>) -> Pore1D {
+    Pore1D::new(Geometry::Cartesian, pore_size, lj93_wall(), n_grid, None)
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn test_bulk_implementations() -> Result<(), Box<dyn Error>> {
@@ -204,6 +230,32 @@ fn test_dft_propane() -> Result<(), Box<dyn Error>> {
         surface_tension_pdgt,
         max_relative = 1e-10,
     );
+
+    // labeled weighted densities of the individual contributions
+    let weighted_densities = profile_full.weighted_densities()?;
+    assert!(weighted_densities.iter().all(|(name, _)| !name.is_empty()));
+    assert_eq!(weighted_densities.len(), 3);
+    for ((_, n), n_ref) in weighted_densities
+        .iter()
+        .zip(profile_full.profile.weighted_densities()?)
+    {
+        assert_eq!(n, n_ref);
+    }
+
+    // the pure-component and the vectorized implementation give the same profiles
+    assert!(
+        profile_pure
+            .profile
+            .max_relative_density_difference(&profile_full_vec.profile)?
+            < 1e-8
+    );
+    assert!(
+        profile_pure
+            .profile
+            .max_density_difference(&profile_full.profile)?
+            > 1e-6 * profile_pure.vle.liquid().density
+    );
+    assert!(profile_pure.surface_tension_difference(&profile_full_vec)? < 1e-8 * surface_tension);
     Ok(())
 }
 
@@ -223,6 +275,2767 @@ fn test_dft_propane_newton() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_dft_propane_negative_density() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+
+    // an overly aggressive constant damping drives densities negative
+    let solver = DFTSolver::new(None).picard_iteration(None, Some(20), None, Some(20.0));
+    let mut interface = PlanarInterface::from_tanh(&vle, 512, 150.0 * ANGSTROM, tc, false);
+    let err = interface.solve_inplace(Some(&solver), false).unwrap_err();
+    assert!(err.to_string().contains("at grid point"));
+    assert!(interface.profile.solver_log.is_some());
+
+    // Anderson mixing in linear space replaces negative densities by their
+    // absolute values and counts them in the log
+    let solver = DFTSolver::new(None).anderson_mixing(Some(false), Some(50), None, Some(5.0), None);
+    let mut interface = PlanarInterface::from_tanh(&vle, 512, 150.0 * ANGSTROM, tc, false);
+    let _ = interface.solve_inplace(Some(&solver), true);
+    let log = interface.profile.solver_log.unwrap();
+    assert!(log.negative_densities() > 0);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_mixing() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 512;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let reference = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve(None)?;
+    let solver = DFTSolver::default().damping_coefficient(0.05);
+    let damped = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve(Some(&solver))?;
+    let solver = DFTSolver::default().use_picard_iteration();
+    let picard = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve(Some(&solver))?;
+    assert!(
+        picard
+            .profile
+            .solver_log
+            .as_ref()
+            .unwrap()
+            .solver()
+            .iter()
+            .all(|s| s.starts_with("Picard iteration"))
+    );
+    for interface in [damped, picard] {
+        assert_relative_eq!(
+            interface.surface_tension.unwrap(),
+            reference.surface_tension.unwrap(),
+            max_relative = 1e-8
+        );
+    }
+
+    let loose = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve_with_tolerance(
+        None,
+        Some(1e-6),
+        None,
+    )?;
+    let iterations =
+        |i: &PlanarInterface<_>| i.profile.solver_log.as_ref().unwrap().residual().len();
+    assert!(iterations(&loose) < iterations(&reference));
+    assert_relative_eq!(
+        loose.surface_tension.unwrap(),
+        reference.surface_tension.unwrap(),
+        max_relative = 1e-3
+    );
+    let solver = DFTSolver::default().max_iter(1);
+    assert!(
+        PlanarInterface::from_tanh(&vle, points, w, tc, false)
+            .solve(Some(&solver))
+            .is_err()
+    );
+    assert!(
+        PlanarInterface::from_tanh(&vle, points, w, tc, false)
+            .solve_with_tolerance(Some(&solver), None, Some(1000))
+            .is_ok()
+    );
+
+    // the solver settings are reported also for failed solves
+    let mut failed = PlanarInterface::from_tanh(&vle, points, w, tc, false);
+    assert!(failed.solve_inplace(Some(&solver), false).is_err());
+    let description = failed.profile.solver_description().unwrap();
+    assert_eq!(description, solver.to_string());
+    assert!(description.contains("max_iter: 1,"));
+    assert!(
+        reference
+            .profile
+            .solver_description()
+            .unwrap()
+            .contains("AndersonMixing")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_critical_scaling() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vles = [0.02, 0.015, 0.01, 0.008, 0.006, 0.005]
+        .iter()
+        .map(|tau| PhaseEquilibrium::pure(&&func, (1.0 - tau) * tc, None, Default::default()))
+        .collect::<FeosResult<Vec<_>>>()?;
+    let mut dia = SurfaceTensionDiagram::new(&vles, None, Some(1024), None, Some(tc), None, None);
+    assert_eq!(dia.profiles.len(), vles.len());
+    let (mu, tc_fit) = dia.critical_scaling(tc, 0.025)?;
+    assert!((mu - 1.5).abs() < 0.05);
+    assert_relative_eq!(tc_fit, tc, max_relative = 1e-3);
+
+    // not enough points close to the critical point
+    assert!(dia.critical_scaling(tc, 0.009).is_err());
+
+    // the surface tension decreases monotonically with the temperature
+    assert!(dia.temperature_of_maximum_surface_tension()?.is_none());
+
+    // the maximum of a parabola is located exactly
+    let t: Vec<_> = vles.iter().map(|vle| vle.vapor().temperature).collect();
+    let t_max = t[2] + 0.3 * (t[3] - t[2]);
+    for (p, &t) in dia.profiles.iter_mut().zip(&t) {
+        let dt = (t - t_max).to_reduced();
+        p.surface_tension = Some(SurfaceTension::from_reduced(1e-2 - 1e-6 * dt * dt));
+    }
+    assert_relative_eq!(
+        dia.temperature_of_maximum_surface_tension()?.unwrap(),
+        t_max,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_isobar() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let cp = State::critical_point(&&func, None, None, None, Default::default())?;
+    let pc = cp.pressure(Contributions::Total);
+    let pressures = Pressure::linspace(0.1 * pc, 1.2 * pc, 12);
+    let mut dia = SurfaceTensionDiagram::isobar(
+        &&func,
+        &pressures,
+        None,
+        Some(512),
+        None,
+        Some(cp.temperature),
+        None,
+        None,
+    );
+    let n = dia.profiles.len();
+    assert!(n > 0 && n < pressures.len());
+    for vle in dia.profiles.iter().map(|p| &p.vle) {
+        assert!(vle.vapor().pressure(Contributions::Total) < pc);
+    }
+    let gamma = dia.surface_tension();
+    for i in 1..n {
+        assert!(gamma.get(i) < gamma.get(i - 1));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_surface_tension_cache() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vles = |temperatures: [f64; 3]| {
+        temperatures
+            .into_iter()
+            .map(|t| PhaseEquilibrium::pure(&&func, t * KELVIN, None, Default::default()))
+            .collect::<FeosResult<Vec<_>>>()
+    };
+    let dia = SurfaceTensionDiagram::new(
+        &vles([200.0, 240.0, 280.0])?,
+        None,
+        Some(512),
+        None,
+        None,
+        None,
+        None,
+    );
+    let cache = dia.cache();
+    assert_eq!(cache.len(), 3);
+
+    // initialize nearby temperatures from the cache
+    let vles = vles([205.0, 245.0, 285.0])?;
+    let mut dia_cache = SurfaceTensionDiagram::new_with_cache(
+        &vles,
+        &cache,
+        None,
+        Some(512),
+        None,
+        None,
+        None,
+        None,
+    );
+    let mut dia_ref = SurfaceTensionDiagram::new(&vles, None, Some(512), None, None, None, None);
+    let gamma_cache = dia_cache.surface_tension();
+    let gamma_ref = dia_ref.surface_tension();
+    assert_eq!(gamma_cache.len(), 3);
+    for i in 0..3 {
+        assert_relative_eq!(gamma_cache.get(i), gamma_ref.get(i), max_relative = 1e-6);
+    }
+
+    // stop the calculation after the second point
+    let mut reported = Vec::new();
+    let dia_progress = SurfaceTensionDiagram::new_with_progress(
+        &vles,
+        &cache,
+        None,
+        Some(512),
+        None,
+        None,
+        None,
+        None,
+        |i, t, gamma| {
+            reported.push((i, t, gamma.unwrap()));
+            if i == 1 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+    assert_eq!(dia_progress.profiles.len(), 2);
+    assert_eq!(reported.len(), 2);
+    for (i, t, gamma) in reported {
+        assert_eq!(t, vles[i].vapor().temperature);
+        assert_relative_eq!(gamma, gamma_ref.get(i), max_relative = 1e-6);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_resolve_with() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let mut params = parameters("propane")?;
+    params.pure[0].model_record.epsilon_k *= 1.01;
+    let func_perturbed = PcSaftFunctional::new(params);
+    let t = 200.0 * KELVIN;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface =
+        PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, tc, false).solve(None)?;
+
+    // warm restart from the converged profile
+    let resolved = interface.resolve_with(&&func_perturbed, None)?;
+    let vle = PhaseEquilibrium::pure(&&func_perturbed, t, None, Default::default())?;
+    let reference =
+        PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, tc, false).solve(None)?;
+    assert_relative_eq!(
+        resolved.surface_tension.unwrap(),
+        reference.surface_tension.unwrap(),
+        max_relative = 1e-6
+    );
+    assert!(resolved.surface_tension.unwrap() > interface.surface_tension.unwrap());
+    let iterations =
+        |i: &PlanarInterface<_>| i.profile.solver_log.as_ref().unwrap().residual().len();
+    assert!(iterations(&resolved) < iterations(&reference));
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_surface_tension_sensitivity() -> Result<(), Box<dyn Error>> {
+    let func = Arc::new(PcSaftFunctional::new(parameters("propane")?));
+    let t = 200.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .solve(None)?;
+    let epsilon_k = |delta: f64| -> FeosResult<_> {
+        let mut params = parameters("propane")?;
+        params.pure[0].model_record.epsilon_k += delta;
+        Ok(Arc::new(PcSaftFunctional::new(params)))
+    };
+    let sigma = |delta: f64| -> FeosResult<_> {
+        let mut params = parameters("propane")?;
+        params.pure[0].model_record.sigma += delta;
+        Ok(Arc::new(PcSaftFunctional::new(params)))
+    };
+    let sensitivity = interface.surface_tension_sensitivity(&[epsilon_k], 0.5, None)?;
+
+    // compare to a central difference with a larger step
+    let gamma = |delta: f64| -> FeosResult<SurfaceTension> {
+        let func = epsilon_k(delta)?;
+        let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+        Ok(
+            PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+                .solve(None)?
+                .surface_tension
+                .unwrap(),
+        )
+    };
+    let reference = (gamma(2.0)? - gamma(-2.0)?) / 4.0;
+    assert!(sensitivity.get(0).to_reduced() > 0.0);
+    assert_relative_eq!(sensitivity.get(0), reference, max_relative = 1e-2);
+
+    // larger molecules at the same energy parameter have a lower surface tension
+    let sensitivity = interface.surface_tension_sensitivity(&[sigma], 1e-3, None)?;
+    assert!(sensitivity.get(0).to_reduced() < 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_shift_equimolar() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 100.0 * ANGSTROM;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 512, w, 370.0 * KELVIN, false).solve(None)?;
+    let z0 = interface.profile.grid.grids()[0][0];
+    let shifted = interface.clone().shift_equimolar()?;
+    let ze = z0 - shifted.profile.grid.grids()[0][0];
+
+    // the same results without modifying the grid
+    let z_s = interface.equimolar_dividing_surface()?;
+    assert_relative_eq!(z_s.to_reduced(), ze, max_relative = 1e-10);
+    assert!(shifted.equimolar_dividing_surface()?.to_reduced().abs() < 1e-10);
+    let h = 20.0 * ANGSTROM;
+    assert_relative_eq!(
+        interface.interfacial_moles_at(z_s, h),
+        shifted.interfacial_moles(h),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        interface.adsorption_at_dividing_surface(z_s),
+        shifted.adsorption_at_dividing_surface(0.0 * ANGSTROM),
+        max_relative = 1e-8
+    );
+
+    // the same profile with the vapor phase at the beginning of the domain
+    let mut inverted = interface.clone();
+    inverted.profile.density = Density::from_reduced(
+        interface
+            .profile
+            .density
+            .to_reduced()
+            .slice(s![.., ..;-1])
+            .to_owned(),
+    );
+    let inverted = inverted.shift_equimolar()?;
+    let ze_inverted = z0 - inverted.profile.grid.grids()[0][0];
+    assert_relative_eq!(ze + ze_inverted, w.to_reduced(), max_relative = 1e-10);
+
+    // no interface between bulk plateaus
+    let mut homogeneous = interface.clone();
+    homogeneous.profile.density = Density::from_reduced(Array2::from_elem(
+        interface.profile.density.raw_dim(),
+        vle.vapor().density.to_reduced(),
+    ));
+    assert!(homogeneous.equimolar_dividing_surface().is_err());
+    assert!(homogeneous.shift_equimolar().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_simpson() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 100.0 * ANGSTROM;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    for n in [64, 65] {
+        let interface = PlanarInterface::from_tanh(&vle, n, w, 370.0 * KELVIN, false);
+        let profile = &interface.profile;
+        let z = Dimensionless::from_reduced(profile.grid.grids()[0].mapv(|z| z * z));
+        let exact = w.powi::<P3>() / 3.0;
+        let simpson = profile.integrate_quadrature(&z, Quadrature::Simpson)?;
+        let midpoint = profile.integrate_quadrature(&z, Quadrature::Midpoint)?;
+        assert_relative_eq!(simpson, exact, max_relative = 1e-12);
+        assert_eq!(midpoint, profile.integrate(&z));
+        assert!((midpoint - exact).abs() > 1e-5 * exact);
+
+        // both rules agree for the grand potential density at the interface
+        let omega = profile.grand_potential_density()?;
+        assert_relative_eq!(
+            profile.integrate_quadrature(&omega, Quadrature::Simpson)?,
+            profile.integrate(&omega),
+            max_relative = 1e-3
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_periodic() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let planar = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, tc, false).solve(None)?;
+    let slab =
+        PlanarInterface::from_tanh_periodic(&vle, 2048, 200.0 * ANGSTROM, tc, false).solve(None)?;
+    let mirrored = PlanarInterface::new_periodic(&vle, 2048, 200.0 * ANGSTROM)
+        .set_density(&planar.mirrored_density()?, false)?;
+    assert_relative_eq!(
+        mirrored.profile.density,
+        slab.profile.density,
+        max_relative = 1e-8
+    );
+    assert!(slab.mirrored_density().is_err());
+    assert_relative_eq!(
+        slab.surface_tension.unwrap(),
+        planar.surface_tension.unwrap(),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        slab.interfacial_thickness()?,
+        planar.interfacial_thickness()?,
+        max_relative = 1e-8
+    );
+    let (w, z0) = planar.fit_tanh_width()?;
+    let (w_slab, z0_slab) = slab.fit_tanh_width()?;
+    assert_relative_eq!(w, w_slab, max_relative = 1e-8);
+    assert_relative_eq!(z0, z0_slab, max_relative = 1e-8);
+    assert_relative_eq!(z0, planar.equimolar_radius.unwrap(), max_relative = 1e-3);
+    assert_relative_eq!(
+        slab.interfacial_moles(20.0 * ANGSTROM),
+        2.0 * planar.interfacial_moles(20.0 * ANGSTROM),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        slab.interfacial_moles(100.0 * ANGSTROM),
+        slab.profile.moles(),
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        w,
+        planar.interfacial_thickness()? / (2.0 * 0.8f64.atanh()),
+        max_relative = 5e-2
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_gibbs_adsorption() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let t = 250.0 * KELVIN;
+    let vles = [0.3, 0.4, 0.5, 0.6]
+        .into_iter()
+        .map(|x| {
+            PhaseEquilibrium::bubble_point(
+                &&func,
+                t,
+                &dvector![x, 1.0 - x],
+                None,
+                None,
+                Default::default(),
+            )
+        })
+        .collect::<FeosResult<Vec<_>>>()?;
+    let mut dia = SurfaceTensionDiagram::new(
+        &vles,
+        None,
+        Some(1024),
+        None,
+        Some(400.0 * KELVIN),
+        None,
+        None,
+    );
+    let gamma = dia.surface_tension();
+    let contributions = dia.gibbs_adsorption_contributions(1)?;
+    assert_eq!(contributions.get((0, 1)), 0.0 * NEWTON / METER);
+    for k in 0..vles.len() - 1 {
+        assert_relative_eq!(
+            contributions.get((k, 0)) + contributions.get((k, 1)),
+            gamma.get(k + 1) - gamma.get(k),
+            max_relative = 1e-2
+        );
+    }
+    let residual = dia.gibbs_adsorption_residual(1)?;
+    assert_eq!(residual.len(), vles.len() - 1);
+    for k in 0..vles.len() - 1 {
+        assert!(residual.get(k).abs() < 1e-2 * (gamma.get(k + 1) - gamma.get(k)).abs());
+    }
+
+    // Gibbs elasticity from finite differences of the surface tension
+    let elasticity = dia.gibbs_elasticity(0, 1)?;
+    let adsorption = dia.relative_adsorption();
+    let ln_gamma_0 = |k: usize| adsorption[k].get((0, 1)).to_reduced().ln();
+    let n = vles.len() - 1;
+    assert_eq!(elasticity.len(), vles.len());
+    assert_relative_eq!(
+        elasticity.get(0),
+        -(gamma.get(1) - gamma.get(0)) / (ln_gamma_0(1) - ln_gamma_0(0)),
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        elasticity.get(n),
+        -(gamma.get(n) - gamma.get(n - 1)) / (ln_gamma_0(n) - ln_gamma_0(n - 1)),
+        max_relative = 1e-12
+    );
+    assert!(dia.gibbs_elasticity(1, 1).is_err());
+
+    // the surface tension of propane/butane does not level off
+    assert_eq!(dia.saturation_concentration(0, 0.1)?, None);
+    assert!(dia.saturation_concentration(2, 0.1).is_err());
+
+    // the relative adsorption is independent of the dividing surface
+    let interface = &dia.profiles[0];
+    let relative_adsorption = interface.relative_adsorption();
+    let (rho_l, rho_v) = interface.boundary_densities();
+    let ratio = (rho_l.get(0) - rho_v.get(0)) / (rho_l.get(1) - rho_v.get(1));
+    for z in [40.0 * ANGSTROM, 60.0 * ANGSTROM] {
+        let adsorption = interface.adsorption_at_dividing_surface(z);
+        assert_relative_eq!(
+            adsorption.get(0) - adsorption.get(1) * ratio,
+            relative_adsorption.get((0, 1)),
+            max_relative = 1e-8
+        );
+    }
+
+    // the segment-weighted adsorption vanishes at the equimolar dividing surface
+    let m = interface.profile.bulk.eos.m();
+    let adsorption = interface.adsorption_at_dividing_surface(interface.equimolar_radius.unwrap());
+    assert!(
+        (adsorption.get(0) * m[0] + adsorption.get(1) * m[1]).abs()
+            < 1e-8 * interface.profile.total_moles()
+    );
+
+    // local mole fractions approach the bulk compositions
+    let x = interface.profile.composition_profile();
+    let n = x.shape()[1];
+    assert_relative_eq!(x[(0, 0)], 0.3, max_relative = 1e-4);
+    assert_relative_eq!(
+        x[(0, n - 1)],
+        vles[0].vapor().molefracs[0],
+        max_relative = 1e-4
+    );
+    for s in x.sum_axis(Axis(0)) {
+        assert_relative_eq!(s, 1.0, max_relative = 1e-12);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_dissolved_gas_surface_tension() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let vle = PhaseEquilibrium::bubble_point(
+        &&func,
+        250.0 * KELVIN,
+        &dvector![0.3, 0.7],
+        None,
+        None,
+        Default::default(),
+    )?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 400.0 * KELVIN, true)
+        .solve(None)?;
+
+    // propane at fixed chemical potential, butane at fixed number of moles
+    let open = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .fix_chemical_potentials(&[0])?;
+    let butane = open.profile.moles().get(1);
+    let open = open.solve(None)?;
+    assert_relative_eq!(
+        open.surface_tension.unwrap(),
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-4
+    );
+    assert_relative_eq!(open.profile.moles().get(1), butane, max_relative = 1e-6);
+    assert!(open.profile.mass_conservation_residual().unwrap() < 1e-6);
+    let (rho_l, _) = open.boundary_densities();
+    assert_relative_eq!(
+        rho_l.get(0),
+        vle.liquid().partial_density.get(0),
+        max_relative = 1e-4
+    );
+
+    assert!(
+        PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+            .fix_chemical_potentials(&[2])
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_surface_tension_direct() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface =
+        PlanarInterface::from_tanh(&vle, 2048, 150.0 * ANGSTROM, tc, false).solve(None)?;
+    assert_relative_eq!(
+        interface::surface_tension(&&func, t, None)?,
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-3
+    );
+
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let mixture = PcSaftFunctional::new(params);
+    assert!(interface::surface_tension(&&mixture, t, None).is_err());
+
+    // inverse problem: temperature at a given surface tension
+    let gamma = interface::surface_tension(&&func, t, None)?;
+    let bracket = (t + 20.0 * KELVIN, t - 20.0 * KELVIN);
+    assert_relative_eq!(
+        interface::temperature_for_surface_tension(&&func, gamma, bracket, None)?,
+        t,
+        max_relative = 1e-6
+    );
+    assert!(
+        interface::temperature_for_surface_tension(&&func, 2.0 * gamma, bracket, None).is_err()
+    );
+    assert!(
+        interface::temperature_for_surface_tension(&&func, 0.0 * gamma, bracket, None).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_metastable_interface() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 100.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 512, w, tc, true).solve(None)?;
+    assert!(interface.metastability_warning().is_none());
+
+    // coexisting states reproduce the equilibrium interface
+    let coexisting =
+        PlanarInterface::from_states(vle.vapor(), vle.liquid(), 512, w, tc, true)?.solve(None)?;
+    assert!(coexisting.metastability_warning().is_none());
+    assert_relative_eq!(
+        coexisting.surface_tension.unwrap(),
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-10
+    );
+
+    // superheated liquid below the saturation pressure
+    let p = 0.5 * vle.vapor().pressure(Contributions::Total);
+    let liquid = State::new_xpt(
+        &&func,
+        t,
+        p,
+        &dvector![1.0],
+        Some(DensityInitialization::Liquid),
+    )?;
+    let metastable =
+        PlanarInterface::from_states(vle.vapor(), &liquid, 512, w, tc, true)?.solve(None)?;
+    assert!(metastable.metastability_warning().is_some());
+    let gamma = metastable.surface_tension.unwrap();
+    assert_relative_eq!(
+        gamma,
+        interface.surface_tension.unwrap(),
+        max_relative = 5e-2
+    );
+
+    let cold = State::new_xpt(
+        &&func,
+        190.0 * KELVIN,
+        p,
+        &dvector![1.0],
+        Some(DensityInitialization::Liquid),
+    )?;
+    assert!(PlanarInterface::from_states(vle.vapor(), &cold, 512, w, tc, true).is_err());
+    assert!(PlanarInterface::from_states(vle.liquid(), vle.vapor(), 512, w, tc, true).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_surface_tension_uncertainty() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let init = PlanarInterface::from_tanh(&vle, 512, w, tc, false);
+    let converged = init.clone().solve(None)?;
+    let gamma = converged.surface_tension.unwrap();
+    for tol in [1e-4, 1e-6] {
+        let solver = DFTSolver::new(None).picard_iteration(None, None, Some(tol), None);
+        let interface = init.clone().solve(Some(&solver))?;
+        let error = (interface.surface_tension.unwrap() - gamma).abs();
+        assert_relative_eq!(
+            interface.convergence_error(Some(&solver))?,
+            error,
+            max_relative = 0.5
+        );
+    }
+    assert!(converged.convergence_error(None)? < 1e-8 * gamma);
+
+    let (gamma, error) = init.surface_tension_with_uncertainty(None)?;
+    let (gamma_richardson, error_richardson) = init.richardson_extrapolation(None)?;
+    assert_relative_eq!(gamma, gamma_richardson, max_relative = 1e-12);
+    assert!(error >= error_richardson);
+    assert!(error < 1e-8 * gamma);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_richardson() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let (gamma, error) =
+        PlanarInterface::from_tanh(&vle, 128, w, tc, false).richardson_extrapolation(None)?;
+    let fine = PlanarInterface::from_tanh(&vle, 4096, w, tc, false).solve(None)?;
+    assert_relative_eq!(gamma, fine.surface_tension.unwrap(), max_relative = 1e-7);
+    assert!(error < 1e-6 * gamma);
+
+    let bulk = State::new_npt(
+        &&func,
+        t,
+        0.5 * vle.vapor().pressure(Contributions::Total),
+        &(dvector![1.0] * MOL),
+        None,
+    )?;
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(256));
+    let (omega, error) = pore.richardson_extrapolation(&bulk, None)?;
+    let fine = Pore1D {
+        n_grid: Some(2048),
+        ..pore.clone()
+    }
+    .initialize(&bulk, InitialGuess::Bulk, None)?
+    .solve(None)?;
+    assert_relative_eq!(
+        (omega / fine.grand_potential.unwrap()).into_value(),
+        1.0,
+        max_relative = 1e-7
+    );
+    assert!(error < 1e-5 * omega.abs());
+
+    assert_relative_eq!(
+        fine.excess_grand_potential()?,
+        fine.interfacial_tension.unwrap(),
+        max_relative = 1e-10
+    );
+    let p = bulk.pressure(Contributions::Total);
+    assert_relative_eq!(
+        fine.excess_gibbs_energy(&pore)?,
+        fine.interfacial_tension.unwrap() + p * (pore.pore_volume()? - fine.profile.volume()),
+        max_relative = 1e-8
+    );
+
+    // bulk state from the fugacity
+    let phi = bulk.ln_phi().map(f64::exp).component_mul(&bulk.molefracs);
+    let fugacity = Pressure::from_reduced(phi * p.to_reduced());
+    let profile = Pore1D {
+        n_grid: Some(2048),
+        ..pore.clone()
+    }
+    .initialize_fugacity(&&func, t, &fugacity, InitialGuess::Bulk, None)?;
+    assert_relative_eq!(
+        profile.profile.bulk.density,
+        bulk.density,
+        max_relative = 1e-10
+    );
+    // both profiles are converged independently from slightly different
+    // bulk states, their grand potentials agree to about 1e-7
+    assert_relative_eq!(
+        (profile.solve(None)?.grand_potential.unwrap() / fine.grand_potential.unwrap())
+            .into_value(),
+        1.0,
+        max_relative = 1e-7
+    );
+    assert!(
+        pore.initialize_fugacity(&&func, t, &(fugacity * 0.0), InitialGuess::Bulk, None)
+            .is_err()
+    );
+
+    // excess with respect to an ideal gas at the same chemical potential
+    let mu_res = bulk.residual_chemical_potential().get(0) / (RGAS * t);
+    let rho_ig = bulk.density * mu_res.into_value().exp();
+    assert!(rho_ig < bulk.density);
+    assert_relative_eq!(
+        ((fine.ideal_excess_moles(&pore)?.get(0) - fine.excess_moles(&pore)?.get(0))
+            / ((bulk.density - rho_ig) * pore.pore_volume()?))
+        .into_value(),
+        1.0,
+        max_relative = 1e-8
+    );
+
+    let p_t = fine.tangential_pressure_profile()?;
+    assert_relative_eq!(
+        (-fine.profile.integrate(&p_t) / fine.grand_potential.unwrap()).into_value(),
+        1.0,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_meniscus_radius() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 280.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let bulk = State::new_npt(
+        &&func,
+        t,
+        0.95 * p_sat,
+        &(dvector![1.0] * MOL),
+        Some(DensityInitialization::Vapor),
+    )?;
+    let radius = 40.0 * ANGSTROM;
+    let pore = Pore1D::new(
+        Geometry::Cylindrical,
+        radius,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 30.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(512),
+        None,
+    );
+    let solver = DFTSolver::new(None)
+        .picard_iteration(None, Some(2000), Some(1e-3), Some(0.01))
+        .anderson_mixing(None, Some(1000), None, None, None);
+    let profile = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(Some(&solver))?;
+    let r_meniscus = profile.meniscus_radius(&vle)?.unwrap();
+    assert!(r_meniscus > 30.0 * ANGSTROM && r_meniscus < radius);
+
+    let slit = Pore1D {
+        geometry: Geometry::Cartesian,
+        ..pore
+    };
+    assert!(
+        slit.initialize(&bulk, InitialGuess::Bulk, None)?
+            .meniscus_radius(&vle)
+            .is_err()
+    );
+
+    // coarse grids are reported by default and rejected if the maximum spacing is set
+    let coarse = Pore1D {
+        n_grid: Some(8),
+        ..slit.clone()
+    };
+    assert!(
+        slit.initialize(&bulk, InitialGuess::Bulk, None)?
+            .grid_spacing_warning
+            .is_none()
+    );
+    assert!(
+        coarse
+            .initialize(&bulk, InitialGuess::Bulk, None)?
+            .grid_spacing_warning
+            .is_some()
+    );
+    assert!(
+        coarse
+            .clone()
+            .max_grid_spacing(0.5)
+            .initialize(&bulk, InitialGuess::Bulk, None)
+            .is_err()
+    );
+    assert!(
+        coarse
+            .max_grid_spacing(f64::INFINITY)
+            .initialize(&bulk, InitialGuess::Bulk, None)?
+            .grid_spacing_warning
+            .is_none()
+    );
+
+    // the pore volume is calculated with helium, which is smaller than propane
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(11)).max_grid_spacing(0.5);
+    assert!(pore.initialize(&bulk, InitialGuess::Bulk, None).is_ok());
+    assert!(pore.pore_volume().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_asymmetric_pore() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let bulk = State::new_npt(
+        &&func,
+        300.0 * KELVIN,
+        1.0 * BAR,
+        &(dvector![1.0] * MOL),
+        None,
+    )?;
+    let potential = lj93_wall();
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        potential.clone(),
+        Some(256),
+        None,
+    );
+    let symmetric = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let full = Pore1D {
+        n_grid: Some(512),
+        ..pore.clone()
+    }
+    .right_wall_potential(potential)
+    .initialize(&bulk, InitialGuess::Bulk, None)?
+    .solve(None)?;
+    // the full pore and the symmetric half pore are solved independently,
+    // their density profiles agree to about 1e-6
+    assert_relative_eq!(
+        (full.grand_potential.unwrap() / symmetric.grand_potential.unwrap()).into_value(),
+        2.0,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        full.center_density()?,
+        symmetric.center_density()?,
+        max_relative = 1e-6
+    );
+
+    let asymmetric = pore
+        .right_wall_potential(ExternalPotential::LJ93 {
+            epsilon_k_ss: 50.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        })
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    // the density peak is located at the more attractive right wall
+    let (rho, _) = asymmetric.profile.density_array();
+    let (i_max, _) = rho
+        .row(0)
+        .indexed_iter()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .unwrap();
+    assert!(asymmetric.profile.z().get(i_max) > 0.0 * ANGSTROM);
+    assert!(asymmetric.grand_potential.unwrap() < full.grand_potential.unwrap());
+    Ok(())
+}
+
+#[test]
+fn test_dft_frozen_components() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let bulk = State::new_npt(
+        &&func,
+        300.0 * KELVIN,
+        1.0 * BAR,
+        &(dvector![0.5, 0.5] * MOL),
+        Some(DensityInitialization::Vapor),
+    )?;
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(256));
+    let profile = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let butane = Density::from_reduced(profile.profile.density_array().0.slice_move(s![1..2, ..]));
+
+    // freezing a component at its equilibrium profile does not change the solution
+    let frozen = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .freeze_components(&[1], &butane)?
+        .solve(None)?;
+    assert_eq!(frozen.profile.frozen_components, [1]);
+    assert_relative_eq!(
+        frozen.profile.density,
+        profile.profile.density,
+        max_relative = 1e-6
+    );
+
+    // the remaining components equilibrate in the fixed background
+    let background = butane.clone() * 0.5;
+    let frozen = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .freeze_components(&[1], &background)?
+        .solve(Some(&DFTSolver::default().newton(None, None, None, None)))?;
+    assert_relative_eq!(
+        frozen.profile.density.index_axis(Axis(0), 1),
+        background.index_axis(Axis(0), 0),
+        max_relative = 1e-14
+    );
+    assert!(
+        frozen
+            .profile
+            .residual(false)?
+            .0
+            .iter()
+            .all(|r| r.abs() < 1e-8)
+    );
+    assert!(frozen.profile.density.get((0, 128)) > profile.profile.density.get((0, 128)));
+    assert!(
+        pore.initialize(&bulk, InitialGuess::Bulk, None)?
+            .freeze_components(&[2], &butane)
+            .is_err()
+    );
+    assert!(
+        pore.initialize(&bulk, InitialGuess::Bulk, None)?
+            .freeze_components(&[0, 1], &butane)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_layering() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let pore = lj93_pore(40.0 * ANGSTROM, Some(512));
+    let liquid = pore
+        .initialize(vle.liquid(), InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let (wavelength, layers) = liquid.layering_wavelength(0.01).unwrap();
+    let sigma = func.sigma_ff()[0] * ANGSTROM;
+    assert!(wavelength > 0.8 * sigma && wavelength < 1.2 * sigma);
+    assert!(layers >= 2);
+
+    // the dilute vapor does not form layers
+    let vapor = pore
+        .initialize(vle.vapor(), InitialGuess::Bulk, None)?
+        .solve(None)?;
+    assert!(vapor.layering_wavelength(0.01).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_dft_methane_insertion_free_energy() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("methane")?);
+    let t = 120.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        40.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 100.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(512),
+        None,
+    );
+    let liquid = pore
+        .initialize(vle.liquid(), InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let w = liquid.insertion_free_energy();
+    let dmu = liquid.excess_chemical_potential_difference()?;
+    let kt = (KB * t).to_reduced();
+    let w = w.to_reduced();
+    let dmu = dmu.to_reduced();
+    let finite: Vec<_> = (0..w.len()).filter(|&i| w[(0, i)].is_finite()).collect();
+    assert!(finite.len() > w.len() / 2);
+    for &i in &finite {
+        assert!((w[(0, i)] - dmu[(0, i)]).abs() < 1e-6 * kt);
+    }
+
+    // the insertion free energy varies strongly close to the walls
+    let (min, max) = finite
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), &i| {
+            (a.min(w[(0, i)]), b.max(w[(0, i)]))
+        });
+    assert!(max - min > kt);
+    Ok(())
+}
+
+#[test]
+fn test_dft_methane_closed_pore() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("methane")?);
+    let t = 300.0 * KELVIN;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 100.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(256),
+        None,
+    );
+    let bulk = |p| State::new_xpt(&&func, t, p, &dvector![1.0], None);
+    let open = pore
+        .initialize(&bulk(50.0 * BAR)?, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let moles = open.profile.moles();
+    assert!(
+        pore.initialize(&bulk(50.0 * BAR)?, InitialGuess::Bulk, None)?
+            .solve_with_tolerance(None, None, Some(1))
+            .is_err()
+    );
+
+    // the average density in the pore is consistent with the excess adsorption
+    let rho_ads = open.adsorbed_phase_density(&pore)?;
+    let excess = open.excess_moles(&pore)?;
+    assert_relative_eq!(
+        rho_ads.get(0) - open.profile.bulk.density,
+        excess.get(0) / pore.pore_volume()?,
+        max_relative = 1e-10
+    );
+
+    // the closed pore with the same loading is in equilibrium with the same bulk
+    let mut closed = pore
+        .initialize(&bulk(10.0 * BAR)?, InitialGuess::Bulk, None)?
+        .fix_moles(&moles)?
+        .solve(None)?;
+    assert_relative_eq!(
+        closed.profile.moles().get(0),
+        moles.get(0),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        closed.profile.bulk.pressure(Contributions::Total),
+        50.0 * BAR,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        closed.grand_potential.unwrap(),
+        open.grand_potential.unwrap(),
+        max_relative = 1e-6
+    );
+
+    // an empty density profile cannot be scaled to the loading
+    let mut empty = closed.clone();
+    empty.profile.density *= 0.0;
+    assert!(empty.fix_moles_inplace(&moles).is_err());
+
+    assert!(closed.fix_moles_inplace(&(moles * 0.0)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_isosteric_heat() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(256));
+    let t = 300.0 * KELVIN;
+    let dt = 1.0 * KELVIN;
+    let pressure = Pressure::linspace(0.1 * BAR, 2.0 * BAR, 20);
+    let isotherm = Adsorption1D::adsorption_isotherm(&&func, t, &pressure, &pore, &None, None)?;
+    let isotherm_dt =
+        Adsorption1D::adsorption_isotherm(&&func, t + dt, &pressure, &pore, &None, None)?;
+    let (loading, heat) = isotherm.isosteric_heat(&isotherm_dt)?;
+    let h_ads = isotherm.enthalpy_of_adsorption();
+    assert_relative_eq!(loading, isotherm.total_adsorption());
+    for i in 0..pressure.len() - 1 {
+        assert_relative_eq!(heat.get(i), h_ads.get(i), max_relative = 2e-3);
+    }
+    assert!(heat.get(pressure.len() - 1).is_nan());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_stress() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 300.0 * KELVIN;
+    let bulk = State::new_npt(&&func, t, 5.0 * BAR, &(dvector![1.0] * MOL), None)?;
+    let pore = |width: Length| {
+        Pore1D::new(
+            Geometry::Cartesian,
+            width,
+            ExternalPotential::HardWall { sigma_ss: 3.0 },
+            Some(4096),
+            None,
+        )
+    };
+    let width = 10.0 * ANGSTROM;
+    let profile = pore(width)
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let (p_n, p_t) = profile.stress_profile()?;
+    assert_eq!(p_t, profile.tangential_pressure_profile()?);
+
+    // the normal pressure is constant inside the pore and vanishes inside the wall
+    let p_0 = p_n.get(0);
+    let n = p_n.len();
+    for (k, &v) in profile.external_potential().row(0).iter().enumerate() {
+        if v == 0.0 {
+            assert_relative_eq!((p_n.get(k) / p_0).into_value(), 1.0, max_relative = 1e-6);
+        }
+    }
+    assert!((p_n.get(n - 1) / p_0).into_value().abs() < 1e-10);
+
+    // the force on the walls is the derivative of the grand potential
+    // with respect to the pore width (the domain contains half of the pore)
+    let delta = 0.2 * ANGSTROM;
+    let omega = |width: Length| -> FeosResult<Energy> {
+        Ok(pore(width)
+            .initialize(&bulk, InitialGuess::Bulk, None)?
+            .solve(None)?
+            .grand_potential
+            .unwrap())
+    };
+    let force = -(omega(width + delta)? - omega(width - delta)?) / delta / Area::from_reduced(1.0);
+    assert_relative_eq!((p_0 / force).into_value(), 1.0, max_relative = 1e-2);
+
+    // the solvation force is the derivative of the potential of mean force,
+    // which is twice the excess grand potential of one half of the pore
+    let pmf = |width: Length| -> FeosResult<Energy> {
+        Ok(2.0
+            * pore(width)
+                .initialize(&bulk, InitialGuess::Bulk, None)?
+                .solve(None)?
+                .excess_grand_potential()?)
+    };
+    let p = bulk.pressure(Contributions::Total);
+    let solvation_force =
+        -(pmf(width + delta)? - pmf(width - delta)?) / (2.0 * delta) / Area::from_reduced(1.0);
+    assert_relative_eq!(
+        solvation_force.to_reduced(),
+        (force - p).to_reduced(),
+        epsilon = 1e-8 * p.to_reduced()
+    );
+
+    let cylinder = Pore1D::new(
+        Geometry::Cylindrical,
+        width,
+        ExternalPotential::HardWall { sigma_ss: 3.0 },
+        Some(256),
+        None,
+    );
+    assert!(
+        cylinder
+            .initialize(&bulk, InitialGuess::Bulk, None)?
+            .solve(None)?
+            .stress_profile()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_depletion_thickness() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 300.0 * KELVIN;
+    let bulk = State::new_npt(&&func, t, 0.1 * BAR, &(dvector![1.0] * MOL), None)?;
+    let wall = Wall1D::new(
+        20.0 * ANGSTROM,
+        ExternalPotential::HardWall { sigma_ss: 3.0 },
+        Some(4096),
+        None,
+    );
+    let profile = wall
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+
+    // the fluid is depleted at the repulsive wall
+    let excess = profile.excess_moles(&wall)?;
+    assert!(excess.get(0).to_reduced() < 0.0);
+    let thickness = profile.depletion_thickness(&wall)?;
+    assert_relative_eq!(
+        (thickness.get(0) * bulk.density * Area::from_reduced(1.0)).to_reduced(),
+        -excess.get(0).to_reduced(),
+        max_relative = 1e-12
+    );
+
+    // at low densities, the depletion of spherical molecules is determined by
+    // the difference of the contact distances of the fluid and helium at the
+    // wall, the chain connectivity of propane increases the depletion
+    let sigma_he = 2.64;
+    assert!(thickness.get(0).to_reduced() > 0.5 * (func.sigma_ff()[0] - sigma_he));
+    let methane = PcSaftFunctional::new(parameters("methane")?);
+    let bulk_methane = State::new_npt(&&methane, t, 0.1 * BAR, &(dvector![1.0] * MOL), None)?;
+    let thickness = wall
+        .initialize(&bulk_methane, InitialGuess::Bulk, None)?
+        .solve(None)?
+        .depletion_thickness(&wall)?;
+    assert_relative_eq!(
+        thickness.get(0).to_reduced(),
+        0.5 * (methane.sigma_ff()[0] - sigma_he),
+        max_relative = 1e-2
+    );
+
+    // attractive walls result in a negative depletion thickness
+    let attractive = Wall1D::new(
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 5.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(4096),
+        None,
+    );
+    let profile = attractive
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    assert!(
+        profile
+            .depletion_thickness(&attractive)?
+            .get(0)
+            .to_reduced()
+            < 0.0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_disjoining_pressure() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 300.0 * KELVIN;
+    let bulk = State::new_npt(&&func, t, 50.0 * BAR, &(dvector![1.0] * MOL), None)?;
+    let solver = DFTSolver::new(None).newton(None, None, None, None);
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        10.0 * ANGSTROM,
+        ExternalPotential::HardWall { sigma_ss: 3.0 },
+        Some(1024),
+        None,
+    );
+    let h = Length::linspace(8.0 * ANGSTROM, 16.0 * ANGSTROM, 17);
+    let isotherm = pore.disjoining_pressure_isotherm(&bulk, &h, Some(&solver))?;
+
+    // the disjoining pressure is the difference of the normal pressure in the
+    // film and the bulk pressure, the hard walls are dried by the compressed
+    // liquid, so the disjoining pressure is negative
+    let p = bulk.pressure(Contributions::Total);
+    let profile = Pore1D {
+        pore_size: h.get(4),
+        ..pore.clone()
+    }
+    .initialize(&bulk, InitialGuess::Bulk, None)?
+    .solve(Some(&solver))?;
+    let (p_n, _) = profile.stress_profile()?;
+    assert!(isotherm.disjoining_pressure.get(4) < 0.0 * BAR);
+    assert_relative_eq!(
+        isotherm.disjoining_pressure.get(4),
+        p_n.get(0) - p,
+        max_relative = 2e-2
+    );
+    assert_relative_eq!(
+        isotherm.excess_grand_potential.get(4).to_reduced(),
+        2.0 * profile.excess_grand_potential()?.to_reduced(),
+        max_relative = 1e-6
+    );
+
+    // the solvation force of a liquid film between attractive walls
+    // oscillates with the film thickness
+    let attractive = Pore1D::new(
+        Geometry::Cartesian,
+        10.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: 28.0,
+            rho_s: 0.114,
+        },
+        Some(1024),
+        None,
+    );
+    let stable = attractive
+        .disjoining_pressure_isotherm(&bulk, &h, Some(&solver))?
+        .is_stable();
+    assert!(stable.iter().any(|&s| s) && stable.iter().any(|&s| !s));
+
+    // only symmetric slit pores are supported
+    let asymmetric = pore
+        .clone()
+        .right_wall_potential(ExternalPotential::HardWall { sigma_ss: 2.0 });
+    assert!(
+        asymmetric
+            .disjoining_pressure_isotherm(&bulk, &h, None)
+            .is_err()
+    );
+    let descending = Length::linspace(16.0 * ANGSTROM, 8.0 * ANGSTROM, 3);
+    assert!(
+        pore.disjoining_pressure_isotherm(&bulk, &descending, None)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_heat_capacity() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("methane")?);
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 3.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(256),
+        None,
+    );
+    let t = 300.0 * KELVIN;
+    let bulk = State::new_npt(&&func, t, 0.1 * BAR, &(dvector![1.0] * MOL), None)?;
+    let solver = DFTSolver::default().tolerance(1e-13);
+    let profile = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(Some(&solver))?;
+    let c_v = profile.excess_isochoric_heat_capacity(&pore, 5.0 * KELVIN, Some(&solver))?;
+
+    // dilute gas in an external potential: C_V = N R Var(V/kT)
+    let rho = profile.profile.density.to_reduced().row(0).to_owned();
+    let v = profile.external_potential().row(0).to_owned();
+    let n = rho.sum();
+    let mean = (&rho * &v).sum() / n;
+    let var = (&rho * &v.mapv(|v| (v - mean).powi(2))).sum() / n;
+    let c_v_ig = RGAS * profile.profile.total_moles() * var;
+    assert_relative_eq!((c_v / c_v_ig).into_value(), 1.0, max_relative = 1e-3);
+
+    // failing solver
+    let solver = DFTSolver::new(None).picard_iteration(None, Some(1), Some(1e-12), None);
+    assert!(
+        profile
+            .excess_isochoric_heat_capacity(&pore, 1.0 * KELVIN, Some(&solver))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_initial_guess() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(512));
+    let liquid = State::new_npt(
+        &&func,
+        t,
+        0.95 * p_sat,
+        &(dvector![1.0] * MOL),
+        Some(DensityInitialization::Liquid),
+    )?;
+
+    // the empty pore is initialized with the ideal gas at the fugacity of the bulk
+    let bulk = pore.initialize(&liquid, InitialGuess::Bulk, None)?;
+    let empty = pore.initialize(&liquid, InitialGuess::Empty, None)?;
+    let mu_res = (liquid.residual_chemical_potential().get(0) / (RGAS * t)).into_value();
+    assert_relative_eq!(
+        empty.profile.density.to_reduced(),
+        bulk.profile.density.to_reduced() * mu_res.exp(),
+        max_relative = 1e-12
+    );
+
+    // the initial guess selects the filled or the empty branch
+    let filled = bulk.solve(None)?;
+    let empty = empty.solve(None)?;
+    let n_filled = filled.profile.total_moles();
+    let n_empty = empty.profile.total_moles();
+    assert!(n_filled > 10.0 * n_empty);
+
+    // an explicit initial guess reproduces the respective branch
+    let explicit = pore
+        .initialize(
+            &liquid,
+            InitialGuess::Explicit(&empty.profile.density),
+            None,
+        )?
+        .solve(None)?;
+    assert_relative_eq!(
+        (explicit.profile.total_moles() / n_empty).into_value(),
+        1.0,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_spinodal() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(512));
+    let pressure = Pressure::linspace(0.3 * p_sat, 0.99 * p_sat, 24);
+    let adsorption = Adsorption1D::adsorption_isotherm(&&func, t, &pressure, &pore, &None, None)?;
+    let desorption = Adsorption1D::desorption_isotherm(&&func, t, &pressure, &pore, &None, None)?;
+    assert!(adsorption.spinodal(0.3).is_none());
+    let relative_pressure = Array1::linspace(0.3, 0.99, 24);
+    let relative =
+        Adsorption1D::adsorption_isotherm_relative(&&func, t, &relative_pressure, &pore, None)?;
+    assert_relative_eq!(
+        relative.relative_pressure()?,
+        relative_pressure,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        relative.total_adsorption(),
+        adsorption.total_adsorption(),
+        max_relative = 1e-6
+    );
+    let (p0, p1) = desorption.spinodal(0.3).unwrap();
+    assert!(p0 < p1 && p0 > 0.3 * p_sat && p1 < 0.99 * p_sat);
+    let loading = desorption.total_adsorption();
+    let i = (0..pressure.len())
+        .position(|i| pressure.get(i) >= p1)
+        .unwrap();
+    assert!(loading.get(i) > 10.0 * loading.get(i - 1));
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_pore_size_distribution() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 300.0 * KELVIN;
+    let pressure = Pressure::linspace(0.1 * BAR, 2.0 * BAR, 8);
+    let widths = [15.0, 20.0, 30.0];
+    let mut kernel = Array2::zeros((widths.len(), pressure.len()));
+    for (mut row, &w) in kernel.axis_iter_mut(Axis(0)).zip(widths.iter()) {
+        let pore = lj93_pore(w * ANGSTROM, Some(256));
+        let isotherm = Adsorption1D::adsorption_isotherm(&&func, t, &pressure, &pore, &None, None)?;
+        row.assign(&isotherm.total_adsorption().to_reduced());
+    }
+    let distribution = arr1(&[2.0, 0.0, 0.5]);
+    let loading = Moles::from_reduced(distribution.dot(&kernel));
+    let kernel = Moles::from_reduced(kernel);
+
+    let psd = pore_size_distribution(&kernel, &loading, 0.0)?;
+    assert_relative_eq!(psd, distribution, epsilon = 1e-6);
+    let psd_regularized = pore_size_distribution(&kernel, &loading, 1e-2)?;
+    assert!(psd_regularized.iter().all(|&f| f >= 0.0));
+    assert!(pore_size_distribution(&kernel, &loading, -1.0).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_parallel() -> Result<(), Box<dyn Error>> {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<PlanarInterface<&PcSaftFunctional>>();
+    assert_send_sync::<PoreProfile1D<&PcSaftFunctional>>();
+    assert_send_sync::<SurfaceTensionDiagram<&PcSaftFunctional>>();
+    assert_send_sync::<Adsorption1D<&PcSaftFunctional>>();
+    assert_send_sync::<DFTSolver>();
+
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let interface = |t: f64| -> FeosResult<_> {
+        let vle = PhaseEquilibrium::pure(&&func, t * KELVIN, None, Default::default())?;
+        PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, tc, false).solve(None)
+    };
+    let temperatures = [200.0, 230.0, 260.0];
+    let parallel = std::thread::scope(|s| {
+        let handles: Vec<_> = temperatures
+            .iter()
+            .map(|&t| s.spawn(move || interface(t)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<FeosResult<Vec<_>>>()
+    })?;
+    for (&t, p) in temperatures.iter().zip(parallel) {
+        assert_eq!(p.surface_tension, interface(t)?.surface_tension);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_coarse_convolution() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let exact = PlanarInterface::from_tanh(&vle, 2048, w, tc, false).solve(None)?;
+    for factor in [2, 4] {
+        let coarse = PlanarInterface::from_tanh(&vle, 2048, w, tc, false)
+            .coarsen_convolution(factor)?
+            .solve(None)?;
+        assert_relative_eq!(
+            coarse.surface_tension.unwrap(),
+            exact.surface_tension.unwrap(),
+            max_relative = 1e-3
+        );
+    }
+    assert!(
+        PlanarInterface::from_tanh(&vle, 2047, w, tc, false)
+            .coarsen_convolution(2)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_update_vle() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, w, tc, false).solve(None)?;
+    let updated = interface.clone().update_vle_from_profile()?;
+    assert_relative_eq!(
+        updated.vle.liquid().density,
+        vle.liquid().density,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        updated.vle.vapor().density,
+        vle.vapor().density,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        updated.surface_tension.unwrap(),
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_reuse_convolver() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, w, tc, false).solve(None)?;
+    let convolver = interface.profile.convolver.clone();
+    let reused = PlanarInterface::from_tanh_with_convolver(&vle, 1024, w, convolver, tc, false)
+        .solve(None)?;
+    assert_relative_eq!(
+        reused.surface_tension.unwrap(),
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_boundary_densities() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let w = 150.0 * ANGSTROM;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, w, tc, false).solve(None)?;
+    let (rho_l, rho_v) = interface.boundary_densities();
+    assert_relative_eq!(rho_l, vle.liquid().partial_density, max_relative = 1e-6);
+    assert_relative_eq!(rho_v, vle.vapor().partial_density, max_relative = 1e-6);
+    assert!(interface.boundary_is_bulk(1e-6));
+
+    let gamma_pdgt = interface.surface_tension_pdgt()?;
+    assert_relative_eq!(
+        gamma_pdgt,
+        PlanarInterface::pdgt_surface_tension(&vle)?,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        gamma_pdgt,
+        interface.surface_tension.unwrap(),
+        max_relative = 5e-2
+    );
+
+    let (omega, offset) = interface.surface_tension_contributions()?;
+    assert_relative_eq!(
+        omega + offset,
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        offset,
+        vle.vapor().pressure(Contributions::Total) * w,
+        max_relative = 1e-10
+    );
+
+    let gamma = interface.adsorption_at_dividing_surface(interface.equimolar_radius.unwrap());
+    assert!(gamma.get(0).abs() < 1e-8 * interface.profile.total_moles());
+
+    let h = 40.0 * ANGSTROM;
+    let area = interface.profile.volume() / w;
+    assert_relative_eq!(
+        interface.interfacial_moles(h).get(0),
+        (vle.liquid().density + vle.vapor().density) * 2.0 * h * area,
+        max_relative = 1e-5
+    );
+    assert_relative_eq!(
+        interface.interfacial_moles(w).get(0),
+        interface.profile.total_moles(),
+        max_relative = 1e-12
+    );
+
+    let (labels, table) = interface.profile_table(false)?;
+    assert_eq!(labels, ["z", "rho0", "rho", "omega"]);
+    assert_relative_eq!(table[(0, 1)], table[(0, 2)]);
+    assert_relative_eq!(
+        table[(0, 2)] * MOL / METER.powi::<P3>(),
+        vle.liquid().density,
+        max_relative = 1e-6
+    );
+    let (_, reduced) = interface.profile_table(true)?;
+    assert_relative_eq!(
+        table[(10, 0)],
+        reduced[(10, 0)] * 1e-10,
+        max_relative = 1e-12
+    );
+
+    let z = interface.profile.grid.grids()[0];
+    let rho = interface.profile.density.to_reduced();
+    let positions = arr1(&[
+        z[20],
+        0.5 * (z[10] + z[11]),
+        -1.0,
+        z[5],
+        w.convert_to(ANGSTROM) + 1.0,
+    ]);
+    let resampled = interface.resample(&(positions * ANGSTROM))?.to_reduced();
+    assert_relative_eq!(resampled[(0, 0)], rho[(0, 20)], max_relative = 1e-12);
+    assert_relative_eq!(
+        resampled[(0, 1)],
+        0.5 * (rho[(0, 10)] + rho[(0, 11)]),
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(resampled[(0, 3)], rho[(0, 5)], max_relative = 1e-12);
+    assert!(resampled[(0, 2)].is_nan() && resampled[(0, 4)].is_nan());
+    assert!(interface.resample(&(arr1(&[f64::NAN]) * ANGSTROM)).is_err());
+
+    // the density gradient integrates to the density difference of the bulk
+    // phases and is extremal close to the equimolar dividing surface
+    let gradient = interface.density_gradient()?;
+    let dz = (z[1] - z[0]) * ANGSTROM;
+    assert_relative_eq!(
+        gradient.sum() * dz,
+        vle.vapor().density - vle.liquid().density,
+        max_relative = 1e-2
+    );
+    let gradient = gradient.to_reduced();
+    let i_min = (0..z.len())
+        .min_by(|&i, &j| gradient[(0, i)].total_cmp(&gradient[(0, j)]))
+        .unwrap();
+    assert!((z[i_min] * ANGSTROM - interface.equimolar_radius.unwrap()).abs() < 1.0 * ANGSTROM);
+
+    // the excess entropy approaches the residual entropies of the bulk phases
+    let s_res = interface.excess_entropy_profile()?;
+    assert_relative_eq!(
+        s_res.get(0),
+        vle.liquid().residual_molar_entropy(),
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        s_res.get(z.len() - 1),
+        vle.vapor().residual_molar_entropy(),
+        max_relative = 1e-4
+    );
+
+    let narrow = PlanarInterface::from_tanh(&vle, 128, 10.0 * ANGSTROM, tc, false);
+    assert!(!narrow.boundary_is_bulk(1e-6));
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_wetting() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let wall = Wall1D::new(
+        100.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 60.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(1024),
+        None,
+    );
+    let solver = DFTSolver::new(None)
+        .anderson_mixing(Some(true), Some(50), Some(1e-5), None, None)
+        .newton(Some(true), None, None, None)
+        .newton(None, None, None, None);
+    let activity = ndarray::Array1::linspace(0.5, 0.99, 8);
+    let isotherm = wall.wetting_isotherm(&&func, 250.0 * KELVIN, &activity, Some(&solver))?;
+    let (thin, thick) = isotherm.film_thickness();
+    let film = isotherm.equilibrium_film_thickness();
+    // no prewetting transition: both branches coincide
+    for i in 0..6 {
+        assert_relative_eq!(thin.get(i), thick.get(i), max_relative = 1e-6);
+    }
+    for i in 1..activity.len() {
+        assert!(film.get(i) > film.get(i - 1));
+    }
+
+    // the undersaturation vanishes at saturation and the film thickness
+    // can be recovered from it
+    let undersaturation = isotherm.undersaturation()?;
+    for i in 1..activity.len() {
+        assert!(undersaturation.get(i) < undersaturation.get(i - 1));
+    }
+    assert!(undersaturation.get(activity.len() - 1).to_reduced() > 0.0);
+    let film_dmu = wall.film_thickness(&&func, 250.0 * KELVIN, &undersaturation, Some(&solver))?;
+    for i in 0..activity.len() {
+        assert_relative_eq!(film_dmu.get(i), film.get(i), max_relative = 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_methane_pore_filling_diagram() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("methane")?);
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 100.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(256),
+        None,
+    );
+    let temperature = Temperature::linspace(110.0 * KELVIN, 160.0 * KELVIN, 6);
+    let pore_size = Length::linspace(12.0 * ANGSTROM, 24.0 * ANGSTROM, 2);
+    let activity = Array1::linspace(0.05, 1.0, 20);
+    let solver = DFTSolver::new(None)
+        .picard_iteration(Some(true), Some(300), Some(1e-5), Some(0.05))
+        .anderson_mixing(None, None, None, None, None);
+    let diagram =
+        pore.pore_filling_diagram(&&func, &temperature, &pore_size, &activity, Some(&solver))?;
+
+    // no transition in the narrow pore
+    for i in 0..temperature.len() {
+        assert!(diagram.pressure.get((i, 0)).to_reduced().is_nan());
+    }
+
+    // capillary condensation below the saturation pressure in the wide pore
+    for i in 0..temperature.len() - 1 {
+        let p = diagram.pressure.get((i, 1));
+        let vle = PhaseEquilibrium::pure(&&func, temperature.get(i), None, Default::default())?;
+        assert!(p < vle.vapor().pressure(Contributions::Total));
+        assert!(diagram.filled_loading.get((i, 1)) > diagram.empty_loading.get((i, 1)));
+        if i > 0 {
+            assert!(p > diagram.pressure.get((i - 1, 1)));
+        }
+    }
+
+    let (t_c, p_c) = diagram.critical_point();
+    assert!(t_c.get(0).to_reduced().is_nan());
+    assert!(t_c.get(1) > temperature.get(4) && t_c.get(1) <= temperature.get(5));
+    assert!(p_c.get(1) > diagram.pressure.get((4, 1)));
+
+    // the confined saturation pressure is the transition pressure of the diagram
+    let wide = Pore1D {
+        pore_size: pore_size.get(1),
+        ..pore.clone()
+    };
+    let p_sat =
+        wide.confined_saturation_pressure(&&func, temperature.get(0), &activity, Some(&solver))?;
+    assert_relative_eq!(p_sat, diagram.pressure.get((0, 1)), max_relative = 1e-12);
+    let narrow = Pore1D {
+        pore_size: pore_size.get(0),
+        ..pore.clone()
+    };
+    assert!(
+        narrow
+            .confined_saturation_pressure(&&func, temperature.get(0), &activity, Some(&solver))
+            .is_err()
+    );
+
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let mixture = PcSaftFunctional::new(params);
+    assert!(
+        pore.pore_filling_diagram(&&mixture, &temperature, &pore_size, &activity, None)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_methane_isotherm_branches() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("methane")?);
+    let t = 110.0 * KELVIN;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        24.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 100.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(256),
+        None,
+    );
+    let p_sat = PhaseEquilibrium::pure(&&func, t, None, Default::default())?
+        .vapor()
+        .pressure(Contributions::Total);
+    let pressure = Pressure::linspace(0.05 * p_sat, p_sat, 20);
+    let solver = DFTSolver::new(None)
+        .picard_iteration(Some(true), Some(300), Some(1e-5), Some(0.05))
+        .anderson_mixing(None, None, None, None, None);
+    let branches =
+        Adsorption1D::isotherm_branches(&&func, t, &pressure, &pore, &None, &[], Some(&solver))?;
+    let n_branches = branches.branches();
+    assert_eq!(n_branches[0], 1);
+    assert_eq!(n_branches[19], 1);
+    assert!(n_branches.iter().any(|&n| n == 2));
+    assert!(n_branches.iter().all(|&n| n <= 2));
+
+    // the first branch is the stable one
+    let omega_ads =
+        Adsorption1D::adsorption_isotherm(&&func, t, &pressure, &pore, &None, Some(&solver))?
+            .grand_potential();
+    let omega_des =
+        Adsorption1D::desorption_isotherm(&&func, t, &pressure, &pore, &None, Some(&solver))?
+            .grand_potential();
+    let omega = branches.grand_potential();
+    let loading = branches.total_adsorption();
+    for (i, omega) in omega.iter().enumerate() {
+        let omega_min = omega_ads.get(i).min(omega_des.get(i));
+        assert_relative_eq!(omega.get(0), omega_min, max_relative = 1e-4);
+        if omega.len() == 2 {
+            assert!(omega.get(0) < omega.get(1));
+            assert!(loading[i].get(0) != loading[i].get(1));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_grand_potential_contributions() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let bulk = vle.liquid();
+    let pore = lj93_pore(80.0 * ANGSTROM, Some(512));
+    let profile = pore
+        .initialize(bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let (ideal, external, excess) = profile.grand_potential_density_contributions()?;
+    let omega = profile.profile.grand_potential_density()?;
+    for i in 0..omega.len() {
+        assert_relative_eq!(
+            (ideal.get(i) + external.get(i) + excess.get(i)).to_reduced(),
+            omega.get(i).to_reduced(),
+            max_relative = 1e-12
+        );
+    }
+
+    // bulk pressures in the center of the pore (up to the tail of the wall potential)
+    let z = profile.profile.grid.grids()[0];
+    let center = (0..z.len())
+        .min_by(|&i, &j| z[i].abs().total_cmp(&z[j].abs()))
+        .unwrap();
+    let p_ideal = bulk.density * RGAS * t;
+    let p = bulk.pressure(Contributions::Total);
+    assert!((external.get(center) / p_ideal).into_value().abs() < 1e-2);
+    assert_relative_eq!(
+        (ideal.get(center) / p_ideal).into_value(),
+        -1.0,
+        max_relative = 1e-3
+    );
+    assert_relative_eq!(
+        (excess.get(center) / (p - p_ideal)).into_value(),
+        -1.0,
+        max_relative = 1e-3
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_bond_number() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 500.0 * KELVIN, false);
+    assert!(interface.bond_number(1.0 * METER).is_err());
+    let interface = interface.solve(None)?;
+
+    // Bo = 1 at the capillary length
+    let gamma = interface.surface_tension.unwrap();
+    let delta_rho = vle.liquid().mass_density() - vle.vapor().mass_density();
+    let g = 9.80665 * METER / SECOND.powi::<P2>();
+    let capillary_length = (gamma / (delta_rho * g)).sqrt();
+    assert_relative_eq!(
+        interface.bond_number(capillary_length)?,
+        1.0,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        interface.bond_number(2.0 * capillary_length)?,
+        4.0,
+        max_relative = 1e-12
+    );
+    assert!(interface.bond_number(1e-3 * capillary_length)? < 1e-5);
+
+    // surface tension reduced with the PC-SAFT parameters
+    let sigma = func.sigma_ff()[0] * ANGSTROM;
+    let epsilon = func.epsilon_k_ff()[0] * KELVIN * KB;
+    assert_relative_eq!(
+        interface.surface_tension_reduced_cs()?,
+        (gamma * sigma * sigma / epsilon).into_value(),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_non_spherical_warning() -> Result<(), Box<dyn Error>> {
+    let t = 150.0 * KELVIN;
+    let propane = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&propane, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 256, 100.0 * ANGSTROM, 500.0 * KELVIN, false);
+    assert!(interface.non_spherical_warning().unwrap().contains("chain"));
+
+    let methane = PcSaftFunctional::new(parameters("methane")?);
+    let vle = PhaseEquilibrium::pure(&&methane, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 256, 100.0 * ANGSTROM, 200.0 * KELVIN, false);
+    assert!(interface.non_spherical_warning().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_interface_external_potential() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 500.0 * KELVIN, false)
+        .solve(None)?;
+
+    // Gaussian field in the liquid
+    let z = interface.profile.grid.grids()[0];
+    let phi = Array2::from_shape_fn(interface.profile.density.raw_dim(), |(_, i)| {
+        (-((z[i] - 20.0) / 3.0).powi(2)).exp()
+    });
+
+    // a vanishing field reproduces the surface tension
+    let zero = interface
+        .clone()
+        .set_external_potential(Array2::zeros(phi.raw_dim()))?
+        .solve(None)?;
+    assert_relative_eq!(
+        (zero.surface_tension.unwrap() / interface.surface_tension.unwrap()).into_value(),
+        1.0,
+        max_relative = 1e-8
+    );
+
+    // dgamma/depsilon = kT int rho(z) phi(z) dz
+    let eps = 1e-3;
+    let gamma = |eps: f64| -> FeosResult<SurfaceTension> {
+        interface
+            .clone()
+            .set_external_potential(&phi * eps)?
+            .solve(None)
+            .map(|i| i.surface_tension.unwrap())
+    };
+    let dgamma = (gamma(eps)? - gamma(-eps)?) / (2.0 * eps);
+    let rho_phi =
+        Density::from_reduced((&interface.profile.density.to_reduced() * &phi).sum_axis(Axis(0)));
+    let dgamma_ref = interface.profile.integrate(&rho_phi) * RGAS * t / Area::from_reduced(1.0);
+    assert_relative_eq!((dgamma / dgamma_ref).into_value(), 1.0, max_relative = 1e-4);
+
+    // the shape of the potential is checked
+    assert!(
+        interface
+            .clone()
+            .set_external_potential(Array2::zeros((2, 1024)))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_work_of_adhesion() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 500.0 * KELVIN, false)
+        .solve(None)?;
+    let gamma_lv = interface.surface_tension.unwrap();
+    assert_relative_eq!(
+        (interface.work_of_cohesion()? / gamma_lv).into_value(),
+        2.0,
+        max_relative = 1e-14
+    );
+
+    let wall = Wall1D::new(
+        40.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 5.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(512),
+        None,
+    );
+    let vapor = wall
+        .initialize(vle.vapor(), InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let liquid = wall
+        .initialize(vle.liquid(), InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let w_a = interface.work_of_adhesion(&vapor, &liquid)?;
+    let cos_theta = (w_a / gamma_lv).into_value() - 1.0;
+    assert!(cos_theta.abs() < 1.0);
+    assert_relative_eq!(
+        (w_a - gamma_lv).to_reduced(),
+        (vapor.interfacial_tension.unwrap() - liquid.interfacial_tension.unwrap()).to_reduced(),
+        max_relative = 1e-12
+    );
+    let WettingState::Partial(theta) = interface.wetting_state(&vapor, &liquid)? else {
+        panic!("The wall should be partially wetted.");
+    };
+    assert_relative_eq!(theta.cos(), cos_theta, max_relative = 1e-12);
+    let spreading = interface.spreading_coefficient(&vapor, &liquid)?;
+    assert_relative_eq!(
+        spreading.to_reduced(),
+        (w_a - interface.work_of_cohesion()?).to_reduced(),
+        max_relative = 1e-12
+    );
+    assert!(!interface.is_spreading(&vapor, &liquid)?);
+
+    // the metastable thin film at a strongly attractive wall results in cos(theta) > 1
+    let strong = Wall1D::new(
+        100.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            epsilon_k_ss: 50.0,
+            sigma_ss: 3.0,
+            rho_s: 0.08,
+        },
+        Some(1024),
+        None,
+    );
+    let solver = DFTSolver::new(None)
+        .picard_iteration(Some(true), Some(500), Some(1e-5), Some(0.05))
+        .anderson_mixing(None, None, None, None, None);
+    let vapor_strong = strong
+        .initialize(vle.vapor(), InitialGuess::Bulk, None)?
+        .solve(Some(&solver))?;
+    let liquid_strong = strong
+        .initialize(vle.liquid(), InitialGuess::Bulk, None)?
+        .solve(Some(&solver))?;
+    assert_eq!(
+        interface.wetting_state(&vapor_strong, &liquid_strong)?,
+        WettingState::Complete
+    );
+    assert!(interface.is_spreading(&vapor_strong, &liquid_strong)?);
+
+    // the wall profiles have to be in contact with the coexisting phases
+    assert!(interface.work_of_adhesion(&liquid, &vapor).is_err());
+    assert!(
+        interface
+            .work_of_adhesion(
+                &vapor,
+                &wall.initialize(vle.liquid(), InitialGuess::Bulk, None)?
+            )
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_corrugated_wall() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let potential = lj93_wall();
+    let bulk = State::new_npt(
+        &&func,
+        300.0 * KELVIN,
+        5.0 * BAR,
+        &(dvector![1.0] * MOL),
+        None,
+    )?;
+    let wall = Wall1D::new(40.0 * ANGSTROM, potential.clone(), Some(256), None);
+    let planar = wall
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let gamma_planar = planar.interfacial_tension.unwrap() / (ANGSTROM * ANGSTROM);
+    let gamma = |amplitude| {
+        CorrugatedWall2D::new(
+            40.0 * ANGSTROM,
+            10.0 * ANGSTROM,
+            amplitude,
+            potential.clone(),
+            [16, 256],
+            None,
+        )
+        .solid_fluid_tension(&bulk, None)
+    };
+    let gamma_flat = gamma(0.0 * ANGSTROM)?;
+    let gamma_rough = gamma(1.0 * ANGSTROM)?;
+    assert_relative_eq!(gamma_flat, gamma_planar, max_relative = 1e-6);
+    assert!(gamma_rough > gamma_flat);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_line_tension() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let width = 64.0 * ANGSTROM;
+    // contact angle close to 90°
+    let potential = ExternalPotential::LJ93 {
+        epsilon_k_ss: 17.6,
+        sigma_ss: 3.0,
+        rho_s: 0.08,
+    };
+    let solver = DFTSolver::new(None)
+        .picard_iteration(Some(true), Some(300), Some(1e-5), Some(0.05))
+        .anderson_mixing(None, None, None, None, None);
+    let wall = Wall1D::new(width, potential.clone(), Some(128), None);
+    let sv = wall
+        .initialize(vle.vapor(), InitialGuess::Bulk, None)?
+        .solve(Some(&solver))?;
+    let sl = wall
+        .initialize(vle.liquid(), InitialGuess::Bulk, None)?
+        .solve(Some(&solver))?;
+
+    // liquid on the left and vapor on the right half of the wall
+    let line_tension = |nx, length| -> Result<_, Box<dyn Error>> {
+        let wall = CorrugatedWall2D::new(
+            width,
+            length,
+            0.0 * ANGSTROM,
+            potential.clone(),
+            [nx, 128],
+            None,
+        );
+        let mut profile = wall.initialize(vle.liquid(), InitialGuess::Bulk, None)?;
+        assert!(profile.line_tension().is_err());
+        let x = profile.profile.grid.grids()[0].to_owned();
+        let x0 = 0.5 * length.convert_to(ANGSTROM);
+        let rho_l = sl.profile.density.to_reduced();
+        let rho_v = sv.profile.density.to_reduced();
+        let density = Array3::from_shape_fn(profile.profile.density.raw_dim(), |(i, j, k)| {
+            let f = 0.5 * (1.0 - ((x[j] - x0) / 3.0).tanh());
+            f * rho_l[(i, k)] + (1.0 - f) * rho_v[(i, k)]
+        });
+        profile.profile.density = Density::from_reduced(density);
+        profile.profile.specification = Arc::new(DFTSpecifications::total_moles_from_profile(
+            &profile.profile,
+        ));
+        Ok(profile.solve(Some(&solver))?.line_tension()?)
+    };
+    let tau = line_tension(128, 64.0 * ANGSTROM)?;
+    let tau_wide = line_tension(192, 96.0 * ANGSTROM)?;
+    assert_relative_eq!(tau, tau_wide, max_relative = 5e-2);
+    assert!(tau.abs() > 1e-13 * NEWTON && tau.abs() < 1e-10 * NEWTON);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_export() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+    let dir = std::env::temp_dir();
+
+    let interface = PlanarInterface::from_tanh(&vle, 64, 40.0 * ANGSTROM, 400.0 * KELVIN, false);
+    let path = dir.join("feos_test_export.csv");
+    interface.profile.to_csv(&path)?;
+    let csv = std::fs::read_to_string(&path)?;
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("z,density_0,external_potential_0"));
+    assert_eq!(lines.count(), 64);
+
+    let wall = CorrugatedWall2D::new(
+        20.0 * ANGSTROM,
+        10.0 * ANGSTROM,
+        1.0 * ANGSTROM,
+        ExternalPotential::HardWall { sigma_ss: 1.0 },
+        [8, 32],
+        None,
+    );
+    let profile = wall.initialize(vle.vapor(), InitialGuess::Bulk, None)?;
+    let path = dir.join("feos_test_export.vtk");
+    profile.profile.to_vtk(&path)?;
+    let vtk = std::fs::read_to_string(&path)?;
+    assert!(vtk.contains("DIMENSIONS 8 32 1"));
+    assert!(vtk.contains("POINT_DATA 256"));
+    assert!(vtk.contains("SCALARS external_potential_0 double 1"));
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_free_surface() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 150.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let film = FreeSurface::from_tanh(vle.liquid(), 1024, 100.0 * ANGSTROM, 50.0 * ANGSTROM)
+        .solve(None)?;
+    film.check_vacuum(1e-3)?;
+    assert!(film.check_vacuum(0.0).is_err());
+    assert!(film.profile.mass_conservation_residual().unwrap() < 1e-8);
+    assert_relative_eq!(
+        film.liquid.density,
+        vle.liquid().density,
+        max_relative = 1e-4
+    );
+
+    // the surface tension agrees with the vapor-liquid interface
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let interface =
+        PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, tc, false).solve(None)?;
+    assert_relative_eq!(
+        film.surface_tension.unwrap(),
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_droplet() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let p = 2.0 * vle.vapor().pressure(Contributions::Total);
+    let vapor = State::new_npt(
+        &&func,
+        t,
+        p,
+        &vle.vapor().moles,
+        Some(DensityInitialization::Vapor),
+    )?;
+    let droplet = Droplet::from_tanh(&vapor, vle.liquid(), 512, 80.0 * ANGSTROM, 25.0 * ANGSTROM)
+        .solve(None)?;
+    let barrier = droplet.nucleation_barrier.unwrap();
+    let radius = droplet.critical_radius.unwrap();
+    let moles = droplet.nucleus_moles.unwrap();
+    assert_relative_eq!(
+        droplet.profile.bulk.density,
+        vapor.density,
+        max_relative = 1e-5
+    );
+    assert!(moles > 0.0 * MOL);
+    assert!(droplet.profile.mass_conservation_residual().unwrap() < 1e-8);
+    assert!(droplet.laplace_pressure()? > 0.0 * PASCAL);
+    assert!(droplet.laplace_consistency_residual()?.unwrap().abs() < 5e-2);
+
+    // the volume enclosed by the critical radius contains the excess moles
+    let area = droplet.profile.dividing_surface_area(radius)?;
+    let volume = droplet.profile.enclosed_volume(radius)?;
+    assert_relative_eq!(volume, area * radius / 3.0, max_relative = 1e-12);
+    assert_relative_eq!(
+        moles,
+        (vle.liquid().density - vapor.density) * volume,
+        max_relative = 5e-2
+    );
+    assert!(
+        droplet
+            .profile
+            .dividing_surface_area(100.0 * ANGSTROM)
+            .is_err()
+    );
+
+    // compare to classical nucleation theory with the planar surface tension
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let gamma = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, tc, false)
+        .solve(None)?
+        .surface_tension
+        .unwrap();
+    let barrier_cnt = 4.0 / 3.0 * std::f64::consts::PI * gamma * radius * radius;
+    assert_relative_eq!(barrier, barrier_cnt, max_relative = 1e-2);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_total_moles() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 200.0 * KELVIN;
+    let w = 100.0 * ANGSTROM;
+    let tc = State::critical_point(&&func, None, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&&func, t, None, Default::default())?;
+    let reference = PlanarInterface::from_tanh(&vle, 512, w, tc, false).solve(None)?;
+
+    // the bulk density is iterated to keep the number of particles constant
+    let interface = PlanarInterface::from_tanh(&vle, 512, w, tc, true);
+    let moles = interface.profile.total_moles();
+    for solver in [
+        DFTSolver::new(None).anderson_mixing(None, None, None, None, None),
+        DFTSolver::default(),
+    ] {
+        let interface = interface.clone().solve(Some(&solver))?;
+        assert_relative_eq!(interface.profile.total_moles(), moles, max_relative = 1e-8);
+        assert_relative_eq!(
+            interface.profile.bulk.density,
+            vle.vapor().density,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            interface.surface_tension.unwrap(),
+            reference.surface_tension.unwrap(),
+            max_relative = 1e-5
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_mixture_moles() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::bubble_point(
+        &&func,
+        t,
+        &dvector![0.5, 0.5],
+        None,
+        None,
+        Default::default(),
+    )?;
+    let reference = PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .solve(None)?;
+
+    // the bulk densities are iterated to keep the number of particles of each
+    // component constant, starting from perturbed bulk densities
+    let mut interface = reference.clone();
+    interface.profile.specification =
+        Arc::new(DFTSpecifications::moles_from_profile(&interface.profile));
+    let volume = METER.powi::<P3>();
+    let moles = &vle.vapor().partial_density * 1.05 * volume;
+    interface.profile.bulk = State::new_nvt(&&func, t, volume, &moles)?;
+    let moles = interface.profile.moles();
+    for log in [false, true] {
+        let solver = DFTSolver::new(None).anderson_mixing(Some(log), None, None, None, None);
+        let interface = interface.clone().solve(Some(&solver))?;
+        for i in 0..2 {
+            assert_relative_eq!(
+                interface.profile.moles().get(i),
+                moles.get(i),
+                max_relative = 1e-8
+            );
+            assert_relative_eq!(
+                interface.profile.bulk.partial_density.get(i),
+                vle.vapor().partial_density.get(i),
+                max_relative = 1e-5
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_pdgt_surface_tension() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+    let gamma = PlanarInterface::pdgt_surface_tension(&vle)?;
+    assert!(gamma > 0.0 * NEWTON / METER);
+    let interface = PlanarInterface::from_pdgt(&vle, 1024, false)?.solve(None)?;
+    assert_relative_eq!(
+        gamma,
+        interface.surface_tension.unwrap(),
+        max_relative = 5e-2
+    );
+
+    // pDGT is only available for pure components
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let vle = PhaseEquilibrium::bubble_point(
+        &&func,
+        250.0 * KELVIN,
+        &dvector![0.5, 0.5],
+        None,
+        None,
+        Default::default(),
+    )?;
+    assert!(PlanarInterface::pdgt_surface_tension(&vle).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_center_density() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let bulk = State::new_npt(
+        &&func,
+        300.0 * KELVIN,
+        1.0 * BAR,
+        &(dvector![1.0] * MOL),
+        None,
+    )?;
+    let wide = lj93_pore(100.0 * ANGSTROM, Some(1024));
+    let profile = wide
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let rho = profile.center_density()?;
+    assert_eq!(rho.get(0), profile.profile.density.get((0, 0)));
+    assert!(profile.is_bulk_core(1e-2)?);
+
+    // the walls of narrow pores affect the density in the center
+    let narrow = Pore1D {
+        pore_size: 10.0 * ANGSTROM,
+        n_grid: Some(256),
+        ..wide
+    };
+    let profile = narrow
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    assert!(!profile.is_bulk_core(1e-2)?);
+    Ok(())
+}
+
+#[test]
+fn test_dft_potential_scaling() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let bulk = State::new_npt(
+        &&func,
+        300.0 * KELVIN,
+        1.0 * BAR,
+        &(dvector![0.5, 0.5] * MOL),
+        Some(DensityInitialization::Vapor),
+    )?;
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(256));
+    let scaled = pore.clone().potential_scaling(dvector![1.0, 0.0]);
+    let profile = pore.initialize(&bulk, InitialGuess::Bulk, None)?;
+    let profile_scaled = scaled.initialize(&bulk, InitialGuess::Bulk, None)?;
+
+    // the walls are removed only for butane
+    assert_eq!(
+        profile_scaled.external_potential().row(0),
+        profile.external_potential().row(0)
+    );
+    assert!(
+        profile_scaled
+            .external_potential()
+            .row(1)
+            .iter()
+            .all(|&v| v == 0.0)
+    );
+    assert_relative_eq!(
+        scaled.pore_volume()?,
+        pore.pore_volume()?,
+        max_relative = 1e-12
+    );
+
+    // one scaling factor per component is required
+    assert!(
+        pore.potential_scaling(dvector![1.0])
+            .initialize(&bulk, InitialGuess::Bulk, None)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_kelvin_condensation_pressure() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&func, 250.0 * KELVIN, None, Default::default())?;
+    let interface = PlanarInterface::from_pdgt(&vle, 1024, false)?;
+    let pore = lj93_pore(50.0 * ANGSTROM, None);
+
+    // the surface tension is only available for solved interfaces
+    assert!(pore.kelvin_condensation_pressure(&interface).is_err());
+    let interface = interface.solve(None)?;
+    let p = pore.kelvin_condensation_pressure(&interface)?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let liquid = vle.liquid();
+    let exponent = -2.0 * interface.surface_tension.unwrap()
+        / (liquid.density * RGAS * liquid.temperature * pore.pore_size);
+    assert_relative_eq!(p, p_sat * exponent.into_value().exp(), max_relative = 1e-12);
+    assert!(p < p_sat);
+
+    // pores condense closer to saturation with increasing pore size
+    let wide = Pore1D {
+        pore_size: 100.0 * ANGSTROM,
+        ..pore
+    };
+    let p_wide = wide.kelvin_condensation_pressure(&interface)?;
+    assert_relative_eq!(
+        (p / p_sat).into_value().ln(),
+        2.0 * (p_wide / p_sat).into_value().ln(),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_interfacial_enrichment_extremum() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = PcSaftFunctional::new(params);
+    let vle = PhaseEquilibrium::bubble_point(
+        &&func,
+        250.0 * KELVIN,
+        &dvector![0.3, 0.7],
+        None,
+        None,
+        Default::default(),
+    )?;
+    let interface = PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .solve(None)?;
+    let (enrichment, z_max) = interface.interfacial_enrichment_extremum(false);
+    let (depletion, z_min) = interface.interfacial_enrichment_extremum(true);
+    for (e, e_ref) in enrichment.iter().zip(interface.interfacial_enrichment()) {
+        assert_relative_eq!(*e, e_ref, max_relative = 1e-14);
+    }
+    assert!(enrichment.iter().all(|&e| e >= 1.0));
+    assert!(depletion.iter().all(|&d| d <= 1.0));
+
+    // the light component is enriched inside the interface
+    let z = interface.profile.grid.grids()[0];
+    assert!(enrichment[0] > 1.0);
+    assert!(z_max.get(0).to_reduced() > z[0]);
+    assert!(z_max.get(0).to_reduced() < z[z.len() - 1]);
+    assert!(z_min.get(0).to_reduced() > z_max.get(0).to_reduced());
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_density_array() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .solve(None)?;
+    let (rho, reference) = interface.profile.density_array();
+    assert_eq!(rho.shape(), interface.profile.density.shape());
+    for (k, &r) in rho.row(0).iter().enumerate() {
+        assert_relative_eq!(
+            r * reference,
+            interface.profile.density.get((0, k)),
+            max_relative = 1e-14
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_excess_moles() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let bulk = State::new_npt(
+        &&func,
+        300.0 * KELVIN,
+        1.0 * BAR,
+        &(dvector![1.0] * MOL),
+        None,
+    )?;
+    let pore = lj93_pore(20.0 * ANGSTROM, Some(256));
+    let profile = pore
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    let excess = profile.excess_moles(&pore)?;
+    assert_relative_eq!(
+        excess.get(0),
+        profile.profile.moles().get(0) - bulk.density * pore.pore_volume()?,
+        max_relative = 1e-12
+    );
+    assert!(excess.get(0) < profile.profile.moles().get(0));
+
+    // attractive walls adsorb, hard walls deplete the fluid
+    assert!(excess.get(0) > 0.0 * MOL);
+    let hard = Pore1D {
+        potential: ExternalPotential::HardWall { sigma_ss: 3.0 },
+        ..pore
+    };
+    let profile = hard
+        .initialize(&bulk, InitialGuess::Bulk, None)?
+        .solve(None)?;
+    assert!(profile.excess_moles(&hard)?.get(0) < 0.0 * MOL);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_pdgt_width() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+    let width = |interface: &PlanarInterface<_>| {
+        let edges = &interface.profile.grid.axes()[0].edges;
+        edges[edges.len() - 1] - edges[0]
+    };
+
+    // the domain is at least as wide as the minimum width
+    let default = PlanarInterface::from_pdgt(&vle, 512, false)?;
+    assert!(width(&default) >= interface::MIN_WIDTH);
+    let narrow = PlanarInterface::from_pdgt_with_width(
+        &vle,
+        512,
+        false,
+        1.0,
+        interface::MIN_WIDTH * ANGSTROM,
+    )?;
+    assert_relative_eq!(width(&narrow), interface::MIN_WIDTH, max_relative = 1e-12);
+    let wide = PlanarInterface::from_pdgt_with_width(
+        &vle,
+        512,
+        false,
+        1.0,
+        2.0 * interface::MIN_WIDTH * ANGSTROM,
+    )?;
+    assert_relative_eq!(
+        width(&wide),
+        2.0 * interface::MIN_WIDTH,
+        max_relative = 1e-12
+    );
+
+    // otherwise the width is a multiple of the width of the pDGT profile
+    let relative = PlanarInterface::from_pdgt_with_width(&vle, 512, false, 100.0, 0.0 * ANGSTROM)?;
+    assert_relative_eq!(
+        width(&relative) / width(&default),
+        100.0 / interface::RELATIVE_WIDTH,
+        max_relative = 1e-12
+    );
+
+    // the surface tension does not depend on the width of the domain
+    assert_relative_eq!(
+        wide.solve(None)?.surface_tension.unwrap(),
+        default.solve(None)?.surface_tension.unwrap(),
+        max_relative = 1e-4
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_set_density() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let vle = PhaseEquilibrium::pure(&&func, 200.0 * KELVIN, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .solve(None)?;
+    let density = interface.profile.density.clone();
+    let initial = PlanarInterface::from_tanh(&vle, 512, 100.0 * ANGSTROM, 400.0 * KELVIN, false)
+        .set_density(&density, false)?;
+    assert_eq!(initial.profile.density, density);
+
+    // profiles of the wrong shape are rejected
+    let wrong = Density::from_reduced(Array2::zeros((1, 256)));
+    let message = interface
+        .clone()
+        .set_density(&wrong, false)
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(message.contains("[1, 512]") && message.contains("[1, 256]"));
+    let mut scaled = interface.clone();
+    assert!(scaled.set_density_inplace(&wrong, true).is_err());
+    assert_eq!(scaled.profile.density, density);
+    Ok(())
+}
+
+#[test]
+fn test_dft_propane_external_potential() -> Result<(), Box<dyn Error>> {
+    let func = PcSaftFunctional::new(parameters("propane")?);
+    let t = 300.0 * KELVIN;
+    let bulk = State::new_npt(&&func, t, 1.0 * BAR, &(dvector![1.0] * MOL), None)?;
+    let pore = Pore1D {
+        potential_cutoff: Some(20.0),
+        ..lj93_pore(20.0 * ANGSTROM, Some(256))
+    };
+    let profile = pore.initialize(&bulk, InitialGuess::Bulk, None)?;
+    let v = profile.external_potential();
+    assert_eq!(v.shape(), profile.profile.density.shape());
+    assert!(v.iter().all(|&v| v <= 20.0));
+    assert!(v.iter().any(|&v| v < 0.0));
+    let energy = profile.external_potential_energy();
+    for (k, &v) in v.row(0).iter().enumerate() {
+        assert_relative_eq!(energy.get((0, k)), v * KB * t, max_relative = 1e-12);
+    }
+    Ok(())
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn test_dft_water() -> Result<(), Box<dyn Error>> {
@@ -5,7 +5,9 @@ use crate::ideal_gas::IdealGasModel;
 use crate::residual::ResidualModel;
 use crate::PyVerbosity;
 use feos_core::EquationOfState;
-use feos_dft::adsorption::{Adsorption, Adsorption1D, Adsorption3D};
+use feos_dft::adsorption::{
+    pore_size_distribution, Adsorption, Adsorption1D, Adsorption3D, IsothermBranches,
+};
 use nalgebra::DMatrix;
 use ndarray::*;
 use numpy::*;
@@ -15,9 +17,14 @@ use std::sync::Arc;
 
 mod external_potential;
 mod pore;
+mod wall;
 
 pub use external_potential::PyExternalPotential;
-pub use pore::{PyPore1D, PyPore2D, PyPore3D, PyPoreProfile1D, PyPoreProfile3D};
+pub use pore::{
+    PyDisjoiningPressureIsotherm, PyPore1D, PyPore2D, PyPore3D, PyPoreFillingDiagram,
+    PyPoreProfile1D, PyPoreProfile3D,
+};
+pub use wall::{PyCorrugatedWall2D, PyWall1D, PyWettingIsotherm};
 
 /// Container structure for adsorption isotherms in 1D pores.
 #[pyclass(name = "Adsorption1D")]
@@ -27,6 +34,12 @@ pub struct PyAdsorption1D(Adsorption1D<Arc<EquationOfState<Vec<IdealGasModel>, R
 #[pyclass(name = "Adsorption3D")]
 pub struct PyAdsorption3D(Adsorption3D<Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>);
 
+/// All distinct solutions found at the pressures of an isotherm in 1D pores.
+#[pyclass(name = "IsothermBranches1D")]
+pub struct PyIsothermBranches1D(
+    IsothermBranches<Ix1, Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>,
+);
+
 macro_rules! impl_adsorption_isotherm {
     ($py_adsorption:ty, $py_pore:ty, $py_pore_profile:ident) => {
         #[pymethods]
@@ -75,6 +88,85 @@ macro_rules! impl_adsorption_isotherm {
                 ).map_err(PyFeosError::from)?))
             }
 
+            /// Calculate an adsorption isotherm of a pure component for
+            /// pressures relative to the saturation pressure, e.g.,
+            /// relative humidities.
+            ///
+            /// Parameters
+            /// ----------
+            /// functional : HelmholtzEnergyFunctional
+            ///     The Helmholtz energy functional.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// relative_pressure : numpy.ndarray[float]
+            ///     The pressures relative to the saturation pressure.
+            /// pore : Pore
+            ///     The pore parameters.
+            /// solver: DFTSolver, optional
+            ///     Custom solver options.
+            ///
+            /// Returns
+            /// -------
+            /// Adsorption
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(functional, temperature, relative_pressure, pore, solver=None)")]
+            #[pyo3(signature = (functional, temperature, relative_pressure, pore, solver=None))]
+            fn adsorption_isotherm_relative(
+                functional: &PyEquationOfState,
+                temperature: Temperature,
+                relative_pressure: PyReadonlyArray1<'_, f64>,
+                pore: &$py_pore,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(Adsorption::adsorption_isotherm_relative(
+                    &functional.0,
+                    temperature,
+                    &relative_pressure.as_array().to_owned(),
+                    &pore.0,
+                    solver.map(|s| s.0).as_ref(),
+                ).map_err(PyFeosError::from)?))
+            }
+
+            /// Calculate a desorption isotherm of a pure component for
+            /// pressures relative to the saturation pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// functional : HelmholtzEnergyFunctional
+            ///     The Helmholtz energy functional.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// relative_pressure : numpy.ndarray[float]
+            ///     The pressures relative to the saturation pressure.
+            /// pore : Pore
+            ///     The pore parameters.
+            /// solver: DFTSolver, optional
+            ///     Custom solver options.
+            ///
+            /// Returns
+            /// -------
+            /// Adsorption
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(functional, temperature, relative_pressure, pore, solver=None)")]
+            #[pyo3(signature = (functional, temperature, relative_pressure, pore, solver=None))]
+            fn desorption_isotherm_relative(
+                functional: &PyEquationOfState,
+                temperature: Temperature,
+                relative_pressure: PyReadonlyArray1<'_, f64>,
+                pore: &$py_pore,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(Adsorption::desorption_isotherm_relative(
+                    &functional.0,
+                    temperature,
+                    &relative_pressure.as_array().to_owned(),
+                    &pore.0,
+                    solver.map(|s| s.0).as_ref(),
+                ).map_err(PyFeosError::from)?))
+            }
+
             /// Calculate a desorption isotherm for the given pressure range.
             /// The profiles are evaluated starting from the highest pressure.
             /// The resulting density profiles can be metastable.
@@ -241,6 +333,12 @@ macro_rules! impl_adsorption_isotherm {
                 self.0.pressure()
             }
 
+            #[getter]
+            fn get_relative_pressure<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray1<f64>>> {
+                let relative_pressure = self.0.relative_pressure().map_err(PyFeosError::from)?;
+                Ok(relative_pressure.view().to_pyarray(py))
+            }
+
             #[getter]
             fn get_adsorption(&self) -> Moles<Array2<f64>> {
                 self.0.adsorption()
@@ -265,9 +363,176 @@ macro_rules! impl_adsorption_isotherm {
             fn get_enthalpy_of_adsorption(&self) -> MolarEnergy<Array1<f64>> {
                 self.0.enthalpy_of_adsorption()
             }
+
+            /// Calculate the isosteric heat of adsorption of a pure
+            /// component as a function of the loading.
+            ///
+            /// The properties of the second isotherm are interpolated
+            /// to the loadings of this isotherm before the chemical
+            /// potentials are differenced.
+            ///
+            /// Parameters
+            /// ----------
+            /// other : Adsorption
+            ///     An isotherm of the same system at a slightly
+            ///     different temperature.
+            ///
+            /// Returns
+            /// -------
+            /// (SIArray1, SIArray1)
+            ///     The loadings and the corresponding isosteric heats.
+            ///
+            fn isosteric_heat(&self, other: &Self) -> PyResult<(Moles<Array1<f64>>, MolarEnergy<Array1<f64>>)> {
+                Ok(self.0.isosteric_heat(&other.0).map_err(PyFeosError::from)?)
+            }
+
+            /// Pressure interval that contains the spinodal at which
+            /// the branch of the isotherm ends.
+            ///
+            /// Parameters
+            /// ----------
+            /// tol : float, optional
+            ///     The relative change of the loading between two
+            ///     consecutive profiles that indicates a jump to the
+            ///     other branch. Defaults to 0.3.
+            ///
+            /// Returns
+            /// -------
+            /// (SINumber, SINumber), optional
+            ///
+            #[pyo3(signature = (tol=0.3))]
+            fn spinodal(&self, tol: f64) -> Option<(Pressure, Pressure)> {
+                self.0.spinodal(tol)
+            }
+
+            /// Calculate a pore-size distribution from a kernel of
+            /// single-pore isotherms using non-negative least squares.
+            ///
+            /// Parameters
+            /// ----------
+            /// kernel : SIArray2
+            ///     The loadings of pores with different widths (rows)
+            ///     at the pressures of the measured isotherm (columns).
+            /// loading : SIArray1
+            ///     The measured loadings.
+            /// regularization : float, optional
+            ///     The Tikhonov regularization relative to the square
+            ///     of the largest kernel entry. Defaults to 0.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///     The number of pores of every width.
+            ///
+            #[staticmethod]
+            #[pyo3(signature = (kernel, loading, regularization=0.0))]
+            fn pore_size_distribution<'py>(
+                kernel: Moles<Array2<f64>>,
+                loading: Moles<Array1<f64>>,
+                regularization: f64,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+                let psd = pore_size_distribution(&kernel, &loading, regularization)
+                    .map_err(PyFeosError::from)?;
+                Ok(psd.view().to_pyarray(py))
+            }
         }
     };
 }
 
 impl_adsorption_isotherm!(PyAdsorption1D, PyPore1D, PyPoreProfile1D);
 impl_adsorption_isotherm!(PyAdsorption3D, PyPore3D, PyPoreProfile3D);
+
+#[pymethods]
+impl PyAdsorption1D {
+    /// Calculate all distinct solutions for the given pressures using
+    /// multiple initial guesses per pressure.
+    ///
+    /// The initial guesses are the profiles of the adsorption and the
+    /// desorption isotherm, the bulk and the empty pore and all
+    /// profiles in `initial_densities`.
+    ///
+    /// Parameters
+    /// ----------
+    /// functional : HelmholtzEnergyFunctional
+    ///     The Helmholtz energy functional.
+    /// temperature : SINumber
+    ///     The temperature.
+    /// pressure : SIArray1
+    ///     The pressures for which the profiles are calculated.
+    /// pore : Pore1D
+    ///     The pore parameters.
+    /// molefracs: numpy.ndarray[float], optional
+    ///     For a mixture, the molefracs of the bulk system.
+    /// initial_densities: [SIArray2], optional
+    ///     Additional initial guesses, e.g., partially filled pores.
+    /// solver: DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// IsothermBranches1D
+    ///
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(functional, temperature, pressure, pore, molefracs=None, initial_densities=None, solver=None)"
+    )]
+    #[pyo3(signature = (functional, temperature, pressure, pore, molefracs=None, initial_densities=None, solver=None))]
+    fn isotherm_branches(
+        functional: &PyEquationOfState,
+        temperature: Temperature,
+        pressure: Pressure<Array1<f64>>,
+        pore: &PyPore1D,
+        molefracs: Option<PyReadonlyArray1<'_, f64>>,
+        initial_densities: Option<Vec<Density<Array2<f64>>>>,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<PyIsothermBranches1D> {
+        Ok(PyIsothermBranches1D(
+            Adsorption::isotherm_branches(
+                &functional.0,
+                temperature,
+                &pressure,
+                &pore.0,
+                &parse_molefracs(molefracs),
+                &initial_densities.unwrap_or_default(),
+                solver.map(|s| s.0).as_ref(),
+            )
+            .map_err(PyFeosError::from)?,
+        ))
+    }
+}
+
+#[pymethods]
+impl PyIsothermBranches1D {
+    #[getter]
+    fn get_pressure(&self) -> Pressure<Array1<f64>> {
+        self.0.pressure.clone()
+    }
+
+    /// The distinct profiles at every pressure, sorted by increasing
+    /// grand potential.
+    #[getter]
+    fn get_profiles(&self) -> Vec<Vec<PyPoreProfile1D>> {
+        self.0
+            .profiles
+            .iter()
+            .map(|p| p.iter().map(|p| PyPoreProfile1D(p.clone())).collect())
+            .collect()
+    }
+
+    /// The number of distinct solutions at every pressure.
+    #[getter]
+    fn get_branches(&self) -> Vec<usize> {
+        self.0.branches().to_vec()
+    }
+
+    #[getter]
+    fn get_total_adsorption(&self) -> Vec<Moles<Array1<f64>>> {
+        self.0.total_adsorption()
+    }
+
+    #[getter]
+    fn get_grand_potential(&self) -> Vec<Energy<Array1<f64>>> {
+        self.0.grand_potential()
+    }
+}
@@ -1,9 +1,11 @@
 use super::PyExternalPotential;
 use crate::dft::profile::*;
-use crate::dft::{PyDFTSolver, PyDFTSolverLog, PyGeometry};
+use crate::dft::{PyDFTSolver, PyDFTSolverLog, PyGeometry, PyPlanarInterface};
+use crate::eos::PyEquationOfState;
 use crate::error::PyFeosError;
 use crate::ideal_gas::IdealGasModel;
 use crate::residual::ResidualModel;
+use crate::phase_equilibria::PyPhaseEquilibrium;
 use crate::state::{PyContributions, PyState};
 use feos_core::{EquationOfState, ReferenceSystem};
 use feos_dft::adsorption::*;
@@ -14,8 +16,20 @@ use pyo3::prelude::*;
 use quantity::*;
 use std::sync::Arc;
 
+/// Initial guess from the optional density profile and the `empty` flag.
+pub(super) fn initial_guess<D: Dimension>(
+    density: Option<&Density<Array<f64, D::Larger>>>,
+    empty: bool,
+) -> InitialGuess<'_, D> {
+    match density {
+        Some(density) => InitialGuess::Explicit(density),
+        None if empty => InitialGuess::Empty,
+        None => InitialGuess::Bulk,
+    }
+}
+
 macro_rules! impl_pore_profile {
-    ($py_profile:ty) => {
+    ($py_profile:ty, $py_pore:ty) => {
         #[pymethods]
         impl $py_profile {
             #[getter]
@@ -28,6 +42,224 @@ macro_rules! impl_pore_profile {
                 self.0.interfacial_tension
             }
 
+            #[getter]
+            fn get_grid_spacing_warning(&self) -> Option<String> {
+                self.0.grid_spacing_warning.clone()
+            }
+
+            /// Calculate the excess (Gibbs) adsorption of each component.
+            ///
+            /// Parameters
+            /// ----------
+            /// pore : Pore1D | Pore2D | Pore3D
+            ///     The pore used to calculate the (Helium) pore volume.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            fn excess_moles(&self, pore: &$py_pore) -> PyResult<Moles<DVector<f64>>> {
+                Ok(self.0.excess_moles(&pore.0).map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the excess adsorption of each component with an
+            /// ideal gas at the same chemical potential as the bulk as
+            /// reference.
+            ///
+            /// Parameters
+            /// ----------
+            /// pore : Pore1D | Pore2D | Pore3D
+            ///     The pore used to calculate the (Helium) pore volume.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            fn ideal_excess_moles(&self, pore: &$py_pore) -> PyResult<Moles<DVector<f64>>> {
+                Ok(self.0.ideal_excess_moles(&pore.0).map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the average partial densities of the adsorbed
+            /// phase within the (Helium) pore volume.
+            ///
+            /// For partially filled pores, the result is an average over
+            /// the pore and not a local liquid density.
+            ///
+            /// Parameters
+            /// ----------
+            /// pore : Pore1D | Pore2D | Pore3D
+            ///     The pore used to calculate the (Helium) pore volume.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            fn adsorbed_phase_density(&self, pore: &$py_pore) -> PyResult<Density<DVector<f64>>> {
+                Ok(self.0.adsorbed_phase_density(&pore.0).map_err(PyFeosError::from)?)
+            }
+
+            /// Fix the number of moles of each component in the pore to
+            /// model a closed pore with a prescribed loading.
+            ///
+            /// After solving the profile, the bulk state is in equilibrium
+            /// with the confined fluid and determines the pressure and the
+            /// chemical potentials of the closed pore.
+            ///
+            /// Parameters
+            /// ----------
+            /// moles : SIArray1
+            ///     The number of moles of each component in the pore.
+            ///
+            /// Returns
+            /// -------
+            /// PoreProfile1D | PoreProfile2D | PoreProfile3D
+            ///
+            fn fix_moles(&self, moles: Moles<DVector<f64>>) -> PyResult<Self> {
+                Ok(Self(
+                    self.0
+                        .clone()
+                        .fix_moles(&moles)
+                        .map_err(PyFeosError::from)?,
+                ))
+            }
+
+            /// Calculate the excess isochoric heat capacity of the confined
+            /// fluid from profiles solved at neighboring temperatures.
+            ///
+            /// Parameters
+            /// ----------
+            /// pore : Pore1D | Pore2D | Pore3D
+            ///     The pore used to solve the profiles.
+            /// delta_temperature : SINumber
+            ///     The temperature step of the finite differences.
+            /// solver : DFTSolver, optional
+            ///     The solver used to solve the density profiles.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            #[pyo3(signature = (pore, delta_temperature, solver=None))]
+            fn excess_isochoric_heat_capacity(
+                &self,
+                pore: &$py_pore,
+                delta_temperature: Temperature,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Entropy> {
+                Ok(self
+                    .0
+                    .excess_isochoric_heat_capacity(
+                        &pore.0,
+                        delta_temperature,
+                        solver.map(|s| s.0).as_ref(),
+                    )
+                    .map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the excess grand potential of the pore with the
+            /// volume of the calculation domain as reference.
+            ///
+            /// The excess grand potential is the solvation free energy
+            /// of the walls. Differences between pores of different
+            /// sizes yield the potential of mean force between the
+            /// walls, which is twice the difference for symmetric slit
+            /// pores that are solved for one half of the pore.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn excess_grand_potential(&self) -> PyResult<Energy> {
+                Ok(self.0.excess_grand_potential().map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the excess Gibbs energy of the adsorbed phase
+            /// with the (Helium) pore volume as reference.
+            ///
+            /// Parameters
+            /// ----------
+            /// pore : Pore1D | Pore2D | Pore3D
+            ///     The pore used to calculate the (Helium) pore volume.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn excess_gibbs_energy(&self, pore: &$py_pore) -> PyResult<Energy> {
+                Ok(self
+                    .0
+                    .excess_gibbs_energy(&pore.0)
+                    .map_err(PyFeosError::from)?)
+            }
+
+            /// Return the external potential acting on each segment in
+            /// units of energy.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray
+            ///
+            fn external_potential_energy(&self) -> Energy<ArrayD<f64>> {
+                Energy::from_reduced(self.0.external_potential_energy().to_reduced().into_dyn())
+            }
+
+            /// Return the insertion free energy of each segment, i.e.,
+            /// -kT ln(rho/rho_bulk) - V_ext.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray
+            ///
+            fn insertion_free_energy(&self) -> Energy<ArrayD<f64>> {
+                Energy::from_reduced(self.0.insertion_free_energy().to_reduced().into_dyn())
+            }
+
+            /// Return the difference of the local and the bulk excess
+            /// chemical potential of each segment.
+            ///
+            /// For converged profiles of spherical molecules, the result
+            /// agrees with the insertion free energy.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray
+            ///
+            fn excess_chemical_potential_difference(&self) -> PyResult<Energy<ArrayD<f64>>> {
+                let dmu = self
+                    .0
+                    .excess_chemical_potential_difference()
+                    .map_err(PyFeosError::from)?;
+                Ok(Energy::from_reduced(dmu.to_reduced().into_dyn()))
+            }
+
+            /// Ideal gas, external potential and excess contributions
+            /// to the grand potential density.
+            ///
+            /// The three contributions sum up to the total grand
+            /// potential density.
+            ///
+            /// Returns
+            /// -------
+            /// (SIArray, SIArray, SIArray)
+            ///
+            fn grand_potential_density_contributions(
+                &self,
+            ) -> PyResult<(
+                Pressure<ArrayD<f64>>,
+                Pressure<ArrayD<f64>>,
+                Pressure<ArrayD<f64>>,
+            )> {
+                let (ideal, external, excess) = self
+                    .0
+                    .grand_potential_density_contributions()
+                    .map_err(PyFeosError::from)?;
+                Ok((
+                    Pressure::from_reduced(ideal.to_reduced().into_dyn()),
+                    Pressure::from_reduced(external.to_reduced().into_dyn()),
+                    Pressure::from_reduced(excess.to_reduced().into_dyn()),
+                ))
+            }
+
             #[getter]
             fn get_partial_molar_enthalpy_of_adsorption(
                 &self,
@@ -71,6 +303,23 @@ macro_rules! impl_pore_profile {
 ///     The number of grid points.
 /// potential_cutoff : float, optional
 ///     Maximum value for the external potential.
+/// potential_scaling : numpy.ndarray[float], optional
+///     Scaling factors of the external potential for every
+///     component. A value of 0 removes the walls for the
+///     respective component.
+/// convolver_coarsening : int, optional
+///     Evaluate the convolutions on a grid that is coarsened by
+///     this factor. Faster, but less accurate than the exact
+///     convolutions on the full grid (default).
+/// right_wall_potential : ExternalPotential, optional
+///     A different potential for the right wall of a slit pore.
+///     Asymmetric pores are solved over the full pore width,
+///     symmetric pores (default) only over one half of the pore.
+/// max_grid_spacing : float, optional
+///     Maximum grid spacing relative to the smallest segment
+///     diameter. If not set, grid spacings above 0.5 are reported
+///     in `PoreProfile1D.grid_spacing_warning`; if set, coarser
+///     grids raise an error.
 ///
 /// Returns
 /// -------
@@ -85,27 +334,159 @@ pub struct PyPoreProfile1D(
 );
 
 impl_1d_profile!(PyPoreProfile1D, [get_r, get_z]);
-impl_pore_profile!(PyPoreProfile1D);
+impl_pore_profile!(PyPoreProfile1D, PyPore1D);
+
+#[pymethods]
+impl PyPoreProfile1D {
+    /// The density of each segment at the center of the pore.
+    #[getter]
+    fn get_center_density(&self) -> PyResult<Density<Array1<f64>>> {
+        Ok(self.0.center_density().map_err(PyFeosError::from)?)
+    }
+
+    /// Check whether the density at the pore center matches the
+    /// bulk density.
+    ///
+    /// Parameters
+    /// ----------
+    /// tol : float
+    ///     The relative tolerance for the deviation from the bulk density.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///
+    fn is_bulk_core(&self, tol: f64) -> PyResult<bool> {
+        Ok(self.0.is_bulk_core(tol).map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the depletion thickness of each component, i.e.,
+    /// the negative excess adsorption per area divided by the
+    /// bulk density, in a slit pore.
+    ///
+    /// Parameters
+    /// ----------
+    /// pore : Pore1D
+    ///     The pore specification that defines the (Helium) pore volume.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn depletion_thickness(&self, pore: &PyPore1D) -> PyResult<Length<DVector<f64>>> {
+        Ok(self
+            .0
+            .depletion_thickness(&pore.0)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the tangential component of the pressure tensor
+    /// in a slit pore.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn tangential_pressure_profile(&self) -> PyResult<Pressure<Array1<f64>>> {
+        Ok(self
+            .0
+            .tangential_pressure_profile()
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the normal and tangential components of the pressure
+    /// tensor in a slit pore.
+    ///
+    /// Returns
+    /// -------
+    /// (SIArray1, SIArray1)
+    ///     The normal and the tangential pressure.
+    ///
+    #[expect(clippy::type_complexity)]
+    fn stress_profile(&self) -> PyResult<(Pressure<Array1<f64>>, Pressure<Array1<f64>>)> {
+        Ok(self.0.stress_profile().map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the radius of the liquid-vapor meniscus
+    /// in a cylindrical pore.
+    ///
+    /// Parameters
+    /// ----------
+    /// vle : PhaseEquilibrium
+    ///     The phase equilibrium that defines the liquid
+    ///     and vapor densities.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber, optional
+    ///     The radius or None if the profile does not contain
+    ///     a vapor core and a liquid film.
+    ///
+    fn meniscus_radius(&self, vle: &PyPhaseEquilibrium) -> PyResult<Option<Length>> {
+        Ok(self.0.meniscus_radius(&vle.0).map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the wavelength and the number of the layers
+    /// of the confined fluid at the wall.
+    ///
+    /// Parameters
+    /// ----------
+    /// tol : float, optional
+    ///     The minimum height of a layer relative to the adjacent
+    ///     minima in units of the total bulk density.
+    ///     Defaults to 0.01.
+    ///
+    /// Returns
+    /// -------
+    /// (SINumber, int), optional
+    ///     The wavelength and the number of layers or None if
+    ///     less than two layers are resolved.
+    ///
+    #[pyo3(signature = (tol=0.01), text_signature = "($self, tol=0.01)")]
+    fn layering_wavelength(&self, tol: f64) -> Option<(Length, usize)> {
+        self.0.layering_wavelength(tol)
+    }
+}
 
 #[pymethods]
 impl PyPore1D {
     #[new]
-    #[pyo3(text_signature = "(geometry, pore_size, potential, n_grid=None, potential_cutoff=None)")]
-    #[pyo3(signature = (geometry, pore_size, potential, n_grid=None, potential_cutoff=None))]
+    #[pyo3(
+        text_signature = "(geometry, pore_size, potential, n_grid=None, potential_cutoff=None, potential_scaling=None, convolver_coarsening=None, right_wall_potential=None, max_grid_spacing=None)"
+    )]
+    #[pyo3(signature = (geometry, pore_size, potential, n_grid=None, potential_cutoff=None, potential_scaling=None, convolver_coarsening=None, right_wall_potential=None, max_grid_spacing=None))]
+    #[expect(clippy::too_many_arguments)]
     fn new(
         geometry: PyGeometry,
         pore_size: Length,
         potential: PyExternalPotential,
         n_grid: Option<usize>,
         potential_cutoff: Option<f64>,
+        potential_scaling: Option<&Bound<'_, PyArray1<f64>>>,
+        convolver_coarsening: Option<usize>,
+        right_wall_potential: Option<PyExternalPotential>,
+        max_grid_spacing: Option<f64>,
     ) -> PyResult<Self> {
-        Ok(Self(Pore1D::new(
+        let mut pore = Pore1D::new(
             geometry.into(),
             pore_size,
             potential.0,
             n_grid,
             potential_cutoff,
-        )))
+        );
+        if let Some(scaling) = potential_scaling {
+            pore = pore.potential_scaling(DVector::from_vec(scaling.to_vec()?));
+        }
+        if let Some(factor) = convolver_coarsening {
+            pore = pore.convolver_coarsening(factor);
+        }
+        if let Some(potential) = right_wall_potential {
+            pore = pore.right_wall_potential(potential.0);
+        }
+        if let Some(max_grid_spacing) = max_grid_spacing {
+            pore = pore.max_grid_spacing(max_grid_spacing);
+        }
+        Ok(Self(pore))
     }
 
     /// Initialize the pore for the given bulk state.
@@ -120,23 +501,79 @@ impl PyPore1D {
     ///     The external potential in the pore. Used to
     ///     save computation time in the case of costly
     ///     evaluations of external potentials.
+    /// empty : bool, optional
+    ///     Initialize an empty (vapor-like) pore with the densities
+    ///     of an ideal gas at the fugacities of the bulk phase instead
+    ///     of the bulk densities. Ignored if density is given.
+    ///     Defaults to False.
     ///
     /// Returns
     /// -------
     /// PoreProfile1D
-    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None)")]
-    #[pyo3(signature = (bulk, density=None, external_potential=None))]
+    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None, empty=False)")]
+    #[pyo3(signature = (bulk, density=None, external_potential=None, empty=false))]
     fn initialize(
         &self,
         bulk: &PyState,
         density: Option<Density<Array2<f64>>>,
         external_potential: Option<&Bound<'_, PyArray2<f64>>>,
+        empty: bool,
     ) -> PyResult<PyPoreProfile1D> {
         Ok(PyPoreProfile1D(
             self.0
                 .initialize(
                     &bulk.0,
-                    density.as_ref(),
+                    initial_guess(density.as_ref(), empty),
+                    external_potential.map(|e| e.to_owned_array()).as_ref(),
+                )
+                .map_err(PyFeosError::from)?,
+        ))
+    }
+
+    /// Initialize the pore in equilibrium with a bulk phase at the
+    /// given temperature and fugacities.
+    ///
+    /// Parameters
+    /// ----------
+    /// eos : EquationOfState
+    ///     The equation of state (Helmholtz energy functional).
+    /// temperature : SINumber
+    ///     The temperature of the bulk phase.
+    /// fugacity : SIArray1
+    ///     The fugacities of all components in the bulk phase.
+    /// density : SIArray2, optional
+    ///     Initial values for the density profile.
+    /// external_potential : numpy.ndarray[float], optional
+    ///     The external potential in the pore.
+    /// empty : bool, optional
+    ///     Initialize an empty (vapor-like) pore with the densities
+    ///     of an ideal gas at the fugacities of the bulk phase instead
+    ///     of the bulk densities. Ignored if density is given.
+    ///     Defaults to False.
+    ///
+    /// Returns
+    /// -------
+    /// PoreProfile1D
+    #[pyo3(
+        text_signature = "($self, eos, temperature, fugacity, density=None, external_potential=None, empty=False)"
+    )]
+    #[pyo3(signature = (eos, temperature, fugacity, density=None, external_potential=None, empty=false))]
+    fn initialize_fugacity(
+        &self,
+        eos: &PyEquationOfState,
+        temperature: Temperature,
+        fugacity: Pressure<DVector<f64>>,
+        density: Option<Density<Array2<f64>>>,
+        external_potential: Option<&Bound<'_, PyArray2<f64>>>,
+        empty: bool,
+    ) -> PyResult<PyPoreProfile1D> {
+        Ok(PyPoreProfile1D(
+            self.0
+                .initialize_fugacity(
+                    &eos.0,
+                    temperature,
+                    &fugacity,
+                    initial_guess(density.as_ref(), empty),
                     external_potential.map(|e| e.to_owned_array()).as_ref(),
                 )
                 .map_err(PyFeosError::from)?,
@@ -158,6 +595,14 @@ impl PyPore1D {
         PyExternalPotential(self.0.potential.clone())
     }
 
+    #[getter]
+    fn get_right_wall_potential(&self) -> Option<PyExternalPotential> {
+        self.0
+            .right_wall_potential
+            .as_ref()
+            .map(|p| PyExternalPotential(p.clone()))
+    }
+
     #[getter]
     fn get_n_grid(&self) -> Option<usize> {
         self.0.n_grid
@@ -168,11 +613,275 @@ impl PyPore1D {
         self.0.potential_cutoff
     }
 
+    #[getter]
+    fn get_max_grid_spacing(&self) -> Option<f64> {
+        self.0.max_grid_spacing
+    }
+
+    #[getter]
+    fn get_potential_scaling<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyArray1<f64>>> {
+        self.0
+            .potential_scaling
+            .as_ref()
+            .map(|s| s.as_slice().to_pyarray(py))
+    }
+
     /// The pore volume using Helium at 298 K as reference.
     #[getter]
     fn get_pore_volume(&self) -> PyResult<Volume> {
         Ok(self.0.pore_volume().map_err(PyFeosError::from)?)
     }
+
+    /// Calculate the condensation pressure predicted by the Kelvin equation.
+    ///
+    /// Parameters
+    /// ----------
+    /// interface : PlanarInterface
+    ///     The solved vapor-liquid interface that provides the
+    ///     surface tension and the saturated bulk phases.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn kelvin_condensation_pressure(&self, interface: &PyPlanarInterface) -> PyResult<Pressure> {
+        Ok(self
+            .0
+            .kelvin_condensation_pressure(&interface.0)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Estimate the grand potential in the limit of infinite grid resolution.
+    ///
+    /// The pore is solved with n_grid grid points and with two and four
+    /// times as many grid points.
+    ///
+    /// Parameters
+    /// ----------
+    /// bulk : State
+    ///     The bulk state in equilibrium with the pore.
+    /// solver : DFTSolver, optional
+    ///     The solver used to solve the density profiles.
+    ///
+    /// Returns
+    /// -------
+    /// (SINumber, SINumber)
+    ///     The extrapolated grand potential and the estimated
+    ///     error on the finest grid.
+    ///
+    #[pyo3(signature = (bulk, solver=None), text_signature = "($self, bulk, solver=None)")]
+    fn richardson_extrapolation(
+        &self,
+        bulk: &PyState,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<(Energy, Energy)> {
+        Ok(self
+            .0
+            .richardson_extrapolation(&bulk.0, solver.map(|s| s.0).as_ref())
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the saturation pressure of a pure component inside
+    /// the pore.
+    ///
+    /// The pressure is located from the crossing of the grand
+    /// potentials of the adsorption and the desorption branch.
+    ///
+    /// Parameters
+    /// ----------
+    /// functional : HelmholtzEnergyFunctional
+    ///     The Helmholtz energy functional.
+    /// temperature : SINumber
+    ///     The temperature.
+    /// activity : numpy.ndarray[float]
+    ///     The bulk activities p/p_sat in ascending order.
+    /// solver : DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    #[pyo3(text_signature = "($self, functional, temperature, activity, solver=None)")]
+    #[pyo3(signature = (functional, temperature, activity, solver=None))]
+    fn confined_saturation_pressure(
+        &self,
+        functional: &PyEquationOfState,
+        temperature: Temperature,
+        activity: PyReadonlyArray1<'_, f64>,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<Pressure> {
+        Ok(self
+            .0
+            .confined_saturation_pressure(
+                &functional.0,
+                temperature,
+                &activity.to_owned_array(),
+                solver.map(|s| s.0).as_ref(),
+            )
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculate the pore-filling transitions of a pure component
+    /// for every combination of temperatures and pore sizes.
+    ///
+    /// The transitions are located from the crossing of the grand
+    /// potentials of the adsorption and the desorption branch.
+    ///
+    /// Parameters
+    /// ----------
+    /// functional : HelmholtzEnergyFunctional
+    ///     The Helmholtz energy functional.
+    /// temperature : SIArray1
+    ///     The temperatures in ascending order.
+    /// pore_size : SIArray1
+    ///     The pore sizes.
+    /// activity : numpy.ndarray[float]
+    ///     The bulk activities p/p_sat in ascending order.
+    /// solver : DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// PoreFillingDiagram
+    ///
+    #[pyo3(text_signature = "($self, functional, temperature, pore_size, activity, solver=None)")]
+    #[pyo3(signature = (functional, temperature, pore_size, activity, solver=None))]
+    fn pore_filling_diagram(
+        &self,
+        functional: &PyEquationOfState,
+        temperature: Temperature<Array1<f64>>,
+        pore_size: Length<Array1<f64>>,
+        activity: PyReadonlyArray1<'_, f64>,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<PyPoreFillingDiagram> {
+        Ok(PyPoreFillingDiagram(
+            self.0
+                .pore_filling_diagram(
+                    &functional.0,
+                    &temperature,
+                    &pore_size,
+                    &activity.to_owned_array(),
+                    solver.map(|s| s.0).as_ref(),
+                )
+                .map_err(PyFeosError::from)?,
+        ))
+    }
+
+    /// Calculate the disjoining-pressure isotherm of a film confined
+    /// between the identical walls of a slit pore.
+    ///
+    /// Parameters
+    /// ----------
+    /// bulk : State
+    ///     The bulk state in equilibrium with the film.
+    /// film_thickness : SIArray1
+    ///     The film thicknesses (pore widths) in ascending order.
+    /// solver : DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// DisjoiningPressureIsotherm
+    ///
+    #[pyo3(text_signature = "($self, bulk, film_thickness, solver=None)")]
+    #[pyo3(signature = (bulk, film_thickness, solver=None))]
+    fn disjoining_pressure_isotherm(
+        &self,
+        bulk: &PyState,
+        film_thickness: Length<Array1<f64>>,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<PyDisjoiningPressureIsotherm> {
+        Ok(PyDisjoiningPressureIsotherm(
+            self.0
+                .disjoining_pressure_isotherm(
+                    &bulk.0,
+                    &film_thickness,
+                    solver.map(|s| s.0).as_ref(),
+                )
+                .map_err(PyFeosError::from)?,
+        ))
+    }
+}
+
+/// Disjoining-pressure isotherm of a symmetric film confined
+/// between two identical walls.
+#[pyclass(name = "DisjoiningPressureIsotherm")]
+pub struct PyDisjoiningPressureIsotherm(DisjoiningPressureIsotherm);
+
+#[pymethods]
+impl PyDisjoiningPressureIsotherm {
+    #[getter]
+    fn get_film_thickness(&self) -> Length<Array1<f64>> {
+        self.0.film_thickness.clone()
+    }
+
+    #[getter]
+    fn get_disjoining_pressure(&self) -> Pressure<Array1<f64>> {
+        self.0.disjoining_pressure.clone()
+    }
+
+    /// The excess grand potential per area of the film
+    /// including both walls.
+    #[getter]
+    fn get_excess_grand_potential(&self) -> SurfaceTension<Array1<f64>> {
+        self.0.excess_grand_potential.clone()
+    }
+
+    /// Classify the films as stable (d Pi / d h < 0) or unstable.
+    ///
+    /// Returns
+    /// -------
+    /// list[bool]
+    ///
+    fn is_stable(&self) -> Vec<bool> {
+        self.0.is_stable()
+    }
+}
+
+/// Pore-filling transitions of a pure component on a grid of
+/// temperatures and pore sizes.
+#[pyclass(name = "PoreFillingDiagram")]
+pub struct PyPoreFillingDiagram(PoreFillingDiagram);
+
+#[pymethods]
+impl PyPoreFillingDiagram {
+    #[getter]
+    fn get_temperature(&self) -> Temperature<Array1<f64>> {
+        self.0.temperature.clone()
+    }
+
+    #[getter]
+    fn get_pore_size(&self) -> Length<Array1<f64>> {
+        self.0.pore_size.clone()
+    }
+
+    /// The transition pressures (temperatures x pore sizes).
+    #[getter]
+    fn get_pressure(&self) -> Pressure<Array2<f64>> {
+        self.0.pressure.clone()
+    }
+
+    #[getter]
+    fn get_empty_loading(&self) -> Moles<Array2<f64>> {
+        self.0.empty_loading.clone()
+    }
+
+    #[getter]
+    fn get_filled_loading(&self) -> Moles<Array2<f64>> {
+        self.0.filled_loading.clone()
+    }
+
+    /// Estimate the pore critical point for every pore size.
+    ///
+    /// Returns
+    /// -------
+    /// (SIArray1, SIArray1)
+    ///     The critical temperatures and pressures.
+    ///
+    fn critical_point(&self) -> (Temperature<Array1<f64>>, Pressure<Array1<f64>>) {
+        self.0.critical_point()
+    }
 }
 
 #[pyclass(name = "Pore2D")]
@@ -184,7 +893,24 @@ pub struct PyPoreProfile2D(
 );
 
 impl_2d_profile!(PyPoreProfile2D, get_x, get_y);
-impl_pore_profile!(PyPoreProfile2D);
+impl_pore_profile!(PyPoreProfile2D, PyPore2D);
+
+#[pymethods]
+impl PyPoreProfile2D {
+    /// Line tension of a three-phase contact line at a planar wall.
+    ///
+    /// The profile has to be solved on a CorrugatedWall2D without
+    /// corrugation at liquid-vapor coexistence with the coexisting
+    /// phases on either side of the lateral axis.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn line_tension(&self) -> PyResult<Force> {
+        Ok(self.0.line_tension().map_err(PyFeosError::from)?)
+    }
+}
 
 #[pymethods]
 impl PyPore2D {
@@ -210,23 +936,29 @@ impl PyPore2D {
     ///     The external potential in the pore. Used to
     ///     save computation time in the case of costly
     ///     evaluations of external potentials.
+    /// empty : bool, optional
+    ///     Initialize an empty (vapor-like) pore with the densities
+    ///     of an ideal gas at the fugacities of the bulk phase instead
+    ///     of the bulk densities. Ignored if density is given.
+    ///     Defaults to False.
     ///
     /// Returns
     /// -------
     /// PoreProfile2D
-    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None)")]
-    #[pyo3(signature = (bulk, density=None, external_potential=None))]
+    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None, empty=False)")]
+    #[pyo3(signature = (bulk, density=None, external_potential=None, empty=false))]
     fn initialize(
         &self,
         bulk: &PyState,
         density: Option<Density<Array3<f64>>>,
         external_potential: Option<&Bound<'_, PyArray3<f64>>>,
+        empty: bool,
     ) -> PyResult<PyPoreProfile2D> {
         Ok(PyPoreProfile2D(
             self.0
                 .initialize(
                     &bulk.0,
-                    density.as_ref(),
+                    initial_guess(density.as_ref(), empty),
                     external_potential.map(|e| e.to_owned_array()).as_ref(),
                 )
                 .map_err(PyFeosError::from)?,
@@ -275,7 +1007,7 @@ pub struct PyPoreProfile3D(
 );
 
 impl_3d_profile!(PyPoreProfile3D, get_x, get_y, get_z);
-impl_pore_profile!(PyPoreProfile3D);
+impl_pore_profile!(PyPoreProfile3D, PyPore3D);
 
 #[pymethods]
 impl PyPore3D {
@@ -319,23 +1051,29 @@ impl PyPore3D {
     ///     The external potential in the pore. Used to
     ///     save computation time in the case of costly
     ///     evaluations of external potentials.
+    /// empty : bool, optional
+    ///     Initialize an empty (vapor-like) pore with the densities
+    ///     of an ideal gas at the fugacities of the bulk phase instead
+    ///     of the bulk densities. Ignored if density is given.
+    ///     Defaults to False.
     ///
     /// Returns
     /// -------
     /// PoreProfile3D
-    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None)")]
-    #[pyo3(signature = (bulk, density=None, external_potential=None))]
+    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None, empty=False)")]
+    #[pyo3(signature = (bulk, density=None, external_potential=None, empty=false))]
     fn initialize(
         &self,
         bulk: &PyState,
         density: Option<Density<Array4<f64>>>,
         external_potential: Option<&Bound<'_, PyArray4<f64>>>,
+        empty: bool,
     ) -> PyResult<PyPoreProfile3D> {
         Ok(PyPoreProfile3D(
             self.0
                 .initialize(
                     &bulk.0,
-                    density.as_ref(),
+                    initial_guess(density.as_ref(), empty),
                     external_potential.map(|e| e.to_owned_array()).as_ref(),
                 )
                 .map_err(PyFeosError::from)?,
@@ -0,0 +1,345 @@
+use super::pore::initial_guess;
+use super::{PyExternalPotential, PyPoreProfile1D};
+use crate::dft::PyDFTSolver;
+use crate::eos::PyEquationOfState;
+use crate::error::PyFeosError;
+use crate::ideal_gas::IdealGasModel;
+use crate::residual::ResidualModel;
+use crate::state::PyState;
+use feos_core::EquationOfState;
+use feos_dft::adsorption::*;
+use ndarray::*;
+use numpy::*;
+use pyo3::prelude::*;
+use quantity::*;
+use std::sync::Arc;
+
+/// Parameters required to specify a single planar wall in contact
+/// with a semi-infinite bulk fluid.
+///
+/// Parameters
+/// ----------
+/// width : SINumber
+///     The width of the system. Needs to be large enough for
+///     the density to reach the bulk value.
+/// potential : ExternalPotential
+///     The potential used to model wall-fluid interactions.
+/// n_grid : int, optional
+///     The number of grid points.
+/// potential_cutoff : float, optional
+///     Maximum value for the external potential.
+///
+/// Returns
+/// -------
+/// Wall1D
+///
+#[pyclass(name = "Wall1D")]
+pub struct PyWall1D(pub Wall1D);
+
+#[pymethods]
+impl PyWall1D {
+    #[new]
+    #[pyo3(text_signature = "(width, potential, n_grid=None, potential_cutoff=None)")]
+    #[pyo3(signature = (width, potential, n_grid=None, potential_cutoff=None))]
+    fn new(
+        width: Length,
+        potential: PyExternalPotential,
+        n_grid: Option<usize>,
+        potential_cutoff: Option<f64>,
+    ) -> Self {
+        Self(Wall1D::new(width, potential.0, n_grid, potential_cutoff))
+    }
+
+    /// Initialize the wall for the given bulk state.
+    ///
+    /// Parameters
+    /// ----------
+    /// bulk : State
+    ///     The bulk state in equilibrium with the wall.
+    /// density : SIArray2, optional
+    ///     Initial values for the density profile.
+    /// external_potential : numpy.ndarray[float], optional
+    ///     The external potential of the wall.
+    /// empty : bool, optional
+    ///     Initialize a vapor-like profile with the densities
+    ///     of an ideal gas at the fugacities of the bulk phase instead
+    ///     of the bulk densities. Ignored if density is given.
+    ///     Defaults to False.
+    ///
+    /// Returns
+    /// -------
+    /// PoreProfile1D
+    #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None, empty=False)")]
+    #[pyo3(signature = (bulk, density=None, external_potential=None, empty=false))]
+    fn initialize(
+        &self,
+        bulk: &PyState,
+        density: Option<Density<Array2<f64>>>,
+        external_potential: Option<&Bound<'_, PyArray2<f64>>>,
+        empty: bool,
+    ) -> PyResult<PyPoreProfile1D> {
+        Ok(PyPoreProfile1D(
+            self.0
+                .initialize(
+                    &bulk.0,
+                    initial_guess(density.as_ref(), empty),
+                    external_potential.map(|e| e.to_owned_array()).as_ref(),
+                )
+                .map_err(PyFeosError::from)?,
+        ))
+    }
+
+    /// Calculate the adsorbed films of a pure component at the wall.
+    ///
+    /// The thin-film branch is calculated from low to high activities
+    /// and the thick-film branch from high to low activities, so that
+    /// a prewetting transition shows up as a jump in the film thickness.
+    ///
+    /// Parameters
+    /// ----------
+    /// functional : HelmholtzEnergyFunctional
+    ///     The Helmholtz energy functional.
+    /// temperature : SINumber
+    ///     The temperature.
+    /// activity : numpy.ndarray[float]
+    ///     The bulk activities p/p_sat in ascending order.
+    /// solver : DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// WettingIsotherm
+    ///
+    #[pyo3(text_signature = "($self, functional, temperature, activity, solver=None)")]
+    #[pyo3(signature = (functional, temperature, activity, solver=None))]
+    fn wetting_isotherm(
+        &self,
+        functional: &PyEquationOfState,
+        temperature: Temperature,
+        activity: PyReadonlyArray1<'_, f64>,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<PyWettingIsotherm> {
+        Ok(PyWettingIsotherm(
+            self.0
+                .wetting_isotherm(
+                    &functional.0,
+                    temperature,
+                    &activity.to_owned_array(),
+                    solver.map(|s| s.0).as_ref(),
+                )
+                .map_err(PyFeosError::from)?,
+        ))
+    }
+
+    /// Calculate the equilibrium thickness of the adsorbed film of a
+    /// pure component as a function of the undersaturation of the bulk
+    /// vapor.
+    ///
+    /// For complete wetting driven by dispersion forces, the film
+    /// thickness diverges as Δμ^(-1/3) upon approaching saturation.
+    ///
+    /// Parameters
+    /// ----------
+    /// functional : HelmholtzEnergyFunctional
+    ///     The Helmholtz energy functional.
+    /// temperature : SINumber
+    ///     The temperature.
+    /// undersaturation : SIArray1
+    ///     The undersaturations μ_sat - μ in descending order.
+    /// solver : DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    #[pyo3(text_signature = "($self, functional, temperature, undersaturation, solver=None)")]
+    #[pyo3(signature = (functional, temperature, undersaturation, solver=None))]
+    fn film_thickness(
+        &self,
+        functional: &PyEquationOfState,
+        temperature: Temperature,
+        undersaturation: MolarEnergy<Array1<f64>>,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<Length<Array1<f64>>> {
+        Ok(self
+            .0
+            .film_thickness(
+                &functional.0,
+                temperature,
+                &undersaturation,
+                solver.map(|s| s.0).as_ref(),
+            )
+            .map_err(PyFeosError::from)?)
+    }
+
+    #[getter]
+    fn get_width(&self) -> Length {
+        self.0.width
+    }
+
+    #[getter]
+    fn get_potential(&self) -> PyExternalPotential {
+        PyExternalPotential(self.0.potential.clone())
+    }
+
+    #[getter]
+    fn get_n_grid(&self) -> Option<usize> {
+        self.0.n_grid
+    }
+
+    #[getter]
+    fn get_potential_cutoff(&self) -> Option<f64> {
+        self.0.potential_cutoff
+    }
+}
+
+/// Parameters required to specify a single wall with a periodic,
+/// sinusoidal corrugation in contact with a semi-infinite bulk fluid.
+///
+/// Parameters
+/// ----------
+/// width : SINumber
+///     The width of the system normal to the wall.
+/// period : SINumber
+///     The period of the corrugation.
+/// amplitude : SINumber
+///     The amplitude of the corrugation.
+/// potential : ExternalPotential
+///     The potential used to model wall-fluid interactions.
+/// n_grid : [int, int]
+///     The number of grid points parallel and normal to the wall.
+/// potential_cutoff : float, optional
+///     Maximum value for the external potential.
+///
+/// Returns
+/// -------
+/// CorrugatedWall2D
+///
+#[pyclass(name = "CorrugatedWall2D")]
+pub struct PyCorrugatedWall2D(pub CorrugatedWall2D);
+
+#[pymethods]
+impl PyCorrugatedWall2D {
+    #[new]
+    #[pyo3(text_signature = "(width, period, amplitude, potential, n_grid, potential_cutoff=None)")]
+    #[pyo3(signature = (width, period, amplitude, potential, n_grid, potential_cutoff=None))]
+    fn new(
+        width: Length,
+        period: Length,
+        amplitude: Length,
+        potential: PyExternalPotential,
+        n_grid: [usize; 2],
+        potential_cutoff: Option<f64>,
+    ) -> Self {
+        Self(CorrugatedWall2D::new(
+            width,
+            period,
+            amplitude,
+            potential.0,
+            n_grid,
+            potential_cutoff,
+        ))
+    }
+
+    /// Solve the density profile at the wall and calculate the
+    /// effective solid-fluid interfacial tension per projected
+    /// area of the wall.
+    ///
+    /// Parameters
+    /// ----------
+    /// bulk : State
+    ///     The bulk state in equilibrium with the wall.
+    /// solver : DFTSolver, optional
+    ///     Custom solver options.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    #[pyo3(text_signature = "($self, bulk, solver=None)")]
+    #[pyo3(signature = (bulk, solver=None))]
+    fn solid_fluid_tension(
+        &self,
+        bulk: &PyState,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<SurfaceTension> {
+        Ok(self
+            .0
+            .solid_fluid_tension(&bulk.0, solver.map(|s| s.0).as_ref())
+            .map_err(PyFeosError::from)?)
+    }
+}
+
+/// Adsorbed films at a single wall on the thin-film and the
+/// thick-film branch.
+#[pyclass(name = "WettingIsotherm")]
+pub struct PyWettingIsotherm(
+    WettingIsotherm<Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>,
+);
+
+#[pymethods]
+impl PyWettingIsotherm {
+    #[getter]
+    fn get_activity<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        self.0.activity.to_pyarray(py)
+    }
+
+    #[getter]
+    fn get_thin_film(&self) -> Vec<PyPoreProfile1D> {
+        self.0
+            .thin_film
+            .iter()
+            .filter_map(|p| p.as_ref().ok().map(|p| PyPoreProfile1D(p.clone())))
+            .collect()
+    }
+
+    #[getter]
+    fn get_thick_film(&self) -> Vec<PyPoreProfile1D> {
+        self.0
+            .thick_film
+            .iter()
+            .filter_map(|p| p.as_ref().ok().map(|p| PyPoreProfile1D(p.clone())))
+            .collect()
+    }
+
+    /// Film thickness on the thin-film and the thick-film branch.
+    ///
+    /// Returns
+    /// -------
+    /// (SIArray1, SIArray1)
+    ///
+    fn film_thickness(&self) -> (Length<Array1<f64>>, Length<Array1<f64>>) {
+        self.0.film_thickness()
+    }
+
+    /// Grand potential on the thin-film and the thick-film branch.
+    ///
+    /// Returns
+    /// -------
+    /// (SIArray1, SIArray1)
+    ///
+    fn grand_potential(&self) -> (Energy<Array1<f64>>, Energy<Array1<f64>>) {
+        self.0.grand_potential()
+    }
+
+    /// Undersaturation μ_sat - μ of the bulk vapor at every activity.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn undersaturation(&self) -> PyResult<MolarEnergy<Array1<f64>>> {
+        Ok(self.0.undersaturation().map_err(PyFeosError::from)?)
+    }
+
+    /// Film thickness of the branch with the lower grand potential.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn equilibrium_film_thickness(&self) -> Length<Array1<f64>> {
+        self.0.equilibrium_film_thickness()
+    }
+}
@@ -0,0 +1,101 @@
+use crate::dft::profile::{impl_1d_profile, impl_profile};
+use crate::dft::{PyDFTSolver, PyDFTSolverLog};
+use crate::error::PyFeosError;
+use crate::ideal_gas::IdealGasModel;
+use crate::residual::ResidualModel;
+use crate::state::{PyContributions, PyState};
+use feos_core::{EquationOfState, ReferenceSystem};
+use feos_dft::interface::Droplet;
+use nalgebra::{DMatrix, DVector};
+use ndarray::*;
+use numpy::*;
+use pyo3::*;
+use quantity::*;
+use std::sync::Arc;
+
+/// A spherical density profile of a critical nucleus in a supersaturated vapor.
+#[pyclass(name = "Droplet")]
+pub struct PyDroplet(pub Droplet<Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>);
+
+impl_1d_profile!(PyDroplet, [get_r]);
+
+#[pymethods]
+impl PyDroplet {
+    /// Initialize a droplet with a hyperbolic tangent.
+    ///
+    /// Parameters
+    /// ----------
+    /// vapor : State
+    ///     The supersaturated bulk vapor.
+    /// liquid : State
+    ///     The liquid phase used to initialize the inside of the droplet.
+    /// n_grid : int
+    ///     The number of grid points.
+    /// l_grid: SINumber
+    ///     The radius of the calculation domain.
+    /// radius: SINumber
+    ///     An estimate for the radius of the critical nucleus.
+    ///
+    /// Returns
+    /// -------
+    /// Droplet
+    ///
+    #[staticmethod]
+    fn from_tanh(
+        vapor: &PyState,
+        liquid: &PyState,
+        n_grid: usize,
+        l_grid: Length,
+        radius: Length,
+    ) -> Self {
+        PyDroplet(Droplet::from_tanh(
+            &vapor.0, &liquid.0, n_grid, l_grid, radius,
+        ))
+    }
+
+    #[getter]
+    fn get_vapor(&self) -> PyState {
+        PyState(self.0.vapor.clone())
+    }
+
+    #[getter]
+    fn get_nucleation_barrier(&self) -> Option<Energy> {
+        self.0.nucleation_barrier
+    }
+
+    #[getter]
+    fn get_critical_radius(&self) -> Option<Length> {
+        self.0.critical_radius
+    }
+
+    #[getter]
+    fn get_nucleus_moles(&self) -> Option<Moles> {
+        self.0.nucleus_moles
+    }
+
+    /// Calculates the Laplace pressure, i.e., the difference between the
+    /// pressure in the center of the droplet and the vapor pressure.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn laplace_pressure(&self) -> PyResult<Pressure> {
+        Ok(self.0.laplace_pressure().map_err(PyFeosError::from)?)
+    }
+
+    /// Calculates the relative deviation of the Laplace pressure from
+    /// 2*gamma/R, with R the critical radius and gamma the surface
+    /// tension that follows from the nucleation barrier.
+    ///
+    /// Returns
+    /// -------
+    /// float or None
+    ///
+    fn laplace_consistency_residual(&self) -> PyResult<Option<f64>> {
+        Ok(self
+            .0
+            .laplace_consistency_residual()
+            .map_err(PyFeosError::from)?)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::dft::profile::{impl_1d_profile, impl_profile};
+use crate::dft::{PyDFTSolver, PyDFTSolverLog};
+use crate::error::PyFeosError;
+use crate::ideal_gas::IdealGasModel;
+use crate::residual::ResidualModel;
+use crate::state::{PyContributions, PyState};
+use feos_core::{EquationOfState, ReferenceSystem};
+use feos_dft::interface::FreeSurface;
+use nalgebra::{DMatrix, DVector};
+use ndarray::*;
+use numpy::*;
+use pyo3::*;
+use quantity::*;
+use std::sync::Arc;
+
+/// A one-dimensional density profile of a liquid film with a free surface towards vacuum.
+#[pyclass(name = "FreeSurface")]
+pub struct PyFreeSurface(pub FreeSurface<Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>);
+
+impl_1d_profile!(PyFreeSurface, [get_z]);
+
+#[pymethods]
+impl PyFreeSurface {
+    /// Initialize a liquid film with a one-sided hyperbolic tangent.
+    ///
+    /// Parameters
+    /// ----------
+    /// liquid : State
+    ///     The liquid phase used to initialize the film.
+    /// n_grid : int
+    ///     The number of grid points.
+    /// l_grid: SINumber
+    ///     The size of the calculation domain.
+    /// thickness: SINumber
+    ///     The initial thickness of the film.
+    ///
+    /// Returns
+    /// -------
+    /// FreeSurface
+    ///
+    #[staticmethod]
+    fn from_tanh(liquid: &PyState, n_grid: usize, l_grid: Length, thickness: Length) -> Self {
+        PyFreeSurface(FreeSurface::from_tanh(&liquid.0, n_grid, l_grid, thickness))
+    }
+
+    #[getter]
+    fn get_liquid(&self) -> PyState {
+        PyState(self.0.liquid.clone())
+    }
+
+    #[getter]
+    fn get_surface_tension(&self) -> Option<SurfaceTension> {
+        self.0.surface_tension
+    }
+
+    #[getter]
+    fn get_vacuum_density(&self) -> Density<DVector<f64>> {
+        self.0.vacuum_density()
+    }
+
+    /// Checks whether the vacuum region is sufficiently dilute.
+    ///
+    /// Parameters
+    /// ----------
+    /// tol : float
+    ///     The maximum ratio of the density in the vacuum region
+    ///     and the density of the liquid.
+    ///
+    #[pyo3(signature = (tol=1e-3), text_signature = "($self, tol=1e-3)")]
+    fn check_vacuum(&self, tol: f64) -> PyResult<()> {
+        Ok(self.0.check_vacuum(tol).map_err(PyFeosError::from)?)
+    }
+}
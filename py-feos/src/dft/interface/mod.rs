@@ -1,26 +1,152 @@
+use super::adsorption::PyPoreProfile1D;
 use super::profile::{impl_1d_profile, impl_profile};
 use super::{PyDFTSolver, PyDFTSolverLog};
+use crate::eos::PyEquationOfState;
 use crate::error::PyFeosError;
 use crate::ideal_gas::IdealGasModel;
 use crate::phase_equilibria::PyPhaseEquilibrium;
 use crate::residual::ResidualModel;
 use crate::state::{PyContributions, PyState};
-use feos_core::{EquationOfState, ReferenceSystem};
-use feos_dft::interface::PlanarInterface;
+use ::indexmap::IndexMap;
+use feos_core::{EquationOfState, FeosError, ReferenceSystem};
+use feos_dft::interface::{
+    ComponentInterfacialProperties, DensityGradient, MIN_WIDTH, PlanarInterface, RELATIVE_WIDTH,
+    WettingState,
+};
 use nalgebra::{DMatrix, DVector};
 use ndarray::*;
 use numpy::*;
-use pyo3::*;
+use pyo3::prelude::*;
 use quantity::*;
+use std::cell::RefCell;
 use std::sync::Arc;
 
+mod droplet;
+mod free_surface;
 mod surface_tension_diagram;
+pub use droplet::PyDroplet;
+pub use free_surface::PyFreeSurface;
 pub use surface_tension_diagram::PySurfaceTensionDiagram;
 
+/// Calculate the vapor-liquid surface tension of a pure component.
+///
+/// The phase equilibrium is calculated and the interface is
+/// initialized from pDGT with an automatically chosen width of
+/// the calculation domain and solved.
+///
+/// Parameters
+/// ----------
+/// functional : HelmholtzEnergyFunctional
+///     The Helmholtz energy functional.
+/// temperature : SINumber
+///     The temperature.
+/// solver : DFTSolver, optional
+///     Custom solver options.
+///
+/// Returns
+/// -------
+/// SINumber
+///
+#[pyfunction]
+#[pyo3(signature = (functional, temperature, solver=None))]
+pub fn surface_tension(
+    functional: &PyEquationOfState,
+    temperature: Temperature,
+    solver: Option<PyDFTSolver>,
+) -> PyResult<SurfaceTension> {
+    Ok(feos_dft::interface::surface_tension(
+        &functional.0,
+        temperature,
+        solver.map(|s| s.0).as_ref(),
+    )
+    .map_err(PyFeosError::from)?)
+}
+
+/// Calculate the temperature at which the vapor-liquid surface
+/// tension of a pure component equals the target value.
+///
+/// The temperature is determined by bisection within the bracket,
+/// where the surface tension is calculated as in `surface_tension`
+/// at every temperature.
+///
+/// Parameters
+/// ----------
+/// functional : HelmholtzEnergyFunctional
+///     The Helmholtz energy functional.
+/// target : SINumber
+///     The target surface tension.
+/// bracket : (SINumber, SINumber)
+///     Temperatures below the critical temperature that enclose
+///     the target surface tension.
+/// solver : DFTSolver, optional
+///     Custom solver options.
+///
+/// Returns
+/// -------
+/// SINumber
+///
+#[pyfunction]
+#[pyo3(signature = (functional, target, bracket, solver=None))]
+pub fn temperature_for_surface_tension(
+    functional: &PyEquationOfState,
+    target: SurfaceTension,
+    bracket: (Temperature, Temperature),
+    solver: Option<PyDFTSolver>,
+) -> PyResult<Temperature> {
+    Ok(feos_dft::interface::temperature_for_surface_tension(
+        &functional.0,
+        target,
+        bracket,
+        solver.map(|s| s.0).as_ref(),
+    )
+    .map_err(PyFeosError::from)?)
+}
+
+/// Wetting of a wall by a liquid in coexistence with its vapor.
+#[derive(Clone, Copy, PartialEq)]
+#[pyclass(name = "WettingState", eq, eq_int)]
+pub enum PyWettingState {
+    Complete,
+    Partial,
+    Dry,
+}
+
+/// Interfacial properties of a single component of a planar interface.
+#[pyclass(name = "ComponentInterfacialProperties")]
+pub struct PyComponentInterfacialProperties(ComponentInterfacialProperties);
+
+#[pymethods]
+impl PyComponentInterfacialProperties {
+    /// The index of the component.
+    #[getter]
+    fn get_component(&self) -> usize {
+        self.0.component
+    }
+
+    /// The indices of the density profiles (segments) of the component.
+    #[getter]
+    fn get_segments(&self) -> Vec<usize> {
+        self.0.segments.clone()
+    }
+
+    /// The relative adsorption of the component with respect to every
+    /// component.
+    #[getter]
+    fn get_relative_adsorption(&self) -> Moles<DVector<f64>> {
+        self.0.relative_adsorption.clone()
+    }
+
+    /// The interfacial enrichment of the component.
+    #[getter]
+    fn get_interfacial_enrichment(&self) -> f64 {
+        self.0.interfacial_enrichment
+    }
+}
+
 /// A one-dimensional density profile of a vapor-liquid or liquid-liquid interface.
 #[pyclass(name = "PlanarInterface")]
 pub struct PyPlanarInterface(
-    PlanarInterface<Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>,
+    pub PlanarInterface<Arc<EquationOfState<Vec<IdealGasModel>, ResidualModel>>>,
 );
 
 impl_1d_profile!(PyPlanarInterface, [get_z]);
@@ -71,6 +197,110 @@ impl PyPlanarInterface {
         PyPlanarInterface(profile)
     }
 
+    /// Initialize a liquid slab with two interfaces on a periodic domain
+    /// with hyperbolic tangents.
+    ///
+    /// The bulk liquid is located at the edges and the bulk vapor in the
+    /// center of the domain. Surface tension and adsorption are given per
+    /// interface.
+    ///
+    /// Parameters
+    /// ----------
+    /// vle : PhaseEquilibrium
+    ///     The bulk phase equilibrium.
+    /// n_grid : int
+    ///     The number of grid points.
+    /// l_grid: SINumber
+    ///     The width of the periodic calculation domain.
+    /// critical_temperature: SINumber
+    ///     An estimate for the critical temperature of the system.
+    ///     Used to guess the width of the interfaces.
+    /// fix_equimolar_surface: bool, optional
+    ///     If True use additional constraints to fix the
+    ///     equimolar surfaces of the system.
+    ///     Defaults to False.
+    ///
+    /// Returns
+    /// -------
+    /// PlanarInterface
+    ///
+    /// Initialize a planar interface between two bulk states that are
+    /// not necessarily in equilibrium with a hyperbolic tangent.
+    ///
+    /// For states that are not in equilibrium, e.g., a superheated
+    /// liquid, the surface tension is a constrained interfacial
+    /// tension of metastable states.
+    ///
+    /// Parameters
+    /// ----------
+    /// vapor : State
+    ///     The bulk vapor.
+    /// liquid : State
+    ///     The bulk liquid at the same temperature.
+    /// n_grid : int
+    ///     The number of grid points.
+    /// l_grid: SINumber
+    ///     The width of the calculation domain.
+    /// critical_temperature: SINumber
+    ///     An estimate for the critical temperature of the system.
+    ///     Used to guess the width of the interface.
+    /// fix_equimolar_surface: bool, optional
+    ///     If True use additional constraints to fix the
+    ///     equimolar surface of the system.
+    ///     Defaults to True.
+    ///
+    /// Returns
+    /// -------
+    /// PlanarInterface
+    ///
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(vapor, liquid, n_grid, l_grid, critical_temperature, fix_equimolar_surface=True)"
+    )]
+    #[pyo3(signature = (vapor, liquid, n_grid, l_grid, critical_temperature, fix_equimolar_surface=true))]
+    fn from_states(
+        vapor: &PyState,
+        liquid: &PyState,
+        n_grid: usize,
+        l_grid: Length,
+        critical_temperature: Temperature,
+        fix_equimolar_surface: bool,
+    ) -> PyResult<Self> {
+        Ok(PyPlanarInterface(
+            PlanarInterface::from_states(
+                &vapor.0,
+                &liquid.0,
+                n_grid,
+                l_grid,
+                critical_temperature,
+                fix_equimolar_surface,
+            )
+            .map_err(PyFeosError::from)?,
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(vle, n_grid, l_grid, critical_temperature, fix_equimolar_surface=None)"
+    )]
+    #[pyo3(signature = (vle, n_grid, l_grid, critical_temperature, fix_equimolar_surface=None))]
+    fn from_tanh_periodic(
+        vle: &PyPhaseEquilibrium,
+        n_grid: usize,
+        l_grid: Length,
+        critical_temperature: Temperature,
+        fix_equimolar_surface: Option<bool>,
+    ) -> Self {
+        let profile = PlanarInterface::from_tanh_periodic(
+            &vle.0,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface.unwrap_or(false),
+        );
+        PyPlanarInterface(profile)
+    }
+
     /// Initialize a planar interface with a pDGT calculation.
     ///
     /// Parameters
@@ -83,25 +313,72 @@ impl PyPlanarInterface {
     ///     If True use additional constraints to fix the
     ///     equimolar surface of the system.
     ///     Defaults to False.
+    /// relative_width: float, optional
+    ///     The width of the calculation domain relative to the
+    ///     width of the pDGT profile.
+    ///     Defaults to 6.
+    /// min_width: SINumber, optional
+    ///     The minimum width of the calculation domain.
+    ///     Defaults to 100 Å.
     ///
     /// Returns
     /// -------
     /// PlanarInterface
     ///
     #[staticmethod]
-    #[pyo3(text_signature = "(vle, n_grid, fix_equimolar_surface=None)")]
-    #[pyo3(signature = (vle, n_grid, fix_equimolar_surface=None))]
+    #[pyo3(
+        text_signature = "(vle, n_grid, fix_equimolar_surface=None, relative_width=None, min_width=None)"
+    )]
+    #[pyo3(signature = (vle, n_grid, fix_equimolar_surface=None, relative_width=None, min_width=None))]
     fn from_pdgt(
         vle: &PyPhaseEquilibrium,
         n_grid: usize,
         fix_equimolar_surface: Option<bool>,
+        relative_width: Option<f64>,
+        min_width: Option<Length>,
     ) -> PyResult<Self> {
-        let profile =
-            PlanarInterface::from_pdgt(&vle.0, n_grid, fix_equimolar_surface.unwrap_or(false))
-                .map_err(PyFeosError::from)?;
+        let profile = PlanarInterface::from_pdgt_with_width(
+            &vle.0,
+            n_grid,
+            fix_equimolar_surface.unwrap_or(false),
+            relative_width.unwrap_or(RELATIVE_WIDTH),
+            min_width.unwrap_or(Length::from_reduced(MIN_WIDTH)),
+        )
+        .map_err(PyFeosError::from)?;
         Ok(PyPlanarInterface(profile))
     }
 
+    /// Calculate the surface tension from a pDGT calculation.
+    ///
+    /// In contrast to `from_pdgt`, the result is not checked and can
+    /// be negative (e.g., close to wetting transitions) or NaN (if the
+    /// pDGT calculation diverged).
+    ///
+    /// Parameters
+    /// ----------
+    /// vle : PhaseEquilibrium
+    ///     The bulk phase equilibrium.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    #[staticmethod]
+    fn pdgt_surface_tension(vle: &PyPhaseEquilibrium) -> PyResult<SurfaceTension> {
+        Ok(PlanarInterface::pdgt_surface_tension(&vle.0).map_err(PyFeosError::from)?)
+    }
+
+    /// Surface tension of the phase equilibrium of the interface
+    /// from perturbed density gradient theory (pDGT).
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn surface_tension_pdgt(&self) -> PyResult<SurfaceTension> {
+        Ok(self.0.surface_tension_pdgt().map_err(PyFeosError::from)?)
+    }
+
     /// Initialize a planar interface with a provided density profile.
     ///
     /// Parameters
@@ -125,10 +402,195 @@ impl PyPlanarInterface {
         n_grid: usize,
         l_grid: Length,
         density_profile: Density<Array2<f64>>,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let mut profile = PlanarInterface::new(&vle.0, n_grid, l_grid);
-        profile.profile.density = density_profile;
-        PyPlanarInterface(profile)
+        profile
+            .set_density_inplace(&density_profile, false)
+            .map_err(PyFeosError::from)?;
+        Ok(PyPlanarInterface(profile))
+    }
+
+    /// Evaluate the convolutions on a grid that is coarsened by `factor`.
+    ///
+    /// The profiles are averaged over blocks of `factor` grid points
+    /// before the convolutions and the results are interpolated back
+    /// to the full grid. This is faster, but less accurate than the
+    /// exact convolutions on the full grid (default).
+    ///
+    /// Parameters
+    /// ----------
+    /// factor : int
+    ///     The coarsening factor. Has to divide the number of grid points.
+    ///
+    fn coarsen_convolution(&mut self, factor: usize) -> PyResult<()> {
+        Ok(self
+            .0
+            .profile
+            .coarsen_convolution(factor)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Set the density profile of the interface.
+    ///
+    /// Parameters
+    /// ----------
+    /// density_profile: SIArray2
+    ///     The new density profile. Has to be of the same shape
+    ///     as the current density profile.
+    /// scale: bool, optional
+    ///     If True, the profile is rescaled to the bulk densities
+    ///     of the interface. Defaults to False.
+    ///
+    #[pyo3(signature = (density_profile, scale=false), text_signature = "($self, density_profile, scale=False)")]
+    fn set_density(&mut self, density_profile: Density<Array2<f64>>, scale: bool) -> PyResult<()> {
+        Ok(self
+            .0
+            .set_density_inplace(&density_profile, scale)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Solves the interface for a different functional, e.g., with
+    /// slightly perturbed parameters, starting from the current
+    /// density profile.
+    ///
+    /// The coexisting phases are recalculated at the same temperature
+    /// (and for mixtures at the same liquid composition).
+    ///
+    /// Parameters
+    /// ----------
+    /// functional : HelmholtzEnergyFunctional
+    ///     The new Helmholtz energy functional.
+    /// solver : DFTSolver, optional
+    ///     The solver used to solve the density profile.
+    ///
+    /// Returns
+    /// -------
+    /// PlanarInterface
+    ///
+    #[pyo3(signature = (functional, solver=None), text_signature = "($self, functional, solver=None)")]
+    fn resolve_with(
+        &self,
+        functional: &PyEquationOfState,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<Self> {
+        Ok(Self(
+            self.0
+                .resolve_with(&functional.0, solver.map(|s| s.0).as_ref())
+                .map_err(PyFeosError::from)?,
+        ))
+    }
+
+    /// Calculates the sensitivities of the surface tension to
+    /// parameters of the functional with central differences.
+    ///
+    /// Both perturbed interfaces are solved starting from the
+    /// current density profile (see `resolve_with`).
+    ///
+    /// Parameters
+    /// ----------
+    /// perturbations : list[Callable[[float], HelmholtzEnergyFunctional]]
+    ///     Functions that return the functional with the respective
+    ///     parameter shifted by the given value.
+    /// step : float
+    ///     The shift of the parameters.
+    /// solver : DFTSolver, optional
+    ///     The solver used to solve the density profiles.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    #[pyo3(
+        signature = (perturbations, step, solver=None),
+        text_signature = "($self, perturbations, step, solver=None)"
+    )]
+    fn surface_tension_sensitivity(
+        &self,
+        perturbations: Vec<Bound<'_, PyAny>>,
+        step: f64,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<SurfaceTension<Array1<f64>>> {
+        let error = RefCell::new(None);
+        let perturbations: Vec<_> = perturbations
+            .iter()
+            .map(|p| {
+                let error = &error;
+                move |delta: f64| {
+                    p.call1((delta,))
+                        .and_then(|f| Ok(f.extract::<PyRef<PyEquationOfState>>()?.0.clone()))
+                        .map_err(|err| {
+                            let message = err.to_string();
+                            *error.borrow_mut() = Some(err);
+                            FeosError::Error(message)
+                        })
+                }
+            })
+            .collect();
+        let sensitivity = self.0.surface_tension_sensitivity(
+            &perturbations,
+            step,
+            solver.map(|s| s.0).as_ref(),
+        );
+        match error.into_inner() {
+            Some(err) => Err(err),
+            None => Ok(sensitivity.map_err(PyFeosError::from)?),
+        }
+    }
+
+    /// Set an arbitrary external potential acting on each segment.
+    ///
+    /// The potential should vanish at the boundaries of the domain.
+    /// Previous results of the solved interface are reset.
+    ///
+    /// Parameters
+    /// ----------
+    /// external_potential: numpy.ndarray[float]
+    ///     The external potential in units of kT. Has to be of
+    ///     the same shape as the density profile.
+    ///
+    fn set_external_potential(
+        &mut self,
+        external_potential: PyReadonlyArray2<f64>,
+    ) -> PyResult<()> {
+        Ok(self
+            .0
+            .set_external_potential_inplace(external_potential.to_owned_array())
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Fix the chemical potentials of the given components and the
+    /// total number of moles of all other components.
+    ///
+    /// Use this for gases dissolved in a liquid at fixed partial
+    /// pressures. Call after initializing the density profile.
+    /// Previous results of the solved interface are reset.
+    ///
+    /// Parameters
+    /// ----------
+    /// components: [int]
+    ///     Indices of the components with fixed chemical potentials.
+    ///
+    fn fix_chemical_potentials(&mut self, components: Vec<usize>) -> PyResult<()> {
+        Ok(self
+            .0
+            .fix_chemical_potentials_inplace(&components)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Replace the bulk phases in `vle` by the states at the
+    /// boundaries of the density profile.
+    ///
+    /// The surface tension and the equimolar radius of solved
+    /// profiles are recalculated with the new bulk phases. Use
+    /// this after manual modifications of the density profile
+    /// or if the calculation domain is too narrow for the profile
+    /// to reach the bulk densities.
+    ///
+    fn update_vle_from_profile(&mut self) -> PyResult<()> {
+        Ok(self
+            .0
+            .update_vle_from_profile_inplace()
+            .map_err(PyFeosError::from)?)
     }
 }
 
@@ -160,6 +622,79 @@ impl PyPlanarInterface {
         self.0.relative_adsorption()
     }
 
+    /// Calculates the number of moles of each component within a band
+    /// centered on the equimolar dividing surface.
+    ///
+    /// Parameters
+    /// ----------
+    /// half_width : SINumber
+    ///     Half of the width of the band.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn interfacial_moles(&self, half_width: Length) -> Moles<DVector<f64>> {
+        self.0.interfacial_moles(half_width)
+    }
+
+    /// Calculates the number of moles of each component within a band
+    /// centered on an explicit dividing surface.
+    ///
+    /// Parameters
+    /// ----------
+    /// dividing_surface : SINumber
+    ///     The position of the dividing surface.
+    /// half_width : SINumber
+    ///     Half of the width of the band.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn interfacial_moles_at(
+        &self,
+        dividing_surface: Length,
+        half_width: Length,
+    ) -> Moles<DVector<f64>> {
+        self.0.interfacial_moles_at(dividing_surface, half_width)
+    }
+
+    /// Calculates the position of the equimolar dividing surface
+    /// without shifting the grid.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn equimolar_dividing_surface(&self) -> PyResult<Length> {
+        Ok(self
+            .0
+            .equimolar_dividing_surface()
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculates the adsorption of every component with respect
+    /// to an explicit dividing surface: \Gamma_i(z_s)
+    ///
+    /// In the reference system, the bulk liquid fills the domain
+    /// up to the dividing surface and the bulk vapor the rest of
+    /// the domain. Positive values indicate an enrichment of the
+    /// component at the interface.
+    ///
+    /// Parameters
+    /// ----------
+    /// dividing_surface : SINumber
+    ///     The position of the dividing surface.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn adsorption_at_dividing_surface(&self, dividing_surface: Length) -> Moles<Array1<f64>> {
+        self.0.adsorption_at_dividing_surface(dividing_surface)
+    }
+
     /// Calculates the interfacial enrichment E_i.
     ///
     /// Returns
@@ -170,6 +705,145 @@ impl PyPlanarInterface {
         self.0.interfacial_enrichment().to_pyarray(py)
     }
 
+    /// Calculates the density profiles of the components, i.e., the
+    /// average of the density profiles of all segments of a component.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray2
+    ///
+    fn component_density(&self) -> Density<Array2<f64>> {
+        self.0.component_density()
+    }
+
+    /// Calculates the interfacial properties of every component
+    /// labeled with the index of the component and its segments.
+    ///
+    /// Returns
+    /// -------
+    /// List[ComponentInterfacialProperties]
+    ///
+    fn component_properties(&self) -> Vec<PyComponentInterfacialProperties> {
+        self.0
+            .component_properties()
+            .into_iter()
+            .map(PyComponentInterfacialProperties)
+            .collect()
+    }
+
+    /// Calculates the interfacial enrichment (or depletion) E_i and the
+    /// position of the corresponding extremum of the density profile.
+    ///
+    /// Parameters
+    /// ----------
+    /// depletion : bool, optional
+    ///     If True, report the depletion min(rho_i)/min(rho_i^L, rho_i^V)
+    ///     at the minimum instead of the enrichment
+    ///     max(rho_i)/max(rho_i^L, rho_i^V) at the maximum.
+    ///     Defaults to False.
+    ///
+    /// Returns
+    /// -------
+    /// (numpy.ndarray, SIArray1)
+    ///
+    #[pyo3(signature = (depletion=false), text_signature = "($self, depletion=False)")]
+    fn interfacial_enrichment_extremum<'py>(
+        &self,
+        py: Python<'py>,
+        depletion: bool,
+    ) -> (Bound<'py, PyArray1<f64>>, Length<Array1<f64>>) {
+        let (enrichment, position) = self.0.interfacial_enrichment_extremum(depletion);
+        (enrichment.to_pyarray(py), position)
+    }
+
+    /// Estimates the surface tension in the limit of infinite grid resolution.
+    ///
+    /// The interface is solved on the current grid and on grids with two
+    /// and four times as many grid points.
+    ///
+    /// Parameters
+    /// ----------
+    /// solver : DFTSolver, optional
+    ///     The solver used to solve the density profiles.
+    ///
+    /// Returns
+    /// -------
+    /// (SINumber, SINumber)
+    ///     The extrapolated surface tension and the estimated
+    ///     error on the finest grid.
+    ///
+    #[pyo3(signature = (solver=None), text_signature = "($self, solver=None)")]
+    fn richardson_extrapolation(
+        &self,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<(SurfaceTension, SurfaceTension)> {
+        Ok(self
+            .0
+            .richardson_extrapolation(solver.map(|s| s.0).as_ref())
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Estimates the error of the surface tension due to the
+    /// incomplete convergence of the density profile.
+    ///
+    /// The interface is solved again with the tolerance reduced
+    /// to a tenth of the final residual.
+    ///
+    /// Parameters
+    /// ----------
+    /// solver : DFTSolver, optional
+    ///     The solver used to solve the density profile.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    #[pyo3(signature = (solver=None), text_signature = "($self, solver=None)")]
+    fn convergence_error(&self, solver: Option<PyDFTSolver>) -> PyResult<SurfaceTension> {
+        Ok(self
+            .0
+            .convergence_error(solver.map(|s| s.0).as_ref())
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Estimates the surface tension with its numerical uncertainty.
+    ///
+    /// The uncertainty combines the discretization error from the
+    /// Richardson extrapolation and the convergence error on the
+    /// finest grid.
+    ///
+    /// Parameters
+    /// ----------
+    /// solver : DFTSolver, optional
+    ///     The solver used to solve the density profiles.
+    ///
+    /// Returns
+    /// -------
+    /// (SINumber, SINumber)
+    ///     The extrapolated surface tension and its uncertainty.
+    ///
+    #[pyo3(signature = (solver=None), text_signature = "($self, solver=None)")]
+    fn surface_tension_with_uncertainty(
+        &self,
+        solver: Option<PyDFTSolver>,
+    ) -> PyResult<(SurfaceTension, SurfaceTension)> {
+        Ok(self
+            .0
+            .surface_tension_with_uncertainty(solver.map(|s| s.0).as_ref())
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Fits a hyperbolic tangent to the total density profile.
+    ///
+    /// Returns
+    /// -------
+    /// (SINumber, SINumber)
+    ///     The width w and the position z0 of the interface.
+    ///
+    fn fit_tanh_width(&self) -> PyResult<(Length, Length)> {
+        Ok(self.0.fit_tanh_width().map_err(PyFeosError::from)?)
+    }
+
     /// Calculates the interfacial thickness (90-10 number density difference)
     ///
     /// Returns
@@ -179,4 +853,322 @@ impl PyPlanarInterface {
     fn interfacial_thickness(&self) -> PyResult<Length> {
         Ok(self.0.interfacial_thickness().map_err(PyFeosError::from)?)
     }
+
+    /// Density profile mirrored at the vapor boundary of the domain.
+    ///
+    /// Can be used to initialize a liquid slab on a periodic
+    /// domain with twice the width.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray2
+    ///
+    fn mirrored_density(&self) -> PyResult<Density<Array2<f64>>> {
+        Ok(self.0.mirrored_density().map_err(PyFeosError::from)?)
+    }
+
+    /// Contributions to the surface tension: the integrated grand
+    /// potential density and the offset from the bulk pressure.
+    ///
+    /// Returns
+    /// -------
+    /// (SINumber, SINumber)
+    ///
+    fn surface_tension_contributions(&self) -> PyResult<(SurfaceTension, SurfaceTension)> {
+        Ok(self
+            .0
+            .surface_tension_contributions()
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Work of cohesion W_c = 2 gamma of the solved interface.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn work_of_cohesion(&self) -> PyResult<SurfaceTension> {
+        Ok(self.0.work_of_cohesion().map_err(PyFeosError::from)?)
+    }
+
+    /// Work of adhesion W_a = gamma_lv + gamma_sv - gamma_sl from the
+    /// interface and the tensions of a planar wall in contact with
+    /// the coexisting vapor and liquid.
+    ///
+    /// Parameters
+    /// ----------
+    /// vapor : PoreProfile1D
+    ///     The solved profile at the wall in contact with the vapor.
+    /// liquid : PoreProfile1D
+    ///     The solved profile at the wall in contact with the liquid.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn work_of_adhesion(
+        &self,
+        vapor: &PyPoreProfile1D,
+        liquid: &PyPoreProfile1D,
+    ) -> PyResult<SurfaceTension> {
+        Ok(self
+            .0
+            .work_of_adhesion(&vapor.0, &liquid.0)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Spreading coefficient S = gamma_sv - gamma_sl - gamma_lv from the
+    /// interface and the tensions of a planar wall in contact with
+    /// the coexisting vapor and liquid.
+    ///
+    /// Parameters
+    /// ----------
+    /// vapor : PoreProfile1D
+    ///     The solved profile at the wall in contact with the vapor.
+    /// liquid : PoreProfile1D
+    ///     The solved profile at the wall in contact with the liquid.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn spreading_coefficient(
+        &self,
+        vapor: &PyPoreProfile1D,
+        liquid: &PyPoreProfile1D,
+    ) -> PyResult<SurfaceTension> {
+        Ok(self
+            .0
+            .spreading_coefficient(&vapor.0, &liquid.0)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Whether the liquid spreads on the wall, i.e., whether the
+    /// spreading coefficient is positive.
+    ///
+    /// Parameters
+    /// ----------
+    /// vapor : PoreProfile1D
+    ///     The solved profile at the wall in contact with the vapor.
+    /// liquid : PoreProfile1D
+    ///     The solved profile at the wall in contact with the liquid.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///
+    fn is_spreading(&self, vapor: &PyPoreProfile1D, liquid: &PyPoreProfile1D) -> PyResult<bool> {
+        Ok(self
+            .0
+            .is_spreading(&vapor.0, &liquid.0)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Classifies the wetting of a wall by the liquid of the interface
+    /// using Young's equation.
+    ///
+    /// Parameters
+    /// ----------
+    /// vapor : PoreProfile1D
+    ///     The solved profile at the wall in contact with the vapor.
+    /// liquid : PoreProfile1D
+    ///     The solved profile at the wall in contact with the liquid.
+    ///
+    /// Returns
+    /// -------
+    /// (WettingState, Angle | None)
+    ///     The wetting state and, for partial wetting, the contact angle.
+    ///
+    fn wetting_state(
+        &self,
+        vapor: &PyPoreProfile1D,
+        liquid: &PyPoreProfile1D,
+    ) -> PyResult<(PyWettingState, Option<Angle>)> {
+        Ok(
+            match self
+                .0
+                .wetting_state(&vapor.0, &liquid.0)
+                .map_err(PyFeosError::from)?
+            {
+                WettingState::Complete => (PyWettingState::Complete, None),
+                WettingState::Partial(theta) => (PyWettingState::Partial, Some(theta)),
+                WettingState::Dry => (PyWettingState::Dry, None),
+            },
+        )
+    }
+
+    /// Bond number of the interface for a characteristic length
+    /// and standard gravity.
+    ///
+    /// For Bond numbers much smaller than 1, the shape of the
+    /// interface is governed by the surface tension and gravity
+    /// can be neglected.
+    ///
+    /// Parameters
+    /// ----------
+    /// length : SINumber
+    ///     The characteristic length, e.g., the size of a droplet.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    fn bond_number(&self, length: Length) -> PyResult<f64> {
+        Ok(self.0.bond_number(length).map_err(PyFeosError::from)?)
+    }
+
+    /// Surface tension reduced with the characteristic energy and size
+    /// of the fluid: gamma* = gamma sigma^2 / epsilon.
+    ///
+    /// Only available for fluids that consist of a single segment.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    fn surface_tension_reduced_cs(&self) -> PyResult<f64> {
+        Ok(self
+            .0
+            .surface_tension_reduced_cs()
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Absolute difference between the surface tensions of two
+    /// solved planar interfaces.
+    ///
+    /// Parameters
+    /// ----------
+    /// other : PlanarInterface
+    ///     The interface to compare with.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    fn surface_tension_difference(&self, other: &Self) -> PyResult<SurfaceTension> {
+        Ok(self
+            .0
+            .surface_tension_difference(&other.0)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Diagnostic for interfaces of non-spherical (chain) molecules.
+    ///
+    /// The orientation of the molecules at the interface is not
+    /// resolved by the functional.
+    ///
+    /// Returns
+    /// -------
+    /// str, optional
+    ///     The warning or None for spherical molecules.
+    ///
+    fn non_spherical_warning(&self) -> Option<String> {
+        self.0.non_spherical_warning()
+    }
+
+    /// Diagnostic for interfaces between bulk phases that are not
+    /// in equilibrium.
+    ///
+    /// Returns
+    /// -------
+    /// str, optional
+    ///     The warning or None for coexisting bulk phases.
+    ///
+    fn metastability_warning(&self) -> Option<String> {
+        self.0.metastability_warning()
+    }
+
+    /// Partial densities at the liquid and vapor boundaries of the profile.
+    ///
+    /// Returns
+    /// -------
+    /// (SIArray1, SIArray1)
+    ///
+    fn boundary_densities(&self) -> (Density<DVector<f64>>, Density<DVector<f64>>) {
+        self.0.boundary_densities()
+    }
+
+    /// Checks whether the densities at the boundaries of the profile
+    /// agree with the bulk phases of the interface.
+    ///
+    /// Parameters
+    /// ----------
+    /// tol : float
+    ///     The relative tolerance for every partial density.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///
+    fn boundary_is_bulk(&self, tol: f64) -> bool {
+        self.0.boundary_is_bulk(tol)
+    }
+
+    /// Interpolates the density profile at arbitrary positions.
+    ///
+    /// Parameters
+    /// ----------
+    /// z : SIArray1
+    ///     The positions, which do not have to be sorted.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray2
+    ///     The densities of each component at the positions. Positions
+    ///     outside of the calculation domain result in NaN.
+    ///
+    fn resample(&self, z: Length<Array1<f64>>) -> PyResult<Density<Array2<f64>>> {
+        Ok(self.0.resample(&z).map_err(PyFeosError::from)?)
+    }
+
+    /// Returns the derivative of the density profile of each component
+    /// with respect to the position.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray2
+    ///
+    fn density_gradient(&self) -> PyResult<DensityGradient<Array2<f64>>> {
+        Ok(self.0.density_gradient().map_err(PyFeosError::from)?)
+    }
+
+    /// Returns the local residual (excess) molar entropy across the
+    /// interface.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    fn excess_entropy_profile(&self) -> PyResult<MolarEntropy<Array1<f64>>> {
+        Ok(self.0.excess_entropy_profile().map_err(PyFeosError::from)?)
+    }
+
+    /// Returns the density profile as dictionary.
+    ///
+    /// Parameters
+    /// ----------
+    /// reduced : bool, optional
+    ///     If True, all values are in reduced units (Å, 1/Å³, kB K/Å³).
+    ///     Defaults to False.
+    ///
+    /// Returns
+    /// -------
+    /// Dict[str, List[float]]
+    ///     Keys: grid positions `z`, density of each component `rhoi`,
+    ///     total density `rho` and grand potential density `omega`.
+    ///
+    /// Notes
+    /// -----
+    /// - z : m
+    /// - densities : mol / m³
+    /// - grand potential density : Pa
+    #[pyo3(signature = (reduced=false), text_signature = "($self, reduced=False)")]
+    fn profile_table(&self, reduced: bool) -> PyResult<IndexMap<String, Vec<f64>>> {
+        let (labels, table) = self.0.profile_table(reduced).map_err(PyFeosError::from)?;
+        Ok(labels
+            .into_iter()
+            .zip(table.columns())
+            .map(|(label, column)| (label, column.to_vec()))
+            .collect())
+    }
 }
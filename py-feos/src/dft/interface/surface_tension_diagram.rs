@@ -1,5 +1,7 @@
 use super::PyPlanarInterface;
 use crate::dft::PyDFTSolver;
+use crate::eos::PyEquationOfState;
+use crate::error::PyFeosError;
 use crate::phase_equilibria::PyPhaseEquilibrium;
 use crate::state::PyStateVec;
 use crate::{ideal_gas::IdealGasModel, residual::ResidualModel};
@@ -7,8 +9,9 @@ use feos_core::EquationOfState;
 use feos_dft::interface::SurfaceTensionDiagram;
 use ndarray::*;
 use numpy::*;
-use pyo3::*;
+use pyo3::prelude::*;
 use quantity::*;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 /// Container structure for the efficient calculation of surface tension diagrams.
@@ -35,6 +38,14 @@ use std::sync::Arc;
 ///     Defaults to False.
 /// solver: DFTSolver, optional
 ///     Custom solver options
+/// cache: SurfaceTensionDiagram, optional
+///     A previously calculated diagram whose density profiles are used
+///     as initial guesses at the closest temperatures.
+/// progress: Callable[[int, SINumber, SINumber | None], bool], optional
+///     Called after every phase equilibrium with its index, the
+///     temperature and the surface tension (None if the interface could
+///     not be solved). If it returns True, the calculation is stopped
+///     and the diagram contains the interfaces solved so far.
 ///
 /// Returns
 /// -------
@@ -48,9 +59,10 @@ pub struct PySurfaceTensionDiagram(
 impl PySurfaceTensionDiagram {
     #[new]
     #[pyo3(
-        text_signature = "(dia, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None)"
+        text_signature = "(dia, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None, cache=None, progress=None)"
     )]
-    #[pyo3(signature = (dia, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None))]
+    #[pyo3(signature = (dia, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None, cache=None, progress=None))]
+    #[expect(clippy::too_many_arguments)]
     pub fn isotherm(
         dia: Vec<PyPhaseEquilibrium>,
         init_densities: Option<bool>,
@@ -59,17 +71,99 @@ impl PySurfaceTensionDiagram {
         critical_temperature: Option<Temperature>,
         fix_equimolar_surface: Option<bool>,
         solver: Option<PyDFTSolver>,
+        cache: Option<PyRef<Self>>,
+        progress: Option<Bound<'_, PyAny>>,
     ) -> PyResult<Self> {
         let x: Vec<_> = dia.into_iter().map(|vle| vle.0).collect();
-        Ok(Self(SurfaceTensionDiagram::new(
+        let cache = cache.map(|c| c.0.cache()).unwrap_or_default();
+        let mut error = None;
+        let dia = SurfaceTensionDiagram::new_with_progress(
             &x,
+            &cache,
             init_densities,
             n_grid,
             l_grid,
             critical_temperature,
             fix_equimolar_surface,
             solver.map(|s| s.0).as_ref(),
-        )))
+            |i, t, gamma| {
+                let Some(progress) = &progress else {
+                    return ControlFlow::Continue(());
+                };
+                match progress.call1((i, t, gamma)).and_then(|r| r.is_truthy()) {
+                    Ok(false) => ControlFlow::Continue(()),
+                    Ok(true) => ControlFlow::Break(()),
+                    Err(err) => {
+                        error = Some(err);
+                        ControlFlow::Break(())
+                    }
+                }
+            },
+        );
+        match error {
+            Some(err) => Err(err),
+            None => Ok(Self(dia)),
+        }
+    }
+
+    /// Calculate surface tensions of a pure fluid along an isobar.
+    ///
+    /// The calculation stops at the first pressure for which no phase
+    /// equilibrium is found, i.e., once the critical pressure is exceeded.
+    ///
+    /// Parameters
+    /// ----------
+    /// eos : EquationOfState
+    ///     The equation of state (Helmholtz energy functional).
+    /// pressures : SIArray1
+    ///     The pressures for which surface tensions shall be calculated.
+    /// init_densities : bool, optional
+    ///     None: Do not initialize densities with old results
+    ///     True: Initialize and scale densities
+    ///     False: Initialize without scaling
+    /// n_grid : int, optional
+    ///     The number of grid points (default: 2048).
+    /// l_grid : SINumber, optional
+    ///     The size of the calculation domain (default: 100 A)
+    /// critical_temperature: SINumber, optional
+    ///     An estimate for the critical temperature, used to initialize
+    ///     density profile (default: 500 K)
+    /// fix_equimolar_surface: bool, optional
+    ///     If True use additional constraints to fix the
+    ///     equimolar surface of the system.
+    ///     Defaults to False.
+    /// solver: DFTSolver, optional
+    ///     Custom solver options
+    ///
+    /// Returns
+    /// -------
+    /// SurfaceTensionDiagram
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(eos, pressures, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None)"
+    )]
+    #[pyo3(signature = (eos, pressures, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None))]
+    #[expect(clippy::too_many_arguments)]
+    pub fn isobar(
+        eos: &PyEquationOfState,
+        pressures: Pressure<Array1<f64>>,
+        init_densities: Option<bool>,
+        n_grid: Option<usize>,
+        l_grid: Option<Length>,
+        critical_temperature: Option<Temperature>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<PyDFTSolver>,
+    ) -> Self {
+        Self(SurfaceTensionDiagram::isobar(
+            &eos.0,
+            &pressures,
+            init_densities,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface,
+            solver.map(|s| s.0).as_ref(),
+        ))
     }
 
     #[getter]
@@ -101,6 +195,159 @@ impl PySurfaceTensionDiagram {
         self.0.relative_adsorption()
     }
 
+    /// Calculates the contributions of the individual components to the
+    /// change of the surface tension between consecutive points of an
+    /// isothermal diagram according to the Gibbs adsorption isotherm.
+    ///
+    /// Parameters
+    /// ----------
+    /// reference : int
+    ///     The index of the reference component j of the relative
+    ///     adsorptions Gamma_i^(j).
+    ///
+    /// Returns
+    /// -------
+    /// SIArray2
+    ///     The contributions with shape (profiles - 1, components).
+    ///
+    pub fn gibbs_adsorption_contributions(
+        &self,
+        reference: usize,
+    ) -> PyResult<SurfaceTension<Array2<f64>>> {
+        Ok(self
+            .0
+            .gibbs_adsorption_contributions(reference)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculates the residual d gamma + sum_i Gamma_i^(j) d mu_i of the
+    /// Gibbs adsorption isotherm between consecutive points of an
+    /// isothermal diagram.
+    ///
+    /// Large residuals compared to the changes of the surface tension
+    /// indicate under-resolved or unconverged profiles.
+    ///
+    /// Parameters
+    /// ----------
+    /// reference : int
+    ///     The index of the reference component j of the relative
+    ///     adsorptions Gamma_i^(j).
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///     The residuals with length profiles - 1.
+    ///
+    pub fn gibbs_adsorption_residual(
+        &self,
+        reference: usize,
+    ) -> PyResult<SurfaceTension<Array1<f64>>> {
+        Ok(self
+            .0
+            .gibbs_adsorption_residual(reference)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Calculates the Gibbs elasticity -d gamma / d ln Gamma_i^(j) at
+    /// every point of the diagram.
+    ///
+    /// Parameters
+    /// ----------
+    /// component : int
+    ///     The index of the component i (e.g. the surfactant).
+    /// reference : int
+    ///     The index of the reference component j of the relative
+    ///     adsorption Gamma_i^(j) (e.g. the solvent).
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    pub fn gibbs_elasticity(
+        &self,
+        component: usize,
+        reference: usize,
+    ) -> PyResult<SurfaceTension<Array1<f64>>> {
+        Ok(self
+            .0
+            .gibbs_elasticity(component, reference)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Estimates the concentration at which the surface tension
+    /// saturates with increasing liquid concentration of a component,
+    /// e.g., the critical micelle concentration of surfactants.
+    ///
+    /// Parameters
+    /// ----------
+    /// component : int
+    ///     The index of the component (e.g. the surfactant).
+    /// tol : float, optional
+    ///     The break is detected, where the magnitude of the slope
+    ///     d gamma / d ln c drops below tol times its maximum.
+    ///     Defaults to 0.1.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber, optional
+    ///     The liquid concentration at the break or None, if the
+    ///     surface tension does not level off within the diagram.
+    ///
+    #[pyo3(signature = (component, tol=0.1), text_signature = "($self, component, tol=0.1)")]
+    pub fn saturation_concentration(
+        &self,
+        component: usize,
+        tol: f64,
+    ) -> PyResult<Option<Density>> {
+        Ok(self
+            .0
+            .saturation_concentration(component, tol)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Fits the surface tensions close to the critical point to the
+    /// power law gamma = gamma_0 (1 - T / T_c)^mu.
+    ///
+    /// Parameters
+    /// ----------
+    /// critical_temperature : SINumber
+    ///     An estimate of the critical temperature, e.g., of the bulk
+    ///     equation of state.
+    /// window : float
+    ///     Only points with 0 < 1 - T / T_c <= window are used.
+    ///
+    /// Returns
+    /// -------
+    /// (float, SINumber)
+    ///     The exponent mu and the fitted critical temperature.
+    ///
+    pub fn critical_scaling(
+        &self,
+        critical_temperature: Temperature,
+        window: f64,
+    ) -> PyResult<(f64, Temperature)> {
+        Ok(self
+            .0
+            .critical_scaling(critical_temperature, window)
+            .map_err(PyFeosError::from)?)
+    }
+
+    /// Locates the temperature at which the surface tension has a
+    /// maximum, i.e., at which the slope d gamma / d T changes sign.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber, optional
+    ///     The temperature of the maximum or None if the surface
+    ///     tension is monotonic.
+    ///
+    pub fn temperature_of_maximum_surface_tension(&self) -> PyResult<Option<Temperature>> {
+        Ok(self
+            .0
+            .temperature_of_maximum_surface_tension()
+            .map_err(PyFeosError::from)?)
+    }
+
     #[getter]
     pub fn get_interfacial_enrichment<'py>(
         &self,
@@ -16,9 +16,14 @@ mod solvation;
 mod solver;
 
 pub(crate) use adsorption::{
-    PyAdsorption1D, PyAdsorption3D, PyExternalPotential, PyPore1D, PyPore2D, PyPore3D,
+    PyAdsorption1D, PyAdsorption3D, PyCorrugatedWall2D, PyDisjoiningPressureIsotherm,
+    PyExternalPotential, PyIsothermBranches1D, PyPore1D, PyPore2D, PyPore3D, PyPoreFillingDiagram,
+    PyWall1D, PyWettingIsotherm,
+};
+pub(crate) use interface::{
+    PyComponentInterfacialProperties, PyDroplet, PyFreeSurface, PyPlanarInterface,
+    PySurfaceTensionDiagram, PyWettingState, surface_tension, temperature_for_surface_tension,
 };
-pub(crate) use interface::{PyPlanarInterface, PySurfaceTensionDiagram};
 pub(crate) use solvation::{PyPairCorrelation, PySolvationProfile};
 pub(crate) use solver::{PyDFTSolver, PyDFTSolverLog};
 
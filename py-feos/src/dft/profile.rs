@@ -32,16 +32,36 @@ macro_rules! impl_profile {
             ///     The solver used to solve the profile.
             /// debug: bool, optional
             ///     If True, do not check for convergence.
+            /// tol: float, optional
+            ///     Overrides the tolerance of the last algorithm of the
+            ///     solver, which decides whether the profile is converged.
+            /// max_iter: int, optional
+            ///     Overrides the maximum number of iterations of the last
+            ///     algorithm of the solver.
             ///
             /// Returns
             /// -------
             /// $struct
             ///
-            #[pyo3(signature = (solver=None, debug=false), text_signature = "($self, solver=None, debug=False)")]
-            fn solve(slf: Bound<'_, Self>, solver: Option<PyDFTSolver>, debug: bool) -> PyResult<Bound<'_, Self>> {
+            #[pyo3(signature = (solver=None, debug=false, tol=None, max_iter=None), text_signature = "($self, solver=None, debug=False, tol=None, max_iter=None)")]
+            fn solve(
+                slf: Bound<'_, Self>,
+                solver: Option<PyDFTSolver>,
+                debug: bool,
+                tol: Option<f64>,
+                max_iter: Option<usize>,
+            ) -> PyResult<Bound<'_, Self>> {
+                let mut solver = solver.map(|s| s.0).unwrap_or_default();
+                // only the last algorithm decides about convergence
+                if let Some(tol) = tol {
+                    solver = solver.tolerance(tol);
+                }
+                if let Some(max_iter) = max_iter {
+                    solver = solver.max_iter(max_iter);
+                }
                 slf.borrow_mut()
                     .0
-                    .solve_inplace(solver.map(|s| s.0).as_ref(), debug).map_err(PyFeosError::from)?;
+                    .solve_inplace(Some(&solver), debug).map_err(PyFeosError::from)?;
                 Ok(slf)
             }
 
@@ -61,6 +81,91 @@ macro_rules! impl_profile {
                 self.0.profile.density.clone()
             }
 
+            /// Return the density profile in reduced units and the reference density.
+            ///
+            /// The density in SI units is `density_array * reference_density`.
+            /// In contrast to `density`, the numpy array takes ownership of the
+            /// data without an additional copy.
+            ///
+            /// Returns
+            /// -------
+            /// (numpy.ndarray[float], SINumber)
+            ///
+            fn density_array<'py>(&self, py: Python<'py>) -> (Bound<'py, $arr2<f64>>, Density) {
+                let (density, reference) = self.0.profile.density_array();
+                (density.into_pyarray(py), reference)
+            }
+
+            /// Calculate the relative deviation of the number of particles in the
+            /// profile from the number of particles fixed by the specification.
+            ///
+            /// Returns
+            /// -------
+            /// float | None
+            ///
+            fn mass_conservation_residual(&self) -> Option<f64> {
+                self.0.profile.mass_conservation_residual()
+            }
+
+            /// Calculate the area of a dividing surface.
+            ///
+            /// Parameters
+            /// ----------
+            /// radius : SINumber
+            ///     The position (radius) of the dividing surface.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn dividing_surface_area(&self, radius: Length) -> PyResult<Area> {
+                Ok(self.0.profile.dividing_surface_area(radius).map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the volume enclosed by a dividing surface.
+            ///
+            /// Parameters
+            /// ----------
+            /// radius : SINumber
+            ///     The position (radius) of the dividing surface.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn enclosed_volume(&self, radius: Length) -> PyResult<Volume> {
+                Ok(self.0.profile.enclosed_volume(radius).map_err(PyFeosError::from)?)
+            }
+
+            /// Fix the density profiles of the given components.
+            ///
+            /// Only the densities of the remaining components are solved for
+            /// in subsequent calls to `solve`.
+            ///
+            /// Parameters
+            /// ----------
+            /// components : [int]
+            ///     The indices of the frozen components.
+            /// density : SIArray
+            ///     The density profiles of all segments of the frozen components.
+            ///
+            fn freeze_components(
+                &mut self,
+                components: Vec<usize>,
+                density: Density<$si_arr2<f64>>,
+            ) -> PyResult<()> {
+                Ok(self
+                    .0
+                    .profile
+                    .freeze_components(&components, &density)
+                    .map_err(PyFeosError::from)?)
+            }
+
+            #[getter]
+            fn get_frozen_components(&self) -> Vec<usize> {
+                self.0.profile.frozen_components.clone()
+            }
+
             #[getter]
             fn get_moles(&self) -> Moles<DVector<f64>> {
                 self.0.profile.moles()
@@ -86,6 +191,16 @@ macro_rules! impl_profile {
                 self.0.profile.solver_log.clone().map(PyDFTSolverLog)
             }
 
+            /// Description of the solver settings used in the last solve.
+            ///
+            /// Returns
+            /// -------
+            /// str, optional
+            ///
+            fn solver_description(&self) -> Option<String> {
+                self.0.profile.solver_description()
+            }
+
             #[getter]
             fn get_weighted_densities<'py>(
                 &self,
@@ -95,6 +210,78 @@ macro_rules! impl_profile {
                 Ok(n.into_iter().map(|n| n.view().to_pyarray(py)).collect())
             }
 
+            /// Calculate the weighted densities labeled with the names
+            /// of the functional contributions.
+            ///
+            /// Returns
+            /// -------
+            /// [(str, numpy.ndarray[float])]
+            ///
+            fn weighted_densities_by_contribution<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Vec<(&'static str, Bound<'py, $arr2<f64>>)>> {
+                let n = self
+                    .0
+                    .profile
+                    .weighted_densities_by_contribution()
+                    .map_err(PyFeosError::from)?;
+                Ok(n.into_iter().map(|(name, n)| (name, n.into_pyarray(py))).collect())
+            }
+
+            /// Calculate the maximum absolute difference between the
+            /// density profiles of two profiles on the same grid.
+            ///
+            /// Parameters
+            /// ----------
+            /// other : Self
+            ///     A profile of the same type and on the same grid.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn max_density_difference(&self, other: &Self) -> PyResult<Density> {
+                Ok(self
+                    .0
+                    .profile
+                    .max_density_difference(&other.0.profile)
+                    .map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the maximum difference between the density
+            /// profiles of two profiles on the same grid relative to
+            /// the larger of the two densities.
+            ///
+            /// Parameters
+            /// ----------
+            /// other : Self
+            ///     A profile of the same type and on the same grid.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            ///
+            fn max_relative_density_difference(&self, other: &Self) -> PyResult<f64> {
+                Ok(self
+                    .0
+                    .profile
+                    .max_relative_density_difference(&other.0.profile)
+                    .map_err(PyFeosError::from)?)
+            }
+
+            /// Calculate the local mole fractions of all components.
+            ///
+            /// The mole fractions are zero where the total density vanishes.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///
+            fn composition_profile<'py>(&self, py: Python<'py>) -> Bound<'py, $arr2<f64>> {
+                self.0.profile.composition_profile().into_pyarray(py)
+            }
+
             #[getter]
             fn get_functional_derivative<'py>(
                 &self,
@@ -211,6 +398,20 @@ macro_rules! impl_1d_profile {
             [$([0, $ax]),+],
             Array3
         );
+
+        #[pymethods]
+        impl $struct {
+            /// Write the density profiles and external potentials to a CSV file.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the CSV file.
+            ///
+            fn to_csv(&self, path: std::path::PathBuf) -> PyResult<()> {
+                Ok(self.0.profile.to_csv(path).map_err(PyFeosError::from)?)
+            }
+        }
     };
 }
 
@@ -237,6 +438,29 @@ macro_rules! impl_2d_profile {
             fn get_meshgrid(&self) -> [Length<Array2<f64>>; 2] {
                 self.0.profile.meshgrid()
             }
+
+            /// Write the density profiles and external potentials to a CSV file.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the CSV file.
+            ///
+            fn to_csv(&self, path: std::path::PathBuf) -> PyResult<()> {
+                Ok(self.0.profile.to_csv(path).map_err(PyFeosError::from)?)
+            }
+
+            /// Write the density profiles and external potentials to a legacy
+            /// VTK file that can be opened, e.g., in ParaView.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the VTK file.
+            ///
+            fn to_vtk(&self, path: std::path::PathBuf) -> PyResult<()> {
+                Ok(self.0.profile.to_vtk(path).map_err(PyFeosError::from)?)
+            }
         }
     };
 }
@@ -264,6 +488,18 @@ macro_rules! impl_3d_profile {
             fn get_meshgrid(&self) -> [Length<Array3<f64>>; 3] {
                 self.0.profile.meshgrid()
             }
+
+            /// Write the density profiles and external potentials to a legacy
+            /// VTK file that can be opened, e.g., in ParaView.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the VTK file.
+            ///
+            fn to_vtk(&self, path: std::path::PathBuf) -> PyResult<()> {
+                Ok(self.0.profile.to_vtk(path).map_err(PyFeosError::from)?)
+            }
         }
     };
 }
@@ -151,6 +151,77 @@ impl PyDFTSolver {
         Self(self.0.clone().newton(log, max_iter, max_iter_gmres, tol))
     }
 
+    /// Override the damping coefficient of all Picard iterations
+    /// and Anderson mixing steps of the solver.
+    ///
+    /// Values between 0.01 and 0.1 usually stabilize the solver
+    /// close to critical points or for strongly associating fluids.
+    ///
+    /// Parameters
+    /// ----------
+    /// damping_coefficient: float
+    ///     The damping coefficient (0 < damping_coefficient <= 1).
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    fn damping_coefficient(&self, damping_coefficient: f64) -> Self {
+        Self(self.0.clone().damping_coefficient(damping_coefficient))
+    }
+
+    /// Replace all Anderson mixing steps of the solver by
+    /// Picard iterations with a line search.
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    fn use_picard_iteration(&self) -> Self {
+        Self(self.0.clone().use_picard_iteration())
+    }
+
+    /// Replace all Picard iterations of the solver by Anderson
+    /// mixing steps.
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    fn use_anderson_mixing(&self) -> Self {
+        Self(self.0.clone().use_anderson_mixing())
+    }
+
+    /// Override the tolerance of the last algorithm of the solver.
+    ///
+    /// Only the last algorithm decides whether the profile is
+    /// converged, the preceding algorithms merely provide initial
+    /// values.
+    ///
+    /// Parameters
+    /// ----------
+    /// tol: float
+    ///     The tolerance.
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    fn tolerance(&self, tol: f64) -> Self {
+        Self(self.0.clone().tolerance(tol))
+    }
+
+    /// Override the maximum number of iterations of the last
+    /// algorithm of the solver.
+    ///
+    /// Parameters
+    /// ----------
+    /// max_iter: int
+    ///     The maximum number of iterations.
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    fn max_iter(&self, max_iter: usize) -> Self {
+        Self(self.0.clone().max_iter(max_iter))
+    }
+
     fn _repr_markdown_(&self) -> String {
         self.0._repr_markdown_()
     }
@@ -180,4 +251,14 @@ impl PyDFTSolverLog {
     fn get_solver(&self) -> Vec<&'static str> {
         self.0.solver().to_vec()
     }
+
+    #[getter]
+    fn get_settings(&self) -> Option<PyDFTSolver> {
+        self.0.settings().cloned().map(PyDFTSolver)
+    }
+
+    #[getter]
+    fn get_negative_densities(&self) -> usize {
+        self.0.negative_densities()
+    }
 }
@@ -212,14 +212,26 @@ fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
         // Adsorption
         m.add_class::<dft::PyAdsorption1D>()?;
         m.add_class::<dft::PyAdsorption3D>()?;
+        m.add_class::<dft::PyIsothermBranches1D>()?;
         m.add_class::<dft::PyExternalPotential>()?;
         m.add_class::<dft::PyPore1D>()?;
         m.add_class::<dft::PyPore2D>()?;
         m.add_class::<dft::PyPore3D>()?;
+        m.add_class::<dft::PyPoreFillingDiagram>()?;
+        m.add_class::<dft::PyDisjoiningPressureIsotherm>()?;
+        m.add_class::<dft::PyWall1D>()?;
+        m.add_class::<dft::PyWettingIsotherm>()?;
+        m.add_class::<dft::PyCorrugatedWall2D>()?;
 
         // Interface
         m.add_class::<dft::PySurfaceTensionDiagram>()?;
         m.add_class::<dft::PyPlanarInterface>()?;
+        m.add_class::<dft::PyDroplet>()?;
+        m.add_class::<dft::PyFreeSurface>()?;
+        m.add_class::<dft::PyWettingState>()?;
+        m.add_class::<dft::PyComponentInterfacialProperties>()?;
+        m.add_function(wrap_pyfunction!(dft::surface_tension, m)?)?;
+        m.add_function(wrap_pyfunction!(dft::temperature_for_surface_tension, m)?)?;
 
         // Solvation
         m.add_class::<dft::PyPairCorrelation>()?;